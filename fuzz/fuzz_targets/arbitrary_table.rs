@@ -0,0 +1,9 @@
+//! Feeds tables of well-framed structures with arbitrary contents to the decoders
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smbioslib::SMBiosData;
+
+fuzz_target!(|data: SMBiosData| {
+    smbios_lib_fuzz::exercise(&data);
+});
@@ -0,0 +1,9 @@
+//! Feeds raw bytes to the table parser
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smbioslib::SMBiosData;
+
+fuzz_target!(|data: &[u8]| {
+    smbios_lib_fuzz::exercise(&SMBiosData::from(data.to_vec()));
+});
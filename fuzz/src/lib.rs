@@ -0,0 +1,28 @@
+//! Shared checks for the fuzz targets
+//!
+//! Run a target with `cargo +nightly fuzz run parse_table` from the repository root.
+
+use smbioslib::*;
+
+/// Exercises the parsing, decoding, and serialization API on a table
+///
+/// Panics if any of it panics or if serialized bytes do not parse back identically.
+pub fn exercise(data: &SMBiosData) {
+    for undefined_struct in data.iter() {
+        let defined_struct = undefined_struct.defined_struct();
+        let _ = format!("{:?}", defined_struct);
+        let _ = serde_json::to_string(&defined_struct);
+    }
+
+    let _ = format!("{:?}", data);
+    let _ = data.to_json();
+    let _ = dmidecode_dump(data);
+    let _ = data.validate();
+    let _ = data.diff(data, DiffMatching::Position);
+
+    let bytes = data.to_bytes();
+    assert_eq!(SMBiosData::from(bytes.clone()).to_bytes(), bytes);
+
+    let redacted = data.redacted(&Redaction::Blank);
+    assert_eq!(redacted.iter().count(), data.iter().count());
+}
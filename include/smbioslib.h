@@ -0,0 +1,168 @@
+#ifndef SMBIOSLIB_H
+#define SMBIOSLIB_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// An SMBIOS table. Create one with an smbios_table_* constructor and release
+// it with smbios_table_free.
+typedef struct SMBiosData SMBiosData;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Loads the table of the running system
+//
+// Returns NULL if the table cannot be read.  Release the table with
+// smbios_table_free.  Requires the `os-loader` feature.
+SMBiosData *smbios_table_load_from_device(void);
+
+// Loads a table from a file
+//
+// Returns NULL if the file cannot be read.  Release the table with
+// smbios_table_free.  Requires the `os-loader` feature.
+//
+// `path` must be NULL or a NUL-terminated UTF-8 string.
+SMBiosData *smbios_table_load_from_file(const char *path);
+
+// Parses raw table data (structures only, without an entry point) of an
+// unknown SMBIOS version
+//
+// Release the table with smbios_table_free.
+//
+// `data` must be NULL or point to `length` readable bytes.
+SMBiosData *smbios_table_from_bytes(const uint8_t *data, size_t length);
+
+// Releases a table
+void smbios_table_free(SMBiosData *table);
+
+// Gets the SMBIOS version of a table
+//
+// Returns `false` if the version is unknown.
+bool smbios_table_version(const SMBiosData *table,
+                          uint8_t *major,
+                          uint8_t *minor,
+                          uint8_t *revision);
+
+// Number of structures in a table
+size_t smbios_table_count(const SMBiosData *table);
+
+// Finds the position of the `instance`th (from 0) structure of a type
+bool smbios_table_find(const SMBiosData *table,
+                       uint8_t struct_type,
+                       size_t instance,
+                       size_t *position);
+
+// Finds the position of the structure with a handle
+bool smbios_table_find_handle(const SMBiosData *table, uint16_t handle, size_t *position);
+
+// Serializes a table to JSON
+//
+// Release the string with smbios_string_free.
+char *smbios_table_to_json(const SMBiosData *table);
+
+// Gets the type of the structure at a position
+bool smbios_struct_type(const SMBiosData *table, size_t position, uint8_t *struct_type);
+
+// Gets the handle of the structure at a position
+bool smbios_struct_handle(const SMBiosData *table, size_t position, uint16_t *handle);
+
+// Reads a BYTE field of the structure at a position
+//
+// Returns `false` if the structure or the field is absent.
+bool smbios_struct_field_byte(const SMBiosData *table,
+                              size_t position,
+                              size_t offset,
+                              uint8_t *value);
+
+// Reads a WORD field of the structure at a position
+//
+// Returns `false` if the structure or the field is absent.
+bool smbios_struct_field_word(const SMBiosData *table,
+                              size_t position,
+                              size_t offset,
+                              uint16_t *value);
+
+// Reads a DWORD field of the structure at a position
+//
+// Returns `false` if the structure or the field is absent.
+bool smbios_struct_field_dword(const SMBiosData *table,
+                               size_t position,
+                               size_t offset,
+                               uint32_t *value);
+
+// Reads a QWORD field of the structure at a position
+//
+// Returns `false` if the structure or the field is absent.
+bool smbios_struct_field_qword(const SMBiosData *table,
+                               size_t position,
+                               size_t offset,
+                               uint64_t *value);
+
+// Reads the string referenced by a string number field of the structure at
+// a position
+//
+// Release the string with smbios_string_free.
+char *smbios_struct_field_string(const SMBiosData *table, size_t position, size_t offset);
+
+// Serializes the decoded fields of the structure at a position to JSON
+//
+// Release the string with smbios_string_free.
+char *smbios_struct_to_json(const SMBiosData *table, size_t position);
+
+// BIOS Vendor (Type 0)
+//
+// Release the string with smbios_string_free.
+char *smbios_bios_vendor(const SMBiosData *table);
+
+// BIOS Version (Type 0)
+//
+// Release the string with smbios_string_free.
+char *smbios_bios_version(const SMBiosData *table);
+
+// System Manufacturer (Type 1)
+//
+// Release the string with smbios_string_free.
+char *smbios_system_manufacturer(const SMBiosData *table);
+
+// System Product Name (Type 1)
+//
+// Release the string with smbios_string_free.
+char *smbios_system_product_name(const SMBiosData *table);
+
+// System Serial Number (Type 1)
+//
+// Release the string with smbios_string_free.
+char *smbios_system_serial_number(const SMBiosData *table);
+
+// System UUID (Type 1)
+//
+// Release the string with smbios_string_free.
+char *smbios_system_uuid(const SMBiosData *table);
+
+// Processor Version of the `instance`th (from 0) Processor Information
+// structure (Type 4)
+//
+// Release the string with smbios_string_free.  Requires the `processor`
+// feature.
+char *smbios_processor_version(const SMBiosData *table, size_t instance);
+
+// Size in kilobytes of the `instance`th (from 0) Memory Device structure
+// (Type 17)
+//
+// An empty socket has a size of 0.  Returns `false` if the size is unknown.
+// Requires the `memory` feature.
+bool smbios_memory_device_size_kb(const SMBiosData *table, size_t instance, uint64_t *kilobytes);
+
+// Releases a string returned by this library
+void smbios_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SMBIOSLIB_H */
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use std::fmt;
+
+/// # Binary-Coded Decimal Value
+///
+/// A one byte binary-coded decimal field holding a value from 00 to 99, such as
+/// the date and time fields of System Power Controls (Type 25) and of the
+/// system event log records.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BcdValue {
+    /// The decoded value (0-99)
+    Value(u8),
+    /// The field is unspecified (FFh)
+    Unspecified,
+    /// The raw byte is not a valid BCD value
+    Invalid(u8),
+}
+
+impl BcdValue {
+    /// The decoded value, `None` when unspecified or invalid
+    pub fn value(&self) -> Option<u8> {
+        match self {
+            BcdValue::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl From<u8> for BcdValue {
+    fn from(raw: u8) -> Self {
+        match (raw >> 4, raw & 0x0F) {
+            (0xF, 0xF) => BcdValue::Unspecified,
+            (tens, ones) if tens < 10 && ones < 10 => BcdValue::Value(tens * 10 + ones),
+            _ => BcdValue::Invalid(raw),
+        }
+    }
+}
+
+/// Formats as two decimal digits, `*` when unspecified or the raw byte in
+/// hexadecimal when invalid
+impl fmt::Display for BcdValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BcdValue::Value(value) => write!(f, "{:02}", value),
+            BcdValue::Unspecified => write!(f, "*"),
+            BcdValue::Invalid(raw) => write!(f, "{:02X}h", raw),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test() {
+        assert_eq!(BcdValue::from(0x00), BcdValue::Value(0));
+        assert_eq!(BcdValue::from(0x59), BcdValue::Value(59));
+        assert_eq!(BcdValue::from(0x99).value(), Some(99));
+        assert_eq!(BcdValue::from(0xFF), BcdValue::Unspecified);
+        assert_eq!(BcdValue::from(0x1A), BcdValue::Invalid(0x1A));
+        assert_eq!(BcdValue::from(0xF9), BcdValue::Invalid(0xF9));
+        assert_eq!(BcdValue::from(0xFF).value(), None);
+
+        assert_eq!(BcdValue::from(0x05).to_string(), "05");
+        assert_eq!(BcdValue::from(0xFF).to_string(), "*");
+        assert_eq!(BcdValue::from(0xA0).to_string(), "A0h");
+    }
+}
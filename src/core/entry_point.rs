@@ -1,11 +1,16 @@
+use super::SMBiosVersion;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::{
     convert::TryFrom,
     convert::TryInto,
     fmt,
-    fs::{read, File},
-    io::{prelude::*, Error, ErrorKind, SeekFrom},
+    io::{Error, ErrorKind},
     num::Wrapping,
+};
+#[cfg(feature = "os-loader")]
+use std::{
+    fs::{read, File},
+    io::{prelude::*, SeekFrom},
     ops::RangeBounds,
     path::Path,
 };
@@ -232,13 +237,23 @@ impl<'a> SMBiosEntryPoint32 {
         self.raw[Self::BCD_REVISION_OFFSET]
     }
 
+    /// SMBIOS Version
+    ///
+    /// The [SMBiosVersion] formed by `major_version` and `minor_version`.
+    /// The 32-bit entry point does not carry a docrev so the revision is 0.
+    pub fn version(&self) -> SMBiosVersion {
+        SMBiosVersion::new(self.major_version(), self.minor_version(), 0)
+    }
+
     /// Load this structure from a file
+    #[cfg(feature = "os-loader")]
     pub fn try_load_from_file(filename: &Path) -> Result<Self, Error> {
         read(filename)?.try_into()
     }
 
     /// Load this structure by scanning a file within the given offsets,
     /// looking for the [SMBiosEntryPoint32::SM_ANCHOR] string.
+    #[cfg(feature = "os-loader")]
     pub fn try_scan_from_file<T: Iterator<Item = u64>>(
         file: &mut File,
         range: T,
@@ -315,7 +330,7 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
         // Verify the IEPS checksum
         // The checksum is calculated for a length of 0x0F
         let intermediate_entry_point_structure: [u8; 0x0F] = raw
-            [Self::INTERMEDIATE_ANCHOR_OFFSET..]
+            [Self::INTERMEDIATE_ANCHOR_OFFSET..Self::INTERMEDIATE_ANCHOR_OFFSET + 0x0F]
             .try_into()
             .expect("0x0F bytes");
 
@@ -520,13 +535,22 @@ impl<'a> SMBiosEntryPoint64 {
         )
     }
 
+    /// SMBIOS Version
+    ///
+    /// The [SMBiosVersion] formed by `major_version`, `minor_version`, and `docrev`.
+    pub fn version(&self) -> SMBiosVersion {
+        SMBiosVersion::new(self.major_version(), self.minor_version(), self.docrev())
+    }
+
     /// Load this structure from a file
+    #[cfg(feature = "os-loader")]
     pub fn try_load_from_file(filename: &Path) -> Result<Self, Error> {
         read(filename)?.try_into()
     }
 
     /// Load this structure by scanning a file within the given offsets,
     /// looking for the [SMBiosEntryPoint64::SM3_ANCHOR] string.
+    #[cfg(feature = "os-loader")]
     pub fn try_scan_from_file<T: Iterator<Item = u64>>(
         file: &mut File,
         range: T,
@@ -650,3 +674,95 @@ fn verify_checksum(data: &[u8]) -> bool {
 
     sum == Wrapping(0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_point_32() {
+        let raw = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0xC2, 0x1F, 0x02, 0x08, 0xB7, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x97, 0x5A, 0x0A, 0x00, 0x08, 0x0F, 0x00,
+            0x2E, 0x00, 0x28,
+        ];
+
+        let entry_point = SMBiosEntryPoint32::try_from(raw.clone()).unwrap();
+        assert_eq!(entry_point.entry_point_structure_checksum(), 0xC2);
+        assert_eq!(entry_point.entry_point_length(), 0x1F);
+        assert_eq!(entry_point.version(), SMBiosVersion::new(2, 8, 0));
+        assert_eq!(entry_point.maximum_structure_size(), 0xB7);
+        assert_eq!(
+            entry_point.intermediate_anchor(),
+            SMBiosEntryPoint32::DMI_ANCHOR
+        );
+        assert_eq!(entry_point.intermediate_checksum(), 0x97);
+        assert_eq!(entry_point.structure_table_length(), 0x0A5A);
+        assert_eq!(entry_point.structure_table_address(), 0x000F_0800);
+        assert_eq!(entry_point.number_of_smbios_structures(), 0x2E);
+        assert_eq!(entry_point.bcd_revision(), 0x28);
+
+        // Trailing bytes beyond the entry point are ignored
+        let mut padded = raw.clone();
+        padded.extend_from_slice(&[0xAA; 0x11]);
+        assert!(SMBiosEntryPoint32::try_from(padded).is_ok());
+
+        // Bad EPS checksum
+        let mut corrupt = raw.clone();
+        corrupt[0x08] = 0xB8;
+        assert!(SMBiosEntryPoint32::try_from(corrupt).is_err());
+
+        // Bad IEPS checksum (EPS checksum rebalanced so only the IEPS fails)
+        let mut corrupt = raw.clone();
+        corrupt[0x16] = 0x5B;
+        corrupt[0x0B] = 0xFF;
+        assert!(SMBiosEntryPoint32::try_from(corrupt).is_err());
+
+        // Missing _DMI_ anchor
+        let mut corrupt = raw.clone();
+        corrupt[0x11] = b'X';
+        corrupt[0x12] = b'C';
+        assert!(SMBiosEntryPoint32::try_from(corrupt).is_err());
+
+        // Truncated
+        assert!(SMBiosEntryPoint32::try_from(raw[..0x1E].to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_entry_point_64() {
+        // Linux /sys/firmware/dmi/tables/smbios_entry_point (3.3.0)
+        let raw = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x7E, 0x18, 0x03, 0x03, 0x00, 0x01, 0x00, 0x83, 0x04,
+            0x00, 0x00, 0x00, 0x20, 0xB0, 0x7B, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let entry_point = SMBiosEntryPoint64::try_from(raw.clone()).unwrap();
+        assert_eq!(entry_point.entry_point_structure_checksum(), 0x7E);
+        assert_eq!(entry_point.entry_point_length(), 0x18);
+        assert_eq!(entry_point.version(), SMBiosVersion::new(3, 3, 0));
+        assert_eq!(entry_point.entry_point_revision(), 0x01);
+        assert_eq!(entry_point.structure_table_maximum_size(), 0x0483);
+        assert_eq!(entry_point.structure_table_address(), 0x7BB0_2000);
+
+        // Bad checksum
+        let mut corrupt = raw.clone();
+        corrupt[0x0C] = 0x84;
+        assert_eq!(
+            SMBiosEntryPoint64::try_from(corrupt).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        // Bad anchor
+        let mut corrupt = raw.clone();
+        corrupt[3] = b'2';
+        assert!(SMBiosEntryPoint64::try_from(corrupt).is_err());
+
+        // Entry point length exceeds the data
+        let mut corrupt = raw.clone();
+        corrupt[0x06] = 0x20;
+        assert!(SMBiosEntryPoint64::try_from(corrupt).is_err());
+
+        // Truncated
+        assert!(SMBiosEntryPoint64::try_from(raw[..0x10].to_vec()).is_err());
+    }
+}
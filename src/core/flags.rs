@@ -0,0 +1,111 @@
+/// Implements the operations shared by the bit field types
+///
+/// The type must be a `Copy` struct holding its bits in a `raw` field, with
+/// an associated constant for each flag defined by the specification.  The
+/// flags are listed with their descriptions, in bit order:
+///
+/// ```ignore
+/// flag_set!(SramTypes, [("Other", OTHER), ("Unknown", UNKNOWN)]);
+/// ```
+///
+/// This adds `empty()`, `contains()`, `intersects()` and `iter()` along with
+/// the `|` and `&` operators.
+macro_rules! flag_set {
+    ($flags:ident, [$(($description:expr, $flag:ident)),* $(,)?]) => {
+        impl $flags {
+            /// Every flag defined by the specification with its description
+            const DEFINED: &'static [(&'static str, $flags)] =
+                &[$(($description, $flags::$flag)),*];
+
+            /// A value with no flags set
+            pub const fn empty() -> Self {
+                Self { raw: 0 }
+            }
+
+            /// Returns true when every flag set in `other` is also set in `self`
+            pub fn contains(&self, other: Self) -> bool {
+                self.raw & other.raw == other.raw
+            }
+
+            /// Returns true when any flag set in `other` is also set in `self`
+            pub fn intersects(&self, other: Self) -> bool {
+                self.raw & other.raw != 0
+            }
+
+            /// Iterates the flags defined by the specification which are set,
+            /// with their descriptions
+            ///
+            /// Reserved bits are never yielded.
+            pub fn iter(&self) -> impl Iterator<Item = (&'static str, $flags)> {
+                let flags = *self;
+                Self::DEFINED
+                    .iter()
+                    .copied()
+                    .filter(move |(_, flag)| flags.contains(*flag))
+            }
+        }
+
+        impl std::ops::BitOr for $flags {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self {
+                    raw: self.raw | rhs.raw,
+                }
+            }
+        }
+
+        impl std::ops::BitAnd for $flags {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self {
+                    raw: self.raw & rhs.raw,
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use flag_set;
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct TestFlags {
+        raw: u16,
+    }
+
+    impl TestFlags {
+        const FIRST: Self = Self { raw: 0x0001 };
+        const SECOND: Self = Self { raw: 0x0002 };
+        const HIGH: Self = Self { raw: 0x8000 };
+    }
+
+    flag_set!(
+        TestFlags,
+        [("First", FIRST), ("Second", SECOND), ("High", HIGH)]
+    );
+
+    #[test]
+    fn test_flag_set() {
+        let flags = TestFlags::FIRST | TestFlags::HIGH;
+        assert_eq!(flags.raw, 0x8001);
+        assert!(flags.contains(TestFlags::FIRST | TestFlags::HIGH));
+        assert!(!flags.contains(TestFlags::FIRST | TestFlags::SECOND));
+        assert!(flags.intersects(TestFlags::FIRST | TestFlags::SECOND));
+        assert!(!flags.intersects(TestFlags::SECOND));
+        assert!(flags.contains(TestFlags::empty()));
+        assert_eq!(flags & TestFlags::HIGH, TestFlags::HIGH);
+        assert_eq!(flags & TestFlags::SECOND, TestFlags::empty());
+        assert_eq!(
+            flags.iter().collect::<Vec<_>>(),
+            vec![("First", TestFlags::FIRST), ("High", TestFlags::HIGH)]
+        );
+
+        // Bits without a defined flag are never yielded
+        let reserved = TestFlags { raw: 0x0F0C };
+        assert_eq!(reserved.iter().count(), 0);
+        assert!(TestFlags::empty().iter().next().is_none());
+    }
+}
@@ -0,0 +1,119 @@
+//! [Arbitrary] implementations for fuzzing
+//!
+//! Structures are generated as raw bytes (a header, formatted portion, and
+//! string-set) and then parsed, so every generated value is one the parser can
+//! produce from firmware.  Field contents are unconstrained.
+
+use super::header::Header;
+use super::smbios_data::{SMBiosData, SMBiosVersion};
+use super::undefined_struct::UndefinedStruct;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::num::NonZeroU8;
+
+/// Upper bound on the number of strings generated for a structure
+const MAX_STRINGS: u8 = 8;
+
+impl<'a> Arbitrary<'a> for SMBiosVersion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SMBiosVersion::new(
+            u.arbitrary()?,
+            u.arbitrary()?,
+            u.arbitrary()?,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for UndefinedStruct {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(UndefinedStruct::new(&arbitrary_struct_bytes(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for SMBiosData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let version: Option<SMBiosVersion> = u.arbitrary()?;
+
+        let mut data = Vec::new();
+        // Each structure consumes at least a header and two terminating nulls
+        for _ in 0..u.arbitrary_len::<[u8; Header::SIZE + 2]>()? {
+            data.extend(arbitrary_struct_bytes(u)?);
+        }
+
+        // Optionally terminate the table, possibly leaving trailing bytes
+        if u.arbitrary()? {
+            data.extend_from_slice(&[127, Header::SIZE as u8, 0xFF, 0xFF, 0x00, 0x00]);
+        }
+        if u.arbitrary()? {
+            let trailing = u.arbitrary_len::<u8>()?;
+            data.extend_from_slice(u.bytes(trailing)?);
+        }
+
+        Ok(SMBiosData::from_vec_and_version(data, version))
+    }
+}
+
+/// Generates the raw bytes of one structure
+fn arbitrary_struct_bytes(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let struct_type: u8 = u.arbitrary()?;
+    let length = u.int_in_range(Header::SIZE as u8..=u8::MAX)?;
+    let handle: u16 = u.arbitrary()?;
+
+    let mut raw = vec![struct_type, length];
+    raw.extend_from_slice(&handle.to_le_bytes());
+    raw.extend_from_slice(u.bytes(length as usize - Header::SIZE)?);
+
+    let mut has_strings = false;
+    for _ in 0..u.int_in_range(0..=MAX_STRINGS)? {
+        let string: Vec<NonZeroU8> = u.arbitrary()?;
+        if !string.is_empty() {
+            raw.extend(string.iter().map(|byte| byte.get()));
+            raw.push(0);
+            has_strings = true;
+        }
+    }
+    if !has_strings {
+        raw.push(0);
+    }
+    raw.push(0);
+
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_round_trip() {
+        // A simple deterministic generator stands in for the fuzzer's input
+        let mut seed = 0x2545_F491_4F6C_DD1Du64;
+        let mut generated = 0;
+        for _ in 0..500 {
+            let input: Vec<u8> = (0..512)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed as u8
+                })
+                .collect();
+
+            let mut u = Unstructured::new(&input);
+            if let Ok(data) = SMBiosData::arbitrary(&mut u) {
+                let bytes = data.to_bytes();
+                assert_eq!(SMBiosData::from(bytes.clone()).to_bytes(), bytes);
+                generated += data.iter().count();
+            }
+
+            let mut u = Unstructured::new(&input);
+            if let Ok(undefined_struct) = UndefinedStruct::arbitrary(&mut u) {
+                assert!(undefined_struct.header.length() as usize >= Header::SIZE);
+                assert_eq!(
+                    UndefinedStruct::new(&undefined_struct.to_bytes()).to_bytes(),
+                    undefined_struct.to_bytes()
+                );
+            }
+        }
+        assert!(generated > 0);
+    }
+}
@@ -7,12 +7,45 @@ use std::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 /// Some structures will reference other structures by using this value.
 ///
 /// Dereference a handle (*handle) to access its u16 value.
-#[derive(Serialize, PartialEq, Eq)]
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Handle(pub u16);
 
 impl Handle {
     /// Handle Size (2 bytes)
     pub const SIZE: usize = 2usize;
+
+    /// A reference to another structure which is not provided (0xFFFE)
+    pub const NOT_PROVIDED: Handle = Handle(0xFFFE);
+
+    /// A reference to no structure (0xFFFF)
+    ///
+    /// For example, a Management Device Component without associated
+    /// threshold data.
+    pub const NONE: Handle = Handle(0xFFFF);
+
+    /// Tests for a reference which does not identify a structure
+    /// ([Handle::NOT_PROVIDED] or [Handle::NONE])
+    pub fn is_unassigned(&self) -> bool {
+        *self == Handle::NOT_PROVIDED || *self == Handle::NONE
+    }
+}
+
+impl PartialEq<u16> for Handle {
+    fn eq(&self, other: &u16) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<u16> for Handle {
+    fn from(value: u16) -> Self {
+        Handle(value)
+    }
+}
+
+impl From<Handle> for u16 {
+    fn from(handle: Handle) -> Self {
+        handle.0
+    }
 }
 
 impl fmt::Debug for Handle {
@@ -74,6 +107,7 @@ impl fmt::Debug for SMBiosType {
 /// # SMBIOS Header
 ///
 /// The header part/section of a structure
+#[derive(Clone)]
 pub struct Header([u8; 4]);
 
 impl fmt::Debug for Header {
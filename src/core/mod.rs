@@ -1,12 +1,28 @@
+mod bcd;
 mod entry_point;
+mod flags;
+#[cfg(feature = "fuzz")]
+mod fuzz;
 mod header;
+mod redaction;
 mod smbios_data;
 /// SMBIOS String module
 pub mod strings;
+mod table_builder;
+mod table_diff;
 mod undefined_struct;
+mod undefined_struct_reader;
+mod validation;
 
+pub use bcd::*;
 pub use entry_point::*;
+pub(crate) use flags::flag_set;
 pub use header::*;
+pub use redaction::*;
 pub use smbios_data::*;
 pub use strings::*;
+pub use table_builder::*;
+pub use table_diff::*;
 pub use undefined_struct::*;
+pub use undefined_struct_reader::*;
+pub use validation::*;
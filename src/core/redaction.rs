@@ -0,0 +1,258 @@
+use super::smbios_data::SMBiosData;
+use super::undefined_struct::UndefinedStruct;
+
+/// # Redaction
+///
+/// How [SMBiosData::redacted] replaces identifying values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+    /// Strings are replaced with "REDACTED", and UUIDs and numeric serial
+    /// numbers are zeroed
+    Blank,
+    /// Values are replaced with a hash of the salt and the original value
+    ///
+    /// Identical values hash identically, so redacted tables from one fleet can
+    /// still be correlated.  The hash is not cryptographic; keep the salt private
+    /// to prevent guessing short serial numbers.
+    Hash {
+        /// Prefix mixed into every hash
+        salt: Vec<u8>,
+    },
+}
+
+/// The string fields (type, offsets) holding serial numbers and asset tags
+const IDENTIFYING_STRINGS: &[(u8, &[usize])] = &[
+    // System Information: serial number
+    (1, &[0x07]),
+    // Baseboard Information: serial number, asset tag
+    (2, &[0x07, 0x08]),
+    // System Enclosure or Chassis: serial number, asset tag
+    (3, &[0x07, 0x08]),
+    // Processor Information: serial number, asset tag
+    (4, &[0x20, 0x21]),
+    // Memory Device: serial number, asset tag
+    (17, &[0x18, 0x19]),
+    // Portable Battery: serial number
+    (22, &[0x07]),
+    // System Power Supply: serial number, asset tag
+    (39, &[0x08, 0x09]),
+];
+
+/// OEM Strings type, whose strings are redacted when they contain a MAC address
+const OEM_STRINGS_TYPE: u8 = 11;
+
+/// System Information UUID field range
+const SYSTEM_UUID: (u8, usize, usize) = (1, 0x08, 0x18);
+
+/// Portable Battery SBDS Serial Number field
+const SBDS_SERIAL_NUMBER: (u8, usize) = (22, 0x10);
+
+impl SMBiosData {
+    /// Produces a copy of the table with identifying data redacted
+    ///
+    /// Serial numbers, asset tags, the system UUID, and OEM strings containing a
+    /// MAC address are replaced according to `redaction`.  A string shared with
+    /// another field is redacted for both.  All other bytes are kept, so the
+    /// result can be serialized with [SMBiosData::to_bytes].
+    pub fn redacted(&self, redaction: &Redaction) -> SMBiosData {
+        let mut data = Vec::new();
+        for undefined_struct in self.iter() {
+            data.extend(redact_struct(undefined_struct, redaction).to_bytes());
+        }
+        SMBiosData::from_vec_and_version(data, self.version)
+    }
+}
+
+fn redact_struct(undefined_struct: &UndefinedStruct, redaction: &Redaction) -> UndefinedStruct {
+    let mut redacted = undefined_struct.clone();
+    let struct_type = undefined_struct.header.struct_type();
+
+    // String numbers to redact
+    let mut string_numbers: Vec<u8> = IDENTIFYING_STRINGS
+        .iter()
+        .filter(|(identifying_type, _)| *identifying_type == struct_type)
+        .flat_map(|(_, offsets)| offsets.iter())
+        .filter_map(|offset| undefined_struct.get_field_byte(*offset))
+        .collect();
+    if struct_type == OEM_STRINGS_TYPE {
+        string_numbers.extend(
+            undefined_struct
+                .strings
+                .iter()
+                .enumerate()
+                .filter(|(_, string)| contains_mac_address(string))
+                .map(|(index, _)| (index + 1) as u8),
+        );
+    }
+
+    for string_number in string_numbers {
+        if let Ok(string) = undefined_struct.strings.get_bytes(string_number) {
+            let replacement = match redaction {
+                Redaction::Blank => b"REDACTED".to_vec(),
+                Redaction::Hash { salt } => format!("{:016X}", hash(salt, string, 0)).into_bytes(),
+            };
+            redacted.strings.replace_bytes(string_number, replacement);
+        }
+    }
+
+    let (uuid_type, uuid_start, uuid_end) = SYSTEM_UUID;
+    if struct_type == uuid_type {
+        if let Some(uuid) = redacted.fields.get_mut(uuid_start..uuid_end) {
+            let replacement = match redaction {
+                Redaction::Blank => [0u8; 0x10],
+                Redaction::Hash { salt } => {
+                    let mut replacement = [0u8; 0x10];
+                    replacement[..8].copy_from_slice(&hash(salt, uuid, 1).to_le_bytes());
+                    replacement[8..].copy_from_slice(&hash(salt, uuid, 2).to_le_bytes());
+                    replacement
+                }
+            };
+            uuid.copy_from_slice(&replacement);
+        }
+    }
+
+    let (battery_type, sbds_offset) = SBDS_SERIAL_NUMBER;
+    if struct_type == battery_type {
+        if let Some(serial) = redacted.fields.get_mut(sbds_offset..sbds_offset + 2) {
+            let replacement = match redaction {
+                Redaction::Blank => 0u16,
+                Redaction::Hash { salt } => hash(salt, serial, 0) as u16,
+            };
+            serial.copy_from_slice(&replacement.to_le_bytes());
+        }
+    }
+
+    redacted
+}
+
+/// 64-bit FNV-1a hash of the salt, a domain separator, and the value
+///
+/// A fixed algorithm is used so hashes are stable across releases.
+fn hash(salt: &[u8], value: &[u8], domain: u8) -> u64 {
+    salt.iter()
+        .chain(&[0, domain])
+        .chain(value)
+        .fold(0xCBF2_9CE4_8422_2325u64, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+}
+
+/// Tests for a MAC address written as six hex octets separated by ':' or '-',
+/// or as twelve consecutive hex digits
+fn contains_mac_address(string: &[u8]) -> bool {
+    let is_hex = |index: usize| string.get(index).is_some_and(u8::is_ascii_hexdigit);
+
+    (0..string.len()).any(|start| {
+        if start > 0 && is_hex(start - 1) {
+            return false;
+        }
+
+        let separated = [b':', b'-'].iter().any(|separator| {
+            (0..6).all(|octet| {
+                let index = start + octet * 3;
+                is_hex(index)
+                    && is_hex(index + 1)
+                    && (octet == 5 || string.get(index + 2) == Some(separator))
+            }) && !is_hex(start + 17)
+        });
+        let consecutive = (start..start + 12).all(is_hex) && !is_hex(start + 12);
+
+        separated || consecutive
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "memory")]
+    use crate::core::{StructBuilder, TableBuilder};
+    #[cfg(feature = "memory")]
+    use crate::structs::{
+        MemoryDeviceBuilder, SMBiosMemoryDevice, SMBiosStruct, SMBiosSystemInformation,
+        SystemInformationBuilder,
+    };
+
+    #[cfg(feature = "memory")]
+    fn table() -> SMBiosData {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            SystemInformationBuilder::new()
+                .manufacturer("Maker")
+                .serial_number("SN0001")
+                .uuid([0x11; 0x10]),
+        );
+        builder.add(
+            MemoryDeviceBuilder::new()
+                .device_locator("DIMM_A1")
+                .serial_number("72091003")
+                .asset_tag("72091003"),
+        );
+        builder.add(
+            StructBuilder::new(OEM_STRINGS_TYPE)
+                .byte(0x04, 3)
+                .push_string("Product line A")
+                .push_string("MAC:00:1a:2B:3c:4D:5e")
+                .push_string("Board 001A2B3C4D5E"),
+        );
+        builder.build_smbios_data(None)
+    }
+
+    #[test]
+    #[cfg(feature = "memory")]
+    fn test_redacted() {
+        let data = table();
+
+        let redacted = data.redacted(&Redaction::Blank);
+        let system_information = redacted.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(system_information.manufacturer().to_string(), "Maker");
+        assert_eq!(system_information.serial_number().to_string(), "REDACTED");
+        assert_eq!(
+            system_information.parts().get_field_data(0x08, 0x18),
+            Some(&[0u8; 0x10][..])
+        );
+        let memory_device = redacted.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert_eq!(memory_device.device_locator().to_string(), "DIMM_A1");
+        assert_eq!(memory_device.asset_tag().to_string(), "REDACTED");
+        let oem_strings: Vec<String> = redacted
+            .iter()
+            .nth(2)
+            .unwrap()
+            .strings
+            .iter()
+            .map(|string| String::from_utf8(string.clone()).unwrap())
+            .collect();
+        assert_eq!(oem_strings, vec!["Product line A", "REDACTED", "REDACTED"]);
+
+        assert_eq!(
+            redacted.redacted(&Redaction::Blank).to_bytes(),
+            redacted.to_bytes()
+        );
+
+        let salt = Redaction::Hash {
+            salt: b"fleet".to_vec(),
+        };
+        let hashed = data.redacted(&salt);
+        let system_information = hashed.first::<SMBiosSystemInformation<'_>>().unwrap();
+        let serial_number = system_information.serial_number().to_string();
+        assert_eq!(serial_number.len(), 16);
+        assert_ne!(serial_number, "SN0001");
+        assert_eq!(hashed.to_bytes(), data.redacted(&salt).to_bytes());
+        assert_ne!(
+            hashed.to_bytes(),
+            data.redacted(&Redaction::Hash {
+                salt: b"other".to_vec()
+            })
+            .to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_contains_mac_address() {
+        assert!(contains_mac_address(b"00:1A:2B:3C:4D:5E"));
+        assert!(contains_mac_address(b"nic0=00-1a-2b-3c-4d-5e;"));
+        assert!(contains_mac_address(b"001A2B3C4D5E"));
+        assert!(!contains_mac_address(b"00:1A:2B:3C:4D"));
+        assert!(!contains_mac_address(b"001A2B3C4D5E6F"));
+        assert!(!contains_mac_address(b"Default string"));
+    }
+}
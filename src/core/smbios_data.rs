@@ -2,16 +2,24 @@ use super::header::Handle;
 use super::undefined_struct::{UndefinedStruct, UndefinedStructTable};
 use crate::structs::{DefinedStructTable, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::io::Error;
+use serde_json::{json, Value};
+use std::fmt;
 use std::{cmp::Ordering, slice::Iter};
-use std::{fmt, fs::read};
+#[cfg(feature = "os-loader")]
+use std::{fs::read, io::Error};
 
 /// # SMBIOS Data
 ///
 /// Contains an optional SMBIOS version and a collection of SMBIOS structures.
+///
+/// The structures own their bytes, so [SMBiosData] can be stored and passed
+/// around independently of the buffer it was loaded from.
+#[derive(Clone)]
 pub struct SMBiosData {
     table: UndefinedStructTable,
     /// Version of the contained SMBIOS structures.
+    ///
+    /// The structures record the version given when the [SMBiosData] is created.
     pub version: Option<SMBiosVersion>,
 }
 
@@ -20,7 +28,8 @@ impl<'a> SMBiosData {
     ///
     /// `table` is iterable table data.
     /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
-    pub fn new(table: UndefinedStructTable, version: Option<SMBiosVersion>) -> Self {
+    pub fn new(mut table: UndefinedStructTable, version: Option<SMBiosVersion>) -> Self {
+        table.set_version(version);
         Self { table, version }
     }
 
@@ -29,23 +38,36 @@ impl<'a> SMBiosData {
     /// `data` is a block of bytes representing the raw table data.
     /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
     pub fn from_vec_and_version(data: Vec<u8>, version: Option<SMBiosVersion>) -> Self {
-        Self {
-            table: UndefinedStructTable::from(data),
-            version,
-        }
+        Self::new(UndefinedStructTable::from(data), version)
     }
 
     /// Loads raw SMBios table data from a file
+    #[cfg(feature = "os-loader")]
     pub fn try_load_from_file(
         filename: &str,
         version: Option<SMBiosVersion>,
     ) -> Result<SMBiosData, Error> {
         let data = read(filename)?;
-        let result = Self {
-            table: UndefinedStructTable::from(data),
-            version,
-        };
-        Ok(result)
+        Ok(Self::new(UndefinedStructTable::from(data), version))
+    }
+
+    /// Tests if fields introduced in the `since` specification version are expected
+    ///
+    /// Returns `false` only when the table version is known and predates `since`.
+    /// The version is also recorded in each structure, whose accessors report
+    /// fields added after it as absent (see [UndefinedStruct::supports]).
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// let data = SMBiosData::from_vec_and_version(vec![], Some(SMBiosVersion::new(2, 6, 0)));
+    /// assert!(data.supports(SMBiosVersion::new(2, 3, 0)));
+    /// assert!(!data.supports(SMBiosVersion::new(2, 7, 0)));
+    /// ```
+    pub fn supports(&self, since: SMBiosVersion) -> bool {
+        match self.version {
+            Some(version) => version >= since,
+            None => true,
+        }
     }
 
     /// Iterator of the contained [UndefinedStruct] items
@@ -137,6 +159,14 @@ impl<'a> SMBiosData {
         self.table.find_by_handle(handle)
     }
 
+    /// Finds the structure matching the given handle if it is of the defined type `T`
+    pub fn find_by_handle_as<T>(&'a self, handle: &Handle) -> Option<T>
+    where
+        T: SMBiosStruct<'a>,
+    {
+        self.table.find_by_handle_as(handle)
+    }
+
     /// Finds all occurances of the structure
     pub fn collect<T>(&'a self) -> Vec<T>
     where
@@ -144,6 +174,40 @@ impl<'a> SMBiosData {
     {
         self.table.collect()
     }
+
+    /// Serializes the table back to its raw SMBIOS byte layout
+    ///
+    /// Bytes following the last complete structure of the loaded data are not included.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.table.to_bytes()
+    }
+
+    /// Exports the table as a JSON array with one object per structure
+    ///
+    /// Each object contains the structure `type` and `handle` numbers, the
+    /// decoded `fields` of the [DefinedStruct](crate::DefinedStruct), and the raw `strings`.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let structures = self
+            .iter()
+            .map(|undefined_struct| {
+                Ok(json!({
+                    "type": undefined_struct.header.struct_type(),
+                    "handle": *undefined_struct.header.handle(),
+                    "fields": serde_json::to_value(undefined_struct.defined_struct())?,
+                    "strings": serde_json::to_value(&undefined_struct.strings)?,
+                }))
+            })
+            .collect::<serde_json::Result<Vec<Value>>>()?;
+
+        serde_json::to_string(&structures)
+    }
+}
+
+impl From<Vec<u8>> for SMBiosData {
+    /// Creates [SMBiosData] from raw table data of an unknown version
+    fn from(data: Vec<u8>) -> Self {
+        Self::from_vec_and_version(data, None)
+    }
 }
 
 impl IntoIterator for SMBiosData {
@@ -155,6 +219,15 @@ impl IntoIterator for SMBiosData {
     }
 }
 
+impl<'a> IntoIterator for &'a SMBiosData {
+    type Item = &'a UndefinedStruct;
+    type IntoIter = Iter<'a, UndefinedStruct>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl fmt::Debug for SMBiosData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Convert to defined structures to see the structure fields
@@ -195,7 +268,7 @@ pub struct SMBiosVersion {
 
 impl SMBiosVersion {
     /// Creates a new [SMBiosVersion] struct
-    pub fn new(major: u8, minor: u8, revision: u8) -> SMBiosVersion {
+    pub const fn new(major: u8, minor: u8, revision: u8) -> SMBiosVersion {
         SMBiosVersion {
             major,
             minor,
@@ -229,3 +302,67 @@ impl PartialOrd for SMBiosVersion {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "platform")]
+    use crate::structs::SMBiosSystemChassisInformation;
+
+    #[test]
+    #[cfg(feature = "platform")]
+    fn test_version_gating() {
+        assert!(SMBiosVersion::new(2, 7, 0) > SMBiosVersion::new(2, 6, 1));
+        assert!(SMBiosVersion::new(3, 0, 0) > SMBiosVersion::new(2, 8, 0));
+        assert_eq!(SMBiosVersion::new(3, 2, 0), SMBiosVersion::new(3, 2, 0));
+
+        // Type 3 with a SKU number string reference (added in 2.7) and no contained elements
+        let raw = vec![
+            0x03, 0x16, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, b'S', b'K', b'U', 0x00, 0x00,
+        ];
+
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(2, 6, 0)));
+        let chassis = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert!(!data.supports(SMBiosVersion::new(2, 7, 0)));
+        assert!(chassis.sku_number().ok().is_none());
+
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(2, 7, 0)));
+        assert!(data.supports(SMBiosVersion::new(2, 7, 0)));
+        let chassis = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert_eq!(chassis.sku_number().to_string(), "SKU");
+
+        // An unknown version does not restrict fields
+        let data = SMBiosData::from_vec_and_version(raw, None);
+        assert!(data.supports(SMBiosVersion::new(3, 7, 0)));
+        let chassis = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert_eq!(chassis.sku_number().to_string(), "SKU");
+    }
+
+    #[test]
+    fn test_to_json() {
+        // Type 11 (OEM Strings) with two strings, followed by End-of-Table
+        let raw = vec![
+            0x0B, 0x05, 0x04, 0x00, 0x02, b'A', 0x00, b'B', 0x00, 0x00, 0x7F, 0x04, 0x05, 0x00,
+            0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(raw, Some(SMBiosVersion::new(3, 0, 0)));
+
+        let json: Value = serde_json::from_str(&data.to_json().unwrap()).unwrap();
+        let structures = json.as_array().unwrap();
+        assert_eq!(structures.len(), 2);
+
+        assert_eq!(structures[0]["type"], 11);
+        assert_eq!(structures[0]["handle"], 4);
+        assert_eq!(structures[0]["strings"], json!(["A", "B"]));
+        #[cfg(feature = "oem")]
+        assert_eq!(structures[0]["fields"]["OemStrings"]["count"], 2);
+        // Without its decoder the structure is serialized as undefined
+        #[cfg(not(feature = "oem"))]
+        assert!(structures[0]["fields"]["Undefined"].is_object());
+
+        assert_eq!(structures[1]["type"], 127);
+        assert_eq!(structures[1]["handle"], 5);
+        assert_eq!(structures[1]["strings"], json!([]));
+    }
+}
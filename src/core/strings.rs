@@ -5,6 +5,7 @@ use std::{fmt, string::FromUtf8Error};
 /// # SMBIOS String-Set
 ///
 /// The string-set part/section of an SMBIOS structure
+#[derive(Clone)]
 pub struct SMBiosStringSet {
     strings: Vec<Vec<u8>>,
     current_string_index: usize,
@@ -15,7 +16,7 @@ impl SMBiosStringSet {
     pub fn new(string_area: Vec<u8>) -> SMBiosStringSet {
         SMBiosStringSet {
             strings: {
-                if string_area == &[] {
+                if string_area.is_empty() {
                     vec![]
                 } else {
                     string_area
@@ -68,6 +69,60 @@ impl SMBiosStringSet {
         }
     }
 
+    /// Returns a [String] at the given 1 based `index` decoded with the given policy
+    ///
+    /// [SMBiosStringDecoding::Utf8] behaves as [SMBiosStringSet::get_string].
+    pub fn get_string_with(&self, index: u8, decoding: SMBiosStringDecoding) -> SMBiosString {
+        SMBiosString {
+            value: self
+                .get_bytes(index)
+                .and_then(|bytes| decoding.decode(bytes)),
+        }
+    }
+
+    /// Returns the raw bytes of the string at the given 1 based `index`
+    ///
+    /// The terminating 0 is not included.  If the index is 0 an empty slice is returned.
+    pub fn get_bytes(&self, index: u8) -> Result<&[u8], SMBiosStringError> {
+        match index {
+            0 => Ok(&[]),
+            _ => self
+                .strings
+                .get(index as usize - 1)
+                .map(|string| string.as_slice())
+                .ok_or(SMBiosStringError::InvalidStringNumber(index)),
+        }
+    }
+
+    /// Returns a UTF-8 [str] borrowed from the string-set at the given 1 based `index`
+    ///
+    /// This is the non-allocating equivalent of [SMBiosStringSet::get_string]; only
+    /// a SMBiosStringError::Utf8 error copies the offending bytes.
+    pub fn get_str(&self, index: u8) -> Result<&str, SMBiosStringError> {
+        let bytes = self.get_bytes(index)?;
+        std::str::from_utf8(bytes).map_err(|_| {
+            String::from_utf8(bytes.to_vec())
+                .expect_err("invalid UTF-8")
+                .into()
+        })
+    }
+
+    /// Replaces the string at the given 1 based `index`, returning false if there is no such string
+    ///
+    /// `value` must be non-empty and contain no 0 bytes for the string-set to remain valid.
+    pub(crate) fn replace_bytes(&mut self, index: u8, value: Vec<u8>) -> bool {
+        match (index as usize)
+            .checked_sub(1)
+            .and_then(|index| self.strings.get_mut(index))
+        {
+            Some(string) => {
+                *string = value;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Iterates the raw bytes of the strings. The terminating 0 is not included in each string.
     pub fn iter(&self) -> std::slice::Iter<'_, Vec<u8>> {
         self.strings.iter()
@@ -127,6 +182,36 @@ impl Serialize for SMBiosStringSet {
     }
 }
 
+/// # SMBiosStringDecoding
+///
+/// How the bytes of an SMBIOS string are interpreted
+///
+/// SMBIOS 3.5.0 specifies UTF-8, while older firmware commonly ships
+/// ISO-8859-1 (Latin-1) or otherwise invalid bytes.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SMBiosStringDecoding {
+    /// Strict UTF-8; invalid bytes produce SMBiosStringError::Utf8 (default)
+    #[default]
+    Utf8,
+    /// UTF-8 with invalid sequences replaced by U+FFFD
+    Utf8Lossy,
+    /// ISO-8859-1, each byte maps to the Unicode code point of the same value
+    Latin1,
+}
+
+impl SMBiosStringDecoding {
+    /// Decodes the given string bytes (without the terminating 0)
+    pub fn decode(&self, bytes: &[u8]) -> Result<String, SMBiosStringError> {
+        match self {
+            SMBiosStringDecoding::Utf8 => {
+                String::from_utf8(bytes.to_vec()).map_err(|err| err.into())
+            }
+            SMBiosStringDecoding::Utf8Lossy => Ok(String::from_utf8_lossy(bytes).into_owned()),
+            SMBiosStringDecoding::Latin1 => Ok(bytes.iter().map(|&byte| byte as char).collect()),
+        }
+    }
+}
+
 /// # SMBiosStringError
 ///
 /// An SMBIOS String retrival error
@@ -279,6 +364,55 @@ impl Serialize for SMBiosString {
     }
 }
 
+/// # Counted String Iterator
+///
+/// Iterates strings 1 through `count` of a structure's string-set, for
+/// structures whose strings are only described by a count field, such as
+/// OEM Strings (Type 11), System Configuration Options (Type 12) and the
+/// installable languages of BIOS Language Information (Type 13).
+#[cfg(any(feature = "oem", feature = "platform"))]
+pub struct CountedStringIterator<'a> {
+    strings: &'a SMBiosStringSet,
+    current_index: u8,
+    count: u8,
+}
+
+#[cfg(any(feature = "oem", feature = "platform"))]
+impl<'a> CountedStringIterator<'a> {
+    pub(crate) fn new(strings: &'a SMBiosStringSet, count: u8) -> Self {
+        CountedStringIterator {
+            strings,
+            current_index: 0,
+            count,
+        }
+    }
+}
+
+#[cfg(any(feature = "oem", feature = "platform"))]
+impl<'a> Iterator for CountedStringIterator<'a> {
+    type Item = SMBiosString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_index == self.count {
+            return None;
+        }
+
+        // String numbers are 1-based
+        self.current_index += 1;
+        Some(self.strings.get_string(self.current_index))
+    }
+}
+
+#[cfg(any(feature = "oem", feature = "platform"))]
+impl fmt::Debug for CountedStringIterator<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<CountedStringIterator<'_>>())
+            .field("current_index", &self.current_index)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,4 +460,75 @@ mod tests {
         let fourth_string = string_iterator.next().unwrap().value.unwrap();
         assert_eq!(fourth_string, "ja|JP|unicode".to_string());
     }
+
+    #[test]
+    fn test_borrowed_strings() {
+        let string_set =
+            SMBiosStringSet::new(vec![b'A', b'B', 0x00, b'E', b'=', 1, 159, 0x00, b'C']);
+
+        assert_eq!(string_set.get_str(0).unwrap(), "");
+        assert_eq!(string_set.get_str(1).unwrap(), "AB");
+        assert_eq!(string_set.get_str(3).unwrap(), "C");
+        assert_eq!(string_set.get_bytes(2).unwrap(), &[b'E', b'=', 1, 159]);
+
+        match string_set.get_str(2) {
+            Err(SMBiosStringError::Utf8(utf8)) => assert_eq!(3, utf8.utf8_error().valid_up_to()),
+            _ => panic!("This should have been a UTF8 error"),
+        }
+
+        assert!(matches!(
+            string_set.get_str(4),
+            Err(SMBiosStringError::InvalidStringNumber(4))
+        ));
+    }
+
+    #[test]
+    fn test_string_decoding() {
+        // "Müller" in ISO-8859-1, then "Müller" in UTF-8
+        let string_set = SMBiosStringSet::new(vec![
+            b'M', 0xFC, b'l', b'l', b'e', b'r', 0x00, b'M', 0xC3, 0xBC, b'l', b'l', b'e', b'r',
+        ]);
+
+        assert!(string_set
+            .get_string_with(1, SMBiosStringDecoding::Utf8)
+            .is_err());
+        assert_eq!(
+            string_set
+                .get_string_with(1, SMBiosStringDecoding::Latin1)
+                .ok(),
+            Some("Müller".to_string())
+        );
+        assert_eq!(
+            string_set
+                .get_string_with(1, SMBiosStringDecoding::Utf8Lossy)
+                .ok(),
+            Some("M\u{FFFD}ller".to_string())
+        );
+
+        assert_eq!(
+            string_set
+                .get_string_with(2, SMBiosStringDecoding::default())
+                .ok(),
+            Some("Müller".to_string())
+        );
+        assert_eq!(
+            string_set
+                .get_string_with(2, SMBiosStringDecoding::Latin1)
+                .ok(),
+            Some("MÃ¼ller".to_string())
+        );
+
+        assert_eq!(
+            string_set
+                .get_string_with(0, SMBiosStringDecoding::Latin1)
+                .ok(),
+            Some(String::new())
+        );
+        assert!(matches!(
+            string_set
+                .get_string_with(3, SMBiosStringDecoding::Utf8Lossy)
+                .err(),
+            Some(SMBiosStringError::InvalidStringNumber(3))
+        ));
+    }
 }
@@ -0,0 +1,380 @@
+use super::entry_point::SMBiosEntryPoint64;
+use super::header::{Handle, Header};
+use super::smbios_data::{SMBiosData, SMBiosVersion};
+
+/// # Structure Builder
+///
+/// Builds the raw bytes of a single SMBIOS structure.
+///
+/// Fields are written at their specification offsets (relative to the start
+/// of the header).  The formatted portion grows to fit the fields written and
+/// string fields are added to the string-set with their string number
+/// written to the field.
+///
+/// ```
+/// # use smbioslib::*;
+/// let mut table = TableBuilder::new();
+/// let handle = table.add(
+///     StructBuilder::new(SMBiosSystemInformation::STRUCT_TYPE)
+///         .string(0x04, "Manufacturer")
+///         .string(0x05, "Product"),
+/// );
+/// let data = table.build_smbios_data(None);
+///
+/// let system_information = data.first::<SMBiosSystemInformation>().unwrap();
+/// assert_eq!(system_information.parts().header.handle(), handle);
+/// assert_eq!(system_information.product_name().to_string(), "Product");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructBuilder {
+    fields: Vec<u8>,
+    strings: Vec<String>,
+}
+
+impl StructBuilder {
+    /// Creates a builder for a structure of the given type
+    pub fn new(struct_type: u8) -> Self {
+        Self::with_length(struct_type, Header::SIZE as u8)
+    }
+
+    /// Creates a builder for a structure of the given type with a zero filled
+    /// formatted portion of `length` bytes (including the header)
+    pub fn with_length(struct_type: u8, length: u8) -> Self {
+        let mut fields = vec![0u8; (length as usize).max(Header::SIZE)];
+        fields[Header::STRUCT_TYPE_OFFSET] = struct_type;
+        StructBuilder {
+            fields,
+            strings: Vec::new(),
+        }
+    }
+
+    /// Writes `value` at the given offset
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is within the header or extends beyond the maximum
+    /// structure length of 255 bytes.
+    pub fn data(mut self, offset: usize, value: &[u8]) -> Self {
+        let end = offset + value.len();
+        assert!(
+            offset >= Header::SIZE,
+            "offset {:#04X} is within the header",
+            offset
+        );
+        assert!(
+            end <= u8::MAX as usize,
+            "field at offset {:#04X} exceeds the maximum structure length",
+            offset
+        );
+
+        if self.fields.len() < end {
+            self.fields.resize(end, 0);
+        }
+        self.fields[offset..end].copy_from_slice(value);
+        self
+    }
+
+    /// Writes a byte at the given offset
+    pub fn byte(self, offset: usize, value: u8) -> Self {
+        self.data(offset, &[value])
+    }
+
+    /// Writes a WORD at the given offset
+    pub fn word(self, offset: usize, value: u16) -> Self {
+        self.data(offset, &value.to_le_bytes())
+    }
+
+    /// Writes a DWORD at the given offset
+    pub fn dword(self, offset: usize, value: u32) -> Self {
+        self.data(offset, &value.to_le_bytes())
+    }
+
+    /// Writes a QWORD at the given offset
+    pub fn qword(self, offset: usize, value: u64) -> Self {
+        self.data(offset, &value.to_le_bytes())
+    }
+
+    /// Writes a [Handle] at the given offset
+    pub fn handle(self, offset: usize, value: Handle) -> Self {
+        self.word(offset, *value)
+    }
+
+    /// Adds `value` to the string-set and writes its string number at the given offset
+    ///
+    /// An empty string writes string number 0 (no string).  Identical strings
+    /// share a single entry in the string-set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` contains a NUL character or the string-set already
+    /// holds 255 strings.
+    pub fn string(mut self, offset: usize, value: &str) -> Self {
+        let string_number = self.add_string(value);
+        self.byte(offset, string_number)
+    }
+
+    /// Appends `value` to the string-set without referencing it from a field
+    ///
+    /// For structures whose strings are counted rather than numbered, such as
+    /// OEM Strings (Type 11).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty, contains a NUL character or the string-set
+    /// already holds 255 strings.
+    pub fn push_string(mut self, value: &str) -> Self {
+        assert!(!value.is_empty(), "strings of a string-set cannot be empty");
+        assert!(
+            !value.contains('\0'),
+            "strings of a string-set cannot contain NUL"
+        );
+        assert!(self.strings.len() < u8::MAX as usize, "too many strings");
+        self.strings.push(value.to_string());
+        self
+    }
+
+    /// Adds `value` to the string-set (if not already present) and returns its string number
+    fn add_string(&mut self, value: &str) -> u8 {
+        if value.is_empty() {
+            return 0;
+        }
+        assert!(
+            !value.contains('\0'),
+            "strings of a string-set cannot contain NUL"
+        );
+
+        let position = match self.strings.iter().position(|string| string == value) {
+            Some(position) => position,
+            None => {
+                self.strings.push(value.to_string());
+                self.strings.len() - 1
+            }
+        };
+
+        assert!(position < u8::MAX as usize, "too many strings");
+        (position + 1) as u8
+    }
+
+    /// Produces the raw bytes of the structure with the given handle
+    pub fn build(&self, handle: Handle) -> Vec<u8> {
+        let mut raw = self.fields.clone();
+        raw[Header::LENGTH_OFFSET] = self.fields.len() as u8;
+        raw[Header::HANDLE_OFFSET..Header::HANDLE_OFFSET + Handle::SIZE]
+            .copy_from_slice(&handle.to_le_bytes());
+
+        if self.strings.is_empty() {
+            raw.push(0);
+        }
+        for string in &self.strings {
+            raw.extend_from_slice(string.as_bytes());
+            raw.push(0);
+        }
+        raw.push(0);
+
+        raw
+    }
+}
+
+/// # Table Builder
+///
+/// Builds a raw SMBIOS structure table from [StructBuilder] structures.
+///
+/// Handles are assigned in the order structures are added, starting at 0, and
+/// the End-of-Table (type 127) structure is appended when the table is built.
+/// The last handle below the reserved range is kept for End-of-Table.
+#[derive(Debug, Clone, Default)]
+pub struct TableBuilder {
+    data: Vec<u8>,
+    next_handle: u16,
+}
+
+impl TableBuilder {
+    /// The highest handle End-of-Table can be given, below [Handle::NOT_PROVIDED]
+    const END_OF_TABLE_HANDLE: u16 = Handle::NOT_PROVIDED.0 - 1;
+
+    /// Creates an empty table builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a structure, returning the [Handle] assigned to it
+    ///
+    /// Accepts a [StructBuilder] or a typed builder such as
+    /// [MemoryDeviceBuilder](crate::MemoryDeviceBuilder).
+    ///
+    /// # Panics
+    ///
+    /// Panics once the handles below the reserved [Handle::NOT_PROVIDED] and
+    /// [Handle::NONE] values, less the one kept for End-of-Table, are exhausted.
+    pub fn add(&mut self, structure: impl Into<StructBuilder>) -> Handle {
+        assert!(
+            self.next_handle < Self::END_OF_TABLE_HANDLE,
+            "no handles left below the reserved range"
+        );
+        let handle = Handle(self.next_handle);
+        self.data.extend(structure.into().build(handle));
+        self.next_handle += 1;
+        handle
+    }
+
+    /// Produces the raw table bytes, terminated by an End-of-Table structure
+    pub fn build(mut self) -> Vec<u8> {
+        self.data
+            .extend(StructBuilder::new(127).build(Handle(self.next_handle)));
+        self.data
+    }
+
+    /// Produces the table as [SMBiosData] of the given version
+    pub fn build_smbios_data(self, version: Option<SMBiosVersion>) -> SMBiosData {
+        SMBiosData::from_vec_and_version(self.build(), version)
+    }
+
+    /// Produces a 64-bit (_SM3_) entry point and the raw table bytes
+    ///
+    /// `table_address` is the physical address the table will be placed at.
+    pub fn build_with_entry_point(
+        self,
+        version: SMBiosVersion,
+        table_address: u64,
+    ) -> (Vec<u8>, Vec<u8>) {
+        let table = self.build();
+        let entry_point = entry_point_64_bytes(version, table.len(), table_address);
+        (entry_point, table)
+    }
+}
+
+/// Produces a 64-bit (_SM3_) entry point for a table of `table_length` bytes at `table_address`
+pub(crate) fn entry_point_64_bytes(
+    version: SMBiosVersion,
+    table_length: usize,
+    table_address: u64,
+) -> Vec<u8> {
+    let mut entry_point = SMBiosEntryPoint64::SM3_ANCHOR.to_vec();
+    entry_point.resize(SMBiosEntryPoint64::MINIMUM_SIZE, 0);
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET] =
+        SMBiosEntryPoint64::MINIMUM_SIZE as u8;
+    entry_point[SMBiosEntryPoint64::MAJOR_VERSION_OFFSET] = version.major;
+    entry_point[SMBiosEntryPoint64::MINOR_VERSION_OFFSET] = version.minor;
+    entry_point[SMBiosEntryPoint64::DOCREV_OFFSET] = version.revision;
+    // Entry point revision 1 (SMBIOS 3.0)
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_REVISION_OFFSET] = 0x01;
+    entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET
+        ..SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET + 4]
+        .copy_from_slice(&(table_length as u32).to_le_bytes());
+    entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET
+        ..SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET + 8]
+        .copy_from_slice(&table_address.to_le_bytes());
+
+    let sum = entry_point
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] = 0u8.wrapping_sub(sum);
+
+    entry_point
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "platform")]
+    use crate::structs::{SMBiosEndOfTable, SMBiosStruct, SMBiosSystemChassisInformation};
+    #[cfg(feature = "platform")]
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_struct_builder() {
+        let raw = StructBuilder::with_length(3, 0x09)
+            .string(0x04, "Maker")
+            .byte(0x05, 0x0A)
+            .string(0x06, "")
+            .string(0x07, "Maker")
+            .string(0x08, "Tag")
+            .build(Handle(0x0300));
+
+        assert_eq!(
+            raw,
+            vec![
+                0x03, 0x09, 0x00, 0x03, 0x01, 0x0A, 0x00, 0x01, 0x02, b'M', b'a', b'k', b'e', b'r',
+                0x00, b'T', b'a', b'g', 0x00, 0x00
+            ]
+        );
+
+        let raw = StructBuilder::new(0x80)
+            .dword(0x06, 0x12345678)
+            .build(Handle(1));
+        assert_eq!(
+            raw,
+            vec![0x80, 0x0A, 0x01, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_struct_builder_header_write() {
+        StructBuilder::new(1).byte(0x02, 0xFF);
+    }
+
+    #[test]
+    #[should_panic(expected = "no handles left")]
+    fn test_table_builder_reserved_handles() {
+        let mut builder = TableBuilder::new();
+        builder.next_handle = 0xFFFC;
+        assert_eq!(builder.add(StructBuilder::new(0x80)), Handle(0xFFFC));
+        builder.add(StructBuilder::new(0x80));
+    }
+
+    #[test]
+    fn test_table_builder_end_of_table_handle() {
+        let mut builder = TableBuilder::new();
+        builder.next_handle = 0xFFFC;
+        builder.add(StructBuilder::new(0x80));
+        let data = builder.build_smbios_data(None);
+        let end_of_table = data.iter().last().unwrap();
+        assert_eq!(end_of_table.header.struct_type(), 127);
+        assert_eq!(*end_of_table.header.handle(), 0xFFFD);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot contain NUL")]
+    fn test_struct_builder_string_nul() {
+        StructBuilder::new(1).string(0x04, "one\0two");
+    }
+
+    #[test]
+    #[cfg(feature = "platform")]
+    fn test_table_builder() {
+        let mut builder = TableBuilder::new();
+        let chassis_handle = builder.add(
+            StructBuilder::with_length(3, 0x0D)
+                .string(0x04, "Maker")
+                .byte(0x05, 0x0A),
+        );
+        let oem_handle = builder.add(StructBuilder::new(0x80).handle(0x04, chassis_handle));
+        assert_eq!(oem_handle, Handle(1));
+
+        let (entry_point, table) = builder
+            .clone()
+            .build_with_entry_point(SMBiosVersion::new(3, 5, 0), 0x1000);
+        let entry_point = SMBiosEntryPoint64::try_from(entry_point).unwrap();
+        assert_eq!(entry_point.version(), SMBiosVersion::new(3, 5, 0));
+        assert_eq!(entry_point.structure_table_address(), 0x1000);
+        assert_eq!(
+            entry_point.structure_table_maximum_size() as usize,
+            table.len()
+        );
+
+        let data = builder.build_smbios_data(Some(SMBiosVersion::new(3, 5, 0)));
+        assert_eq!(data.to_bytes(), table);
+        assert_eq!(data.iter().count(), 3);
+
+        let chassis = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert_eq!(chassis.manufacturer().to_string(), "Maker");
+        assert_eq!(chassis.chassis_type().unwrap().raw, 0x0A);
+
+        let oem = data.find_by_handle(&Handle(1)).unwrap();
+        assert_eq!(oem.get_field_handle(0x04), Some(Handle(0)));
+
+        let end_of_table = data.first::<SMBiosEndOfTable<'_>>().unwrap();
+        assert_eq!(end_of_table.parts().header.handle(), Handle(2));
+    }
+}
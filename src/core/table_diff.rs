@@ -0,0 +1,279 @@
+use super::header::Handle;
+use super::smbios_data::SMBiosData;
+use super::undefined_struct::UndefinedStruct;
+use serde::Serialize;
+use serde_json::Value;
+
+/// # Structure Matching
+///
+/// How structures of two tables are paired when computing a [StructDiff].
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffMatching {
+    /// Structures with the same type and handle are compared
+    ///
+    /// Suited to comparing tables of the same system, where firmware assigns
+    /// stable handles.
+    TypeAndHandle,
+    /// Structures at the same position in each table are compared
+    ///
+    /// A structure whose type differs from its counterpart is reported as
+    /// removed and added.
+    Position,
+}
+
+/// # Structure Difference
+///
+/// A difference between two tables found by [SMBiosData::diff].
+#[derive(Serialize, Debug, PartialEq)]
+pub enum StructDiff {
+    /// A structure present only in the newer table
+    Added {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+    },
+    /// A structure present only in the older table
+    Removed {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+    },
+    /// A structure present in both tables with different content
+    ///
+    /// `fields` is empty when only bytes this library does not decode differ.
+    Changed {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle in the newer table
+        handle: Handle,
+        /// The decoded fields which differ
+        fields: Vec<FieldChange>,
+    },
+}
+
+/// # Field Change
+///
+/// A decoded field of a [StructDiff::Changed] structure, named and valued as in
+/// the serialized [DefinedStruct](crate::DefinedStruct).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// Field name
+    pub name: String,
+    /// Value in the older table, `Null` if absent
+    pub old: Value,
+    /// Value in the newer table, `Null` if absent
+    pub new: Value,
+}
+
+impl SMBiosData {
+    /// Compares this (older) table to a `newer` table structure-by-structure
+    ///
+    /// Removed structures are reported first in the order of this table,
+    /// followed by added and changed structures in the order of `newer`.
+    pub fn diff(&self, newer: &SMBiosData, matching: DiffMatching) -> Vec<StructDiff> {
+        let older: Vec<&UndefinedStruct> = self.iter().collect();
+        let newer: Vec<&UndefinedStruct> = newer.iter().collect();
+
+        // For each structure of `newer`, the index of its counterpart in `older`
+        let mut counterparts: Vec<Option<usize>> = vec![None; newer.len()];
+        let mut matched = vec![false; older.len()];
+
+        for (new_index, new_struct) in newer.iter().enumerate() {
+            let old_index = match matching {
+                DiffMatching::TypeAndHandle => {
+                    older
+                        .iter()
+                        .enumerate()
+                        .position(|(old_index, old_struct)| {
+                            !matched[old_index]
+                                && old_struct.header.struct_type()
+                                    == new_struct.header.struct_type()
+                                && old_struct.header.handle() == new_struct.header.handle()
+                        })
+                }
+                DiffMatching::Position => older
+                    .get(new_index)
+                    .filter(|old_struct| {
+                        old_struct.header.struct_type() == new_struct.header.struct_type()
+                    })
+                    .map(|_| new_index),
+            };
+
+            if let Some(old_index) = old_index {
+                matched[old_index] = true;
+                counterparts[new_index] = Some(old_index);
+            }
+        }
+
+        let mut differences: Vec<StructDiff> = older
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(old_struct, _)| StructDiff::Removed {
+                struct_type: old_struct.header.struct_type(),
+                handle: old_struct.header.handle(),
+            })
+            .collect();
+
+        for (new_struct, counterpart) in newer.iter().zip(counterparts) {
+            match counterpart {
+                None => differences.push(StructDiff::Added {
+                    struct_type: new_struct.header.struct_type(),
+                    handle: new_struct.header.handle(),
+                }),
+                Some(old_index) => {
+                    let old_struct = older[old_index];
+                    if old_struct.to_bytes() != new_struct.to_bytes() {
+                        differences.push(StructDiff::Changed {
+                            struct_type: new_struct.header.struct_type(),
+                            handle: new_struct.header.handle(),
+                            fields: field_changes(old_struct, new_struct),
+                        });
+                    }
+                }
+            }
+        }
+
+        differences
+    }
+}
+
+/// Compares the decoded fields of two structures
+fn field_changes(old_struct: &UndefinedStruct, new_struct: &UndefinedStruct) -> Vec<FieldChange> {
+    let old_fields = decoded_fields(old_struct);
+    let new_fields = decoded_fields(new_struct);
+
+    let mut changes: Vec<FieldChange> = new_fields
+        .iter()
+        .filter(|(name, new)| old_fields.get(*name).unwrap_or(&Value::Null) != *new)
+        .map(|(name, new)| FieldChange {
+            name: name.clone(),
+            old: old_fields.get(name).cloned().unwrap_or(Value::Null),
+            new: new.clone(),
+        })
+        .collect();
+
+    changes.extend(
+        old_fields
+            .iter()
+            .filter(|(name, _)| !new_fields.contains_key(*name))
+            .map(|(name, old)| FieldChange {
+                name: name.clone(),
+                old: old.clone(),
+                new: Value::Null,
+            }),
+    );
+
+    changes
+}
+
+/// The serialized fields of a structure's [DefinedStruct](crate::DefinedStruct)
+fn decoded_fields(undefined_struct: &UndefinedStruct) -> serde_json::Map<String, Value> {
+    match serde_json::to_value(undefined_struct.defined_struct()) {
+        // DefinedStruct serializes as an externally tagged enum: {"Variant": {fields}}
+        Ok(Value::Object(variant)) => match variant.into_iter().next() {
+            Some((_, Value::Object(fields))) => fields,
+            _ => serde_json::Map::new(),
+        },
+        _ => serde_json::Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Header, StructBuilder, TableBuilder};
+    use crate::structs::SystemInformationBuilder;
+
+    fn table(version: &str, with_oem: bool, with_chassis: bool) -> SMBiosData {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            SystemInformationBuilder::new()
+                .manufacturer("Maker")
+                .version(version),
+        );
+        if with_oem {
+            builder.add(StructBuilder::new(0x80).byte(0x04, 0x01));
+        }
+        if with_chassis {
+            builder.add(StructBuilder::with_length(3, 0x09).string(0x04, "Maker"));
+        }
+        builder.build_smbios_data(None)
+    }
+
+    #[test]
+    fn test_diff() {
+        let older = table("1.0", true, false);
+        assert!(older.diff(&older, DiffMatching::TypeAndHandle).is_empty());
+        assert!(older.diff(&older, DiffMatching::Position).is_empty());
+
+        let newer = table("1.1", false, true);
+        let differences = older.diff(&newer, DiffMatching::TypeAndHandle);
+        assert_eq!(differences.len(), 3);
+        assert_eq!(
+            differences[0],
+            StructDiff::Removed {
+                struct_type: 0x80,
+                handle: Handle(1)
+            }
+        );
+        match &differences[1] {
+            StructDiff::Changed {
+                struct_type,
+                handle,
+                fields,
+            } => {
+                assert_eq!(*struct_type, 1);
+                assert_eq!(*handle, Handle(0));
+                assert_eq!(
+                    fields,
+                    &vec![FieldChange {
+                        name: "version".to_string(),
+                        old: Value::from("1.0"),
+                        new: Value::from("1.1"),
+                    }]
+                );
+            }
+            other => panic!("expected a change, got {:?}", other),
+        }
+        assert_eq!(
+            differences[2],
+            StructDiff::Added {
+                struct_type: 3,
+                handle: Handle(1)
+            }
+        );
+
+        // Renumbering a handle only appears as a change when matching by position
+        let mut raw = older.to_bytes();
+        raw[Header::HANDLE_OFFSET] = 0x10;
+        let renumbered = SMBiosData::from(raw);
+
+        let differences = older.diff(&renumbered, DiffMatching::TypeAndHandle);
+        assert_eq!(
+            differences,
+            vec![
+                StructDiff::Removed {
+                    struct_type: 1,
+                    handle: Handle(0)
+                },
+                StructDiff::Added {
+                    struct_type: 1,
+                    handle: Handle(0x10)
+                }
+            ]
+        );
+
+        let differences = older.diff(&renumbered, DiffMatching::Position);
+        match differences.as_slice() {
+            [StructDiff::Changed { handle, fields, .. }] => {
+                assert_eq!(*handle, Handle(0x10));
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name, "header");
+            }
+            other => panic!("expected a single change, got {:?}", other),
+        }
+    }
+}
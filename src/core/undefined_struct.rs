@@ -1,13 +1,14 @@
 use super::header::{Handle, Header};
+use super::smbios_data::SMBiosVersion;
 use super::strings::*;
 use crate::structs::{DefinedStruct, SMBiosEndOfTable, SMBiosStruct};
 use serde::{Serialize, Serializer};
 use std::fmt;
+use std::{collections::HashMap, convert::TryInto, slice::Iter, sync::OnceLock};
+#[cfg(feature = "os-loader")]
 use std::{
-    convert::TryInto,
     fs::File,
     io::{prelude::*, Error, ErrorKind, SeekFrom},
-    slice::Iter,
 };
 /// # Embodies the three basic parts of an SMBIOS structure
 ///
@@ -23,7 +24,7 @@ use std::{
 /// necessary.  Therefore, [UndefinedStruct] is public for the case of OEM,
 /// as well as when working with structures that are defined in an SMBIOS
 /// standard newer than the one this library currently supports.
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub struct UndefinedStruct {
     /// The [Header] of the structure
     pub header: Header,
@@ -50,6 +51,14 @@ pub struct UndefinedStruct {
     /// The strings of the structure
     #[serde(serialize_with = "ser_strings")]
     pub strings: SMBiosStringSet,
+
+    /// The SMBIOS version of the table containing the structure, when known
+    ///
+    /// Set by [super::SMBiosData] so that accessors of fields introduced in a
+    /// later specification version report them as absent (see
+    /// [UndefinedStruct::supports]).
+    #[serde(skip)]
+    pub version: Option<SMBiosVersion>,
 }
 
 fn ser_strings<S>(data: &SMBiosStringSet, serializer: S) -> Result<S::Ok, S::Error>
@@ -61,18 +70,26 @@ where
 
 impl<'a> UndefinedStruct {
     /// Creates a structure instance of the given byte array slice
+    ///
+    /// `raw` is expected to contain the header, the formatted portion, and the
+    /// double-null terminated string-set.  A `raw` too short to contain a header
+    /// produces a default (empty) structure.
     pub fn new(raw: &Vec<u8>) -> Self {
-        match raw.get(Header::LENGTH_OFFSET) {
-            Some(&header_length) => UndefinedStruct {
-                header: Header::new(raw[..Header::SIZE].try_into().expect("4 bytes")),
-                fields: raw.get(..(header_length as usize)).unwrap_or(&[]).to_vec(),
+        match raw.get(..Header::SIZE) {
+            Some(header) => UndefinedStruct {
+                header: Header::new(header.try_into().expect("4 bytes")),
+                fields: raw
+                    .get(..(header[Header::LENGTH_OFFSET] as usize))
+                    .unwrap_or(&[])
+                    .to_vec(),
                 strings: {
                     SMBiosStringSet::new(
-                        raw.get((header_length as usize)..raw.len() - 2)
+                        raw.get((header[Header::LENGTH_OFFSET] as usize)..raw.len() - 2)
                             .unwrap_or(&[])
                             .to_vec(),
                     )
                 },
+                version: None,
             },
             None => UndefinedStruct {
                 ..Default::default()
@@ -80,46 +97,130 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Tests if fields introduced in the `since` specification version are expected
+    ///
+    /// Returns `false` only when the version of the containing table is known
+    /// and predates `since`, in which case the bytes of such fields are padding.
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// let mut parts = UndefinedStruct::new(&vec![0x80, 0x04, 0x10, 0x00, 0x00, 0x00]);
+    /// assert!(parts.supports(SMBiosVersion::new(3, 0, 0)));
+    /// parts.version = Some(SMBiosVersion::new(2, 8, 0));
+    /// assert!(!parts.supports(SMBiosVersion::new(3, 0, 0)));
+    /// ```
+    pub fn supports(&self, since: SMBiosVersion) -> bool {
+        match self.version {
+            Some(version) => version >= since,
+            None => true,
+        }
+    }
+
+    /// The structure when fields introduced in the `since` specification version are expected
+    ///
+    /// Accessors of such fields use this to report them as absent when the table
+    /// predates them, for example `self.parts.since(SMBiosVersion::new(2, 7, 0))?`.
+    pub fn since(&self, since: SMBiosVersion) -> Option<&Self> {
+        match self.supports(since) {
+            true => Some(self),
+            false => None,
+        }
+    }
+
+    /// Retrieve `size` bytes at the given offset from the structure's data section
+    ///
+    /// Unlike [UndefinedStruct::get_field_data], the error describes why the field
+    /// could not be retrieved.
+    pub fn try_get_field_bytes(
+        &self,
+        offset: usize,
+        size: usize,
+    ) -> Result<&[u8], SMBiosFieldError> {
+        let length = self.fields.len();
+        if offset >= length {
+            return Err(SMBiosFieldError::NotPresent { offset, length });
+        }
+
+        offset
+            .checked_add(size)
+            .and_then(|end| self.fields.get(offset..end))
+            .ok_or(SMBiosFieldError::Truncated {
+                offset,
+                size,
+                length,
+            })
+    }
+
+    /// Retrieve a byte at the given offset from the structure's data section
+    pub fn try_get_field_byte(&self, offset: usize) -> Result<u8, SMBiosFieldError> {
+        self.try_get_field_bytes(offset, 1).map(|val| val[0])
+    }
+
+    /// Retrieve a WORD at the given offset from the structure's data section
+    pub fn try_get_field_word(&self, offset: usize) -> Result<u16, SMBiosFieldError> {
+        self.try_get_field_bytes(offset, 2)
+            .map(|val| u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes")))
+    }
+
+    /// Retrieve a [Handle] at the given offset from the structure's data section
+    pub fn try_get_field_handle(&self, offset: usize) -> Result<Handle, SMBiosFieldError> {
+        self.try_get_field_bytes(offset, Handle::SIZE)
+            .map(|val| Handle(u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes"))))
+    }
+
+    /// Retrieve a DWORD at the given offset from the structure's data section
+    pub fn try_get_field_dword(&self, offset: usize) -> Result<u32, SMBiosFieldError> {
+        self.try_get_field_bytes(offset, 4)
+            .map(|val| u32::from_le_bytes(val.try_into().expect("u32 is 4 bytes")))
+    }
+
+    /// Retrieve a QWORD at the given offset from the structure's data section
+    pub fn try_get_field_qword(&self, offset: usize) -> Result<u64, SMBiosFieldError> {
+        self.try_get_field_bytes(offset, 8)
+            .map(|val| u64::from_le_bytes(val.try_into().expect("u64 is 8 bytes")))
+    }
+
+    /// Retrieve `size` bytes at the given offset from the structure's data section
+    ///
+    /// Use this to read vendor-specific fields, such as those of OEM structures
+    /// (types 128-255), that this library does not model.
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// // OEM type 0x85 with a vendor-defined DWORD at offset 0x04
+    /// let raw = vec![0x85, 0x08, 0x30, 0x00, 0x78, 0x56, 0x34, 0x12, 0x00, 0x00];
+    /// let oem = UndefinedStruct::new(&raw);
+    ///
+    /// assert_eq!(oem.get_field_bytes(0x04, 4), Some(&[0x78, 0x56, 0x34, 0x12][..]));
+    /// assert_eq!(oem.get_field_bytes(0x06, 4), None);
+    /// ```
+    pub fn get_field_bytes(&self, offset: usize, size: usize) -> Option<&[u8]> {
+        self.try_get_field_bytes(offset, size).ok()
+    }
+
     /// Retrieve a byte at the given offset from the structure's data section
     pub fn get_field_byte(&self, offset: usize) -> Option<u8> {
-        match self.fields.get(offset..offset + 1) {
-            Some(val) => Some(val[0]),
-            None => None,
-        }
+        self.try_get_field_byte(offset).ok()
     }
 
     /// Retrieve a WORD at the given offset from the structure's data section
     pub fn get_field_word(&self, offset: usize) -> Option<u16> {
-        match self.fields.get(offset..offset + 2) {
-            Some(val) => Some(u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes"))),
-            None => None,
-        }
+        self.try_get_field_word(offset).ok()
     }
 
     /// Retrieve a [Handle] at the given offset from the structure's data section
     pub fn get_field_handle(&self, offset: usize) -> Option<Handle> {
-        match self.fields.get(offset..offset + Handle::SIZE) {
-            Some(val) => Some(Handle(u16::from_le_bytes(
-                val.try_into().expect("u16 is 2 bytes"),
-            ))),
-            None => None,
-        }
+        self.try_get_field_handle(offset).ok()
     }
 
     /// Retrieve a DWORD at the given offset from the structure's data section
     pub fn get_field_dword(&self, offset: usize) -> Option<u32> {
-        match self.fields.get(offset..offset + 4) {
-            Some(val) => Some(u32::from_le_bytes(val.try_into().expect("u32 is 4 bytes"))),
-            None => None,
-        }
+        self.try_get_field_dword(offset).ok()
     }
 
     /// Retrieve a QWORD at the given offset from the structure's data section
     pub fn get_field_qword(&self, offset: usize) -> Option<u64> {
-        match self.fields.get(offset..offset + 8) {
-            Some(val) => Some(u64::from_le_bytes(val.try_into().expect("u64 is 8 bytes"))),
-            None => None,
-        }
+        self.try_get_field_qword(offset).ok()
     }
 
     /// Retrieve a String of the given offset
@@ -135,6 +236,30 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Retrieve a String of the given offset decoded with the given policy
+    ///
+    /// See [UndefinedStruct::get_field_string] and [SMBiosStringDecoding].
+    pub fn get_field_string_with(
+        &self,
+        offset: usize,
+        decoding: SMBiosStringDecoding,
+    ) -> SMBiosString {
+        match self.get_field_byte(offset) {
+            Some(val) => self.strings.get_string_with(val, decoding),
+            None => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
+    }
+
+    /// Retrieve a [str] of the given offset, borrowed from the structure's strings
+    ///
+    /// This is the non-allocating equivalent of [UndefinedStruct::get_field_string].
+    pub fn get_field_str(&self, offset: usize) -> Result<&str, SMBiosStringError> {
+        match self.get_field_byte(offset) {
+            Some(val) => self.strings.get_str(val),
+            None => Err(SMBiosStringError::FieldOutOfBounds),
+        }
+    }
+
     // todo: learn how to pass an index range (SliceIndex?) rather than start/end indices.
     // This would better conform to the Rust design look and feel.
 
@@ -162,15 +287,91 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Serializes the structure back to its raw SMBIOS byte layout
+    ///
+    /// The result contains the header, formatted portion, and the double-null
+    /// terminated string-set.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut raw = self.fields.clone();
+
+        let mut strings = self.strings.iter().peekable();
+        if strings.peek().is_none() {
+            // A structure without strings is terminated by two nulls
+            raw.push(0);
+        }
+        for string in strings {
+            raw.extend_from_slice(string);
+            raw.push(0);
+        }
+        raw.push(0);
+
+        raw
+    }
+
+    /// Formats the raw bytes of the structure as an annotated hexdump
+    ///
+    /// The formatted area (including the header) is shown in rows of 16 bytes
+    /// labelled with the offset of their first byte, followed by each string of
+    /// the string-set labelled with its string number.  Each row ends with its
+    /// bytes as text, with bytes outside printable ASCII shown as '.'.
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// let raw = vec![0x80, 0x06, 0x10, 0x00, 0x01, 0xFF, b'O', b'E', b'M', 0x00, 0x00];
+    /// let dump = UndefinedStruct::new(&raw).hexdump();
+    /// assert_eq!(dump.lines().nth(2), Some("0x00: 80 06 10 00 01 FF                                ......"));
+    /// assert_eq!(dump.lines().nth(4), Some("   1: 4F 45 4D                                         OEM"));
+    /// ```
+    pub fn hexdump(&self) -> String {
+        let mut output = format!(
+            "Type {}, Handle {:#06X}, Length {:#04X}\n",
+            self.header.struct_type(),
+            *self.header.handle(),
+            self.header.length()
+        );
+        output.push_str("      00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F\n");
+        for (row, bytes) in self.fields.chunks(16).enumerate() {
+            push_hexdump_row(&format!("{:#04X}:", row * 16), bytes, &mut output);
+        }
+
+        output.push_str("Strings:\n");
+        for (index, string) in self.strings.iter().enumerate() {
+            for (row, bytes) in string.chunks(16).enumerate() {
+                let label = match row {
+                    0 => format!("{:>4}:", index + 1),
+                    _ => String::new(),
+                };
+                push_hexdump_row(&label, bytes, &mut output);
+            }
+        }
+        output
+    }
+
     /// Down casts the current structure to its specific defined BIOS structure type
     pub fn defined_struct(&self) -> DefinedStruct<'_> {
         self.into()
     }
 }
 
+/// Writes a labelled row of up to 16 bytes as hex values followed by text
+fn push_hexdump_row(label: &str, bytes: &[u8], output: &mut String) {
+    let hex: Vec<String> = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+    let text: String = bytes
+        .iter()
+        .map(|&byte| {
+            if (32..127).contains(&byte) {
+                byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    output.push_str(&format!("{:<6}{:<47}  {}\n", label, hex.join(" "), text));
+}
+
 impl fmt::Debug for UndefinedStruct {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let fields = &self.fields[Header::SIZE..];
+        let fields = self.fields.get(Header::SIZE..).unwrap_or(&[]);
         fmt.debug_struct(std::any::type_name::<UndefinedStruct>())
             .field("header", &self.header)
             .field("fields", &fields)
@@ -186,23 +387,123 @@ impl Default for UndefinedStruct {
             header: Header::new(v),
             fields: (&[]).to_vec(),
             strings: { SMBiosStringSet::new((&[]).to_vec()) },
+            version: None,
+        }
+    }
+}
+
+/// # SMBiosFieldError
+///
+/// An SMBIOS field retrieval error
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SMBiosFieldError {
+    /// The field starts beyond the formatted portion of the structure
+    ///
+    /// Typically the field was introduced in a later specification version than
+    /// the firmware implements.
+    NotPresent {
+        /// Offset of the field
+        offset: usize,
+        /// Length of the formatted portion of the structure
+        length: usize,
+    },
+    /// The field starts within the formatted portion of the structure but extends past its end
+    Truncated {
+        /// Offset of the field
+        offset: usize,
+        /// Size of the field
+        size: usize,
+        /// Length of the formatted portion of the structure
+        length: usize,
+    },
+}
+
+impl fmt::Display for SMBiosFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SMBiosFieldError::NotPresent { offset, length } => write!(
+                f,
+                "The field at offset {:#04X} is not present in a structure of length {:#04X}",
+                offset, length
+            ),
+            SMBiosFieldError::Truncated {
+                offset,
+                size,
+                length,
+            } => write!(
+                f,
+                "The {} byte field at offset {:#04X} is truncated by a structure of length {:#04X}",
+                size, offset, length
+            ),
         }
     }
 }
 
+impl std::error::Error for SMBiosFieldError {}
+
 /// # Undefined Struct Table
 ///
 /// A collection of [UndefinedStruct] items.
-#[derive(Debug, Serialize)]
-pub struct UndefinedStructTable(Vec<UndefinedStruct>);
+///
+/// Lookups by type and handle use an index built on first use.
+#[derive(Clone)]
+pub struct UndefinedStructTable(Vec<UndefinedStruct>, OnceLock<TableIndex>);
+
+/// Positions of the structures of a table by type and by handle
+#[derive(Clone)]
+struct TableIndex {
+    /// Positions of each type, up to and including the End-of-Table structure
+    by_type: HashMap<u8, Vec<usize>>,
+    /// Position of the first structure with each handle
+    by_handle: HashMap<u16, usize>,
+}
+
+impl TableIndex {
+    fn new(structs: &[UndefinedStruct]) -> Self {
+        let end = structs
+            .iter()
+            .position(|undefined_struct| {
+                undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE
+            })
+            .map_or(structs.len(), |end_of_table| end_of_table + 1);
+
+        let mut by_type: HashMap<u8, Vec<usize>> = HashMap::new();
+        let mut by_handle = HashMap::new();
+        for (position, undefined_struct) in structs.iter().enumerate() {
+            if position < end {
+                by_type
+                    .entry(undefined_struct.header.struct_type())
+                    .or_default()
+                    .push(position);
+            }
+            by_handle
+                .entry(*undefined_struct.header.handle())
+                .or_insert(position);
+        }
+
+        TableIndex { by_type, by_handle }
+    }
+}
 
 impl<'a> UndefinedStructTable {
     fn new() -> UndefinedStructTable {
-        UndefinedStructTable(Vec::new())
+        UndefinedStructTable(Vec::new(), OnceLock::new())
     }
 
     fn add(&mut self, elem: UndefinedStruct) {
         self.0.push(elem);
+        self.1 = OnceLock::new();
+    }
+
+    /// Records the SMBIOS version of the table in each of its structures
+    pub(crate) fn set_version(&mut self, version: Option<SMBiosVersion>) {
+        for undefined_struct in self.0.iter_mut() {
+            undefined_struct.version = version;
+        }
+    }
+
+    fn index(&self) -> &TableIndex {
+        self.1.get_or_init(|| TableIndex::new(&self.0))
     }
 
     /// Iterator of the contained [UndefinedStruct] items.
@@ -211,21 +512,21 @@ impl<'a> UndefinedStructTable {
     }
 
     /// An iterator over the defined type instances within the table.
+    ///
+    /// Structures following the End-of-Table (type 127) structure are not included.
     pub fn defined_struct_iter<T>(&'a self) -> impl Iterator<Item = T> + 'a
     where
         T: SMBiosStruct<'a>,
     {
-        self.iter()
-            .take_while(|undefined_struct| {
-                undefined_struct.header.struct_type() != SMBiosEndOfTable::STRUCT_TYPE
-            })
-            .filter_map(|undefined_struct| {
-                if undefined_struct.header.struct_type() == T::STRUCT_TYPE {
-                    Some(T::new(undefined_struct))
-                } else {
-                    None
-                }
-            })
+        let positions: &[usize] = self
+            .index()
+            .by_type
+            .get(&T::STRUCT_TYPE)
+            .map_or(&[], |positions| positions.as_slice());
+
+        positions
+            .iter()
+            .map(move |position| T::new(&self.0[*position]))
     }
 
     /// Tests if every element of the defined struct iterator matches a predicate.
@@ -303,9 +604,20 @@ impl<'a> UndefinedStructTable {
     ///
     /// To downcast to the defined struct, call .defined_struct() on the result.
     pub fn find_by_handle(&'a self, handle: &Handle) -> Option<&'a UndefinedStruct> {
-        self.iter()
-            .find(|smbios_struct| smbios_struct.header.handle() == *handle)
-            .and_then(|undefined_struct| Some(undefined_struct))
+        self.index()
+            .by_handle
+            .get(&**handle)
+            .map(|position| &self.0[*position])
+    }
+
+    /// Finds the structure matching the given handle if it is of the defined type `T`
+    pub fn find_by_handle_as<T>(&'a self, handle: &Handle) -> Option<T>
+    where
+        T: SMBiosStruct<'a>,
+    {
+        self.find_by_handle(handle)
+            .filter(|undefined_struct| undefined_struct.header.struct_type() == T::STRUCT_TYPE)
+            .map(T::new)
     }
 
     /// Returns all occurances of the structure
@@ -316,7 +628,15 @@ impl<'a> UndefinedStructTable {
         self.defined_struct_iter().collect()
     }
 
+    /// Serializes the table back to its raw SMBIOS byte layout
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.iter()
+            .flat_map(|undefined_struct| undefined_struct.to_bytes())
+            .collect()
+    }
+
     /// Load an [UndefinedStructTable] by seeking and reading the file offsets.
+    #[cfg(feature = "os-loader")]
     pub fn try_load_from_file_offset(
         file: &mut File,
         table_offset: u64,
@@ -392,6 +712,23 @@ impl From<Vec<u8>> for UndefinedStructTable {
     }
 }
 
+impl fmt::Debug for UndefinedStructTable {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("UndefinedStructTable")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl Serialize for UndefinedStructTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("UndefinedStructTable", &self.0)
+    }
+}
+
 impl IntoIterator for UndefinedStructTable {
     type Item = UndefinedStruct;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -400,3 +737,250 @@ impl IntoIterator for UndefinedStructTable {
         self.0.into_iter()
     }
 }
+
+impl<'a> IntoIterator for &'a UndefinedStructTable {
+    type Item = &'a UndefinedStruct;
+    type IntoIter = Iter<'a, UndefinedStruct>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::DefinedStructTable;
+    #[cfg(feature = "processor")]
+    use crate::structs::{SMBiosCacheInformation, SMBiosSystemInformation};
+
+    #[test]
+    fn test_table_walk() {
+        // Type 0 with two strings
+        let mut data = vec![
+            0x00, 0x05, 0x00, 0x00, 0x01, b'A', 0x00, b'B', b'C', 0x00, 0x00,
+        ];
+        // Type 32 without strings
+        data.extend_from_slice(&[0x20, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00]);
+        // End-of-table
+        data.extend_from_slice(&[0x7F, 0x04, 0x02, 0x00, 0x00, 0x00]);
+        // Trailing bytes which do not form a structure
+        data.extend_from_slice(&[0x00, 0x00]);
+        let data_copy = data.clone();
+        let table = UndefinedStructTable::from(data);
+
+        let mut count = 0;
+        for (index, undefined_struct) in (&table).into_iter().enumerate() {
+            assert_eq!(undefined_struct.header.handle(), Handle(index as u16));
+            count += 1;
+        }
+        assert_eq!(count, 3);
+
+        let types: Vec<u8> = table
+            .iter()
+            .map(|undefined_struct| undefined_struct.header.struct_type())
+            .collect();
+        assert_eq!(types, vec![0x00, 0x20, 0x7F]);
+
+        let first = table.iter().next().unwrap();
+        assert_eq!(first.fields, vec![0x00, 0x05, 0x00, 0x00, 0x01]);
+        assert_eq!(first.get_field_string(0x04).to_string(), "A".to_string());
+        assert!(table.iter().nth(1).unwrap().strings.iter().next().is_none());
+
+        let mut expected = data_copy;
+        expected.truncate(expected.len() - 2);
+        assert_eq!(table.to_bytes(), expected);
+        assert_eq!(
+            table.iter().next().unwrap().to_bytes(),
+            vec![0x00, 0x05, 0x00, 0x00, 0x01, b'A', 0x00, b'B', b'C', 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "processor")]
+    fn test_typed_collection() {
+        let mut data = Vec::new();
+        for handle in 0..3u8 {
+            // Type 7 (Cache Information) without strings
+            data.extend_from_slice(&[0x07, 0x05, handle, 0x00, 0x00, 0x00, 0x00]);
+        }
+        data.extend_from_slice(&[0x7F, 0x04, 0x03, 0x00, 0x00, 0x00]);
+        // Structures after the End-of-Table are ignored
+        data.extend_from_slice(&[0x07, 0x05, 0x04, 0x00, 0x00, 0x00, 0x00]);
+        let table = UndefinedStructTable::from(data);
+
+        let caches = table.collect::<SMBiosCacheInformation<'_>>();
+        assert_eq!(caches.len(), 3);
+        assert_eq!(caches[2].parts().header.handle(), Handle(2));
+
+        let first = table.first::<SMBiosCacheInformation<'_>>().unwrap();
+        assert_eq!(first.parts().header.handle(), Handle(0));
+
+        let end_of_table = table.first::<SMBiosEndOfTable<'_>>().unwrap();
+        assert_eq!(end_of_table.parts().header.handle(), Handle(3));
+
+        assert!(table.first::<SMBiosSystemInformation<'_>>().is_none());
+        assert!(table.collect::<SMBiosSystemInformation<'_>>().is_empty());
+    }
+
+    #[test]
+    fn test_field_errors() {
+        // Type 1 truncated to offset 0x08 with one string
+        let raw = vec![
+            0x01, 0x08, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, b'A', 0x00, 0x00,
+        ];
+        let undefined_struct = UndefinedStruct::new(&raw);
+
+        assert_eq!(undefined_struct.try_get_field_byte(0x04), Ok(0x01));
+        assert_eq!(undefined_struct.try_get_field_word(0x06), Ok(0x0000));
+        assert_eq!(
+            undefined_struct.try_get_field_dword(0x06),
+            Err(SMBiosFieldError::Truncated {
+                offset: 0x06,
+                size: 4,
+                length: 0x08
+            })
+        );
+        assert_eq!(
+            undefined_struct.try_get_field_byte(0x08),
+            Err(SMBiosFieldError::NotPresent {
+                offset: 0x08,
+                length: 0x08
+            })
+        );
+        assert!(undefined_struct.try_get_field_qword(usize::MAX).is_err());
+        assert_eq!(undefined_struct.get_field_dword(0x06), None);
+        assert_eq!(
+            undefined_struct.get_field_bytes(0x04, 4),
+            Some(&raw[0x04..0x08])
+        );
+        assert_eq!(undefined_struct.get_field_bytes(0x04, usize::MAX), None);
+
+        assert_eq!(undefined_struct.get_field_string(0x04).to_string(), "A");
+        assert_eq!(undefined_struct.get_field_str(0x04).unwrap(), "A");
+        assert!(matches!(
+            undefined_struct.get_field_str(0x08),
+            Err(SMBiosStringError::FieldOutOfBounds)
+        ));
+        assert!(matches!(
+            undefined_struct.get_field_string(0x05).err(),
+            Some(SMBiosStringError::InvalidStringNumber(2))
+        ));
+        assert!(matches!(
+            undefined_struct.get_field_string(0x08).err(),
+            Some(SMBiosStringError::FieldOutOfBounds)
+        ));
+    }
+
+    #[test]
+    fn test_hexdump() {
+        // Type 0 (BIOS Information) with a vendor string longer than one row
+        let mut raw = vec![
+            0x00, 0x12, 0x00, 0x00, 0x01, 0x02, 0x00, 0xF0, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        raw.extend_from_slice(b"Acme\tInc. BIOS Vendor\0v1\0\0");
+
+        let expected = "\
+Type 0, Handle 0x0000, Length 0x12
+      00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F
+0x00: 00 12 00 00 01 02 00 F0 03 00 00 00 00 00 00 00  ................
+0x10: 00 00                                            ..
+Strings:
+   1: 41 63 6D 65 09 49 6E 63 2E 20 42 49 4F 53 20 56  Acme.Inc. BIOS V
+      65 6E 64 6F 72                                   endor
+   2: 76 31                                            v1
+";
+        assert_eq!(UndefinedStruct::new(&raw).hexdump(), expected);
+    }
+
+    #[test]
+    fn test_malformed_structures() {
+        let fixtures: Vec<Vec<u8>> = vec![
+            // Too short for a header
+            vec![],
+            vec![0x01],
+            vec![0x01, 0x1B, 0x00],
+            // Header length smaller than the header itself
+            vec![0x0A, 0x00, 0x00, 0x00, 0x00, 0x00],
+            vec![0xC8, 0x02, 0x00, 0x00, 0x00, 0x00],
+            // Header length beyond the end of the data
+            vec![0xC8, 0xE6, 0x00, 0x00, 0x01, 0x00],
+            // Missing double-null terminator
+            vec![0x01, 0x08, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, b'A'],
+            // Type 40 entry with a length shorter than its fixed portion
+            vec![
+                0x28, 0x0B, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+        ];
+
+        for raw in fixtures {
+            let undefined_struct = UndefinedStruct::new(&raw);
+            let _ = format!("{:?}", undefined_struct);
+            let _ = format!("{:?}", undefined_struct.defined_struct());
+            let _ = serde_json::to_string(&undefined_struct.defined_struct()).unwrap();
+
+            let table = UndefinedStructTable::from(raw);
+            let defined_table: DefinedStructTable<'_> = table.iter().collect();
+            let _ = format!("{:?}", defined_table);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "processor")]
+    fn test_find_by_handle() {
+        // Type 7 (Cache Information) at handle 0x0010
+        let mut data = vec![0x07, 0x05, 0x10, 0x00, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0x7F, 0x04, 0x11, 0x00, 0x00, 0x00]);
+        let table = UndefinedStructTable::from(data);
+
+        let found = table.find_by_handle(&Handle(0x0010)).unwrap();
+        assert_eq!(
+            found.header.struct_type(),
+            SMBiosCacheInformation::STRUCT_TYPE
+        );
+        assert!(table.find_by_handle(&Handle(0x0012)).is_none());
+
+        let cache = table
+            .find_by_handle_as::<SMBiosCacheInformation<'_>>(&Handle(0x0010))
+            .unwrap();
+        assert_eq!(cache.parts().header.handle(), Handle(0x0010));
+        assert!(table
+            .find_by_handle_as::<SMBiosSystemInformation<'_>>(&Handle(0x0010))
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "processor")]
+    fn test_index() {
+        // Two structures share handle 0x0010 and a third follows End-of-Table
+        let mut data = vec![0x07, 0x05, 0x10, 0x00, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0x80, 0x04, 0x10, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x07, 0x05, 0x11, 0x00, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x7F, 0x04, 0x12, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x07, 0x05, 0x13, 0x00, 0x00, 0x00, 0x00]);
+        let table = UndefinedStructTable::from(data);
+
+        for _ in 0..2 {
+            let handles: Vec<u16> = table
+                .defined_struct_iter::<SMBiosCacheInformation<'_>>()
+                .map(|cache| *cache.parts().header.handle())
+                .collect();
+            assert_eq!(handles, vec![0x0010, 0x0011]);
+
+            let found = table.find_by_handle(&Handle(0x0010)).unwrap();
+            assert_eq!(found.header.struct_type(), 0x07);
+            // Handles after End-of-Table can still be found
+            assert!(table.find_by_handle(&Handle(0x0013)).is_some());
+        }
+
+        let cloned = table.clone();
+        assert_eq!(
+            cloned
+                .defined_struct_iter::<SMBiosCacheInformation<'_>>()
+                .count(),
+            2
+        );
+        assert!(format!("{:?}", cloned).starts_with("UndefinedStructTable(["));
+    }
+}
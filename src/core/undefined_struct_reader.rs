@@ -0,0 +1,204 @@
+use super::header::Header;
+use super::undefined_struct::UndefinedStruct;
+use crate::structs::{SMBiosEndOfTable, SMBiosStruct};
+use std::io::{Error, ErrorKind, Read};
+
+/// # Undefined Struct Reader
+///
+/// Parses a table from a [Read] source one structure at a time, holding only
+/// the structure being read in memory.
+///
+/// Yields the same structures as [UndefinedStructTable](crate::UndefinedStructTable)
+/// does for the whole table.  Reads are a single byte at a time while
+/// searching for the end of a string-set, so wrap sources with costly reads in
+/// a [BufReader](std::io::BufReader).
+///
+/// The reader ends at the end of the source or after the first error, which
+/// is [ErrorKind::UnexpectedEof] when the source ends within a structure and
+/// [ErrorKind::InvalidData] when a structure reports a length shorter than its
+/// header.
+///
+/// ```
+/// # use smbioslib::*;
+/// let table: &[u8] = &[
+///     0x80, 0x05, 0x00, 0x00, 0x01, b'O', b'E', b'M', 0x00, 0x00,
+///     0x7F, 0x04, 0x01, 0x00, 0x00, 0x00,
+/// ];
+/// let mut reader = UndefinedStructReader::new(table);
+/// let oem = reader.next().unwrap().unwrap();
+/// assert_eq!(oem.header.struct_type(), 0x80);
+/// assert_eq!(oem.get_field_string(0x04).to_string(), "OEM");
+/// assert_eq!(reader.next().unwrap().unwrap().header.struct_type(), 127);
+/// assert!(reader.next().is_none());
+/// ```
+pub struct UndefinedStructReader<R> {
+    source: R,
+    until_end_of_table: bool,
+    finished: bool,
+}
+
+impl<R: Read> UndefinedStructReader<R> {
+    /// Creates a reader which parses structures until the end of `source`
+    pub fn new(source: R) -> Self {
+        UndefinedStructReader {
+            source,
+            until_end_of_table: false,
+            finished: false,
+        }
+    }
+
+    /// Ends the reader after the End-of-Table structure without reading further
+    ///
+    /// For sources, such as a firmware mailbox, which do not end with the table.
+    pub fn until_end_of_table(mut self) -> Self {
+        self.until_end_of_table = true;
+        self
+    }
+
+    /// Returns the underlying source
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Reads the next structure, or `None` at the end of the source
+    fn read_struct(&mut self) -> Result<Option<UndefinedStruct>, Error> {
+        let mut header = [0u8; Header::SIZE];
+        let mut filled = 0;
+        while filled < Header::SIZE {
+            match self.source.read(&mut header[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(count) => filled += count,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let struct_len = header[Header::LENGTH_OFFSET] as usize;
+        if struct_len < Header::SIZE {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("The structure has an invalid length: {}", struct_len),
+            ));
+        }
+
+        let mut raw = header.to_vec();
+        raw.resize(struct_len, 0);
+        self.source.read_exact(&mut raw[Header::SIZE..])?;
+
+        // The string-set ends at the first pair of null bytes
+        let mut byte = [0u8];
+        let mut previous = None;
+        loop {
+            self.source.read_exact(&mut byte)?;
+            raw.push(byte[0]);
+            if previous == Some(0) && byte[0] == 0 {
+                break;
+            }
+            previous = Some(byte[0]);
+        }
+
+        Ok(Some(UndefinedStruct::new(&raw)))
+    }
+}
+
+impl<R: Read> Iterator for UndefinedStructReader<R> {
+    type Item = Result<UndefinedStruct, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.read_struct().transpose();
+        match &result {
+            Some(Ok(undefined_struct)) => {
+                if self.until_end_of_table
+                    && undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE
+                {
+                    self.finished = true;
+                }
+            }
+            _ => self.finished = true,
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{StructBuilder, TableBuilder, UndefinedStructTable};
+
+    /// A source returning at most `chunk` bytes per read, like a slow mailbox
+    struct Mailbox<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Mailbox<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let count = buf.len().min(self.chunk).min(self.data.len());
+            buf[..count].copy_from_slice(&self.data[..count]);
+            self.data = &self.data[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_reader() {
+        let mut builder = TableBuilder::new();
+        builder.add(StructBuilder::new(1).string(0x04, "Maker"));
+        builder.add(StructBuilder::with_length(0x80, 0x08).dword(0x04, 0));
+        builder.add(StructBuilder::new(0x81).push_string("A").push_string("B"));
+        let mut table = builder.build();
+        // A structure following End-of-Table
+        table.extend_from_slice(&[0x82, 0x04, 0x10, 0x00, 0x00, 0x00]);
+
+        let expected: Vec<Vec<u8>> = UndefinedStructTable::from(table.clone())
+            .into_iter()
+            .map(|undefined_struct| undefined_struct.to_bytes())
+            .collect();
+        assert_eq!(expected.len(), 5);
+
+        for chunk in [1, 3, 64] {
+            let source = Mailbox {
+                data: &table,
+                chunk,
+            };
+            let parsed: Vec<Vec<u8>> = UndefinedStructReader::new(source)
+                .map(|undefined_struct| undefined_struct.unwrap().to_bytes())
+                .collect();
+            assert_eq!(parsed, expected);
+        }
+
+        let mut reader = UndefinedStructReader::new(table.as_slice()).until_end_of_table();
+        assert_eq!(reader.by_ref().count(), 4);
+        assert_eq!(reader.into_inner().len(), 6);
+    }
+
+    #[test]
+    fn test_reader_errors() {
+        // Truncated within the string-set of the second structure
+        let table: &[u8] = &[
+            0x80, 0x04, 0x00, 0x00, 0x00, 0x00, 0x81, 0x05, 0x01, 0x00, 0x01, b'A', 0x00,
+        ];
+        let mut reader = UndefinedStructReader::new(table);
+        assert!(reader.next().unwrap().is_ok());
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(reader.next().is_none());
+
+        // A length shorter than the header
+        let table: &[u8] = &[0x80, 0x02, 0x00, 0x00, 0x00, 0x00];
+        let mut reader = UndefinedStructReader::new(table);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert!(reader.next().is_none());
+        assert_eq!(UndefinedStructTable::from(table.to_vec()).iter().count(), 0);
+    }
+}
@@ -0,0 +1,326 @@
+use super::header::Handle;
+use super::smbios_data::{SMBiosData, SMBiosVersion};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+/// # Validation Finding
+///
+/// A specification conformance problem found by [SMBiosData::validate].
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum ValidationFinding {
+    /// A structure type required by the specification (2.3 and later) is absent
+    MissingStructure {
+        /// Structure type
+        struct_type: u8,
+    },
+    /// A structure is shorter than the specification version requires
+    LengthTooShort {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+        /// Length of the structure
+        length: u8,
+        /// Minimum length for the specification version
+        minimum: u8,
+        /// Specification version which introduced the minimum length
+        since: SMBiosVersion,
+    },
+    /// Bits reserved by the specification are set
+    ReservedBitsSet {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+        /// Offset of the byte containing the reserved bits
+        offset: usize,
+        /// The reserved bits which are set
+        bits: u8,
+    },
+    /// A structure uses a handle in the reserved range FF00h-FFFFh
+    ReservedHandle {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+    },
+    /// A structure uses a handle already used by a preceding structure
+    DuplicateHandle {
+        /// Structure type
+        struct_type: u8,
+        /// Structure handle
+        handle: Handle,
+    },
+}
+
+impl fmt::Display for ValidationFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationFinding::MissingStructure { struct_type } => {
+                write!(f, "required structure type {} is missing", struct_type)
+            }
+            ValidationFinding::LengthTooShort {
+                struct_type,
+                handle,
+                length,
+                minimum,
+                since,
+            } => write!(
+                f,
+                "type {} handle {:#06X}: length {:#04X} is less than {:#04X} required since SMBIOS {}.{}",
+                struct_type, **handle, length, minimum, since.major, since.minor
+            ),
+            ValidationFinding::ReservedBitsSet {
+                struct_type,
+                handle,
+                offset,
+                bits,
+            } => write!(
+                f,
+                "type {} handle {:#06X}: reserved bits {:#04X} set at offset {:#04X}",
+                struct_type, **handle, bits, offset
+            ),
+            ValidationFinding::ReservedHandle {
+                struct_type,
+                handle,
+            } => write!(
+                f,
+                "type {} handle {:#06X}: handle is in the reserved range",
+                struct_type, **handle
+            ),
+            ValidationFinding::DuplicateHandle {
+                struct_type,
+                handle,
+            } => write!(
+                f,
+                "type {} handle {:#06X}: handle is already in use",
+                struct_type, **handle
+            ),
+        }
+    }
+}
+
+/// Structure types required since SMBIOS 2.3
+const REQUIRED_STRUCTURES: &[u8] = &[0, 1, 3, 4, 16, 17, 19, 32, 127];
+
+/// Minimum structure lengths (type, major, minor, length) in ascending version order
+const MINIMUM_LENGTHS: &[(u8, u8, u8, u8)] = &[
+    (0, 2, 0, 0x12),
+    (0, 2, 4, 0x18),
+    (0, 3, 1, 0x1A),
+    (1, 2, 0, 0x08),
+    (1, 2, 1, 0x19),
+    (1, 2, 4, 0x1B),
+    (2, 2, 0, 0x08),
+    (3, 2, 0, 0x09),
+    (3, 2, 1, 0x0D),
+    (3, 2, 3, 0x15),
+    (4, 2, 0, 0x1A),
+    (4, 2, 3, 0x23),
+    (4, 2, 5, 0x28),
+    (4, 2, 6, 0x2A),
+    (4, 3, 0, 0x30),
+    (16, 2, 1, 0x0F),
+    (16, 2, 7, 0x17),
+    (17, 2, 1, 0x15),
+    (17, 2, 3, 0x1B),
+    (17, 2, 6, 0x1C),
+    (17, 2, 7, 0x22),
+    (17, 2, 8, 0x28),
+    (17, 3, 2, 0x54),
+    (17, 3, 3, 0x5C),
+    (19, 2, 1, 0x0F),
+    (19, 2, 7, 0x1F),
+    (32, 2, 0, 0x0B),
+    (127, 2, 0, 0x04),
+];
+
+/// Reserved bits (type, offset, mask), checked when the field is present
+const RESERVED_BITS: &[(u8, usize, u8)] = &[
+    // BIOS Information: BIOS Characteristics bits 0-1
+    (0, 0x0A, 0x03),
+    // Processor Information: Processor Characteristics bit 0
+    (4, 0x26, 0x01),
+    // Memory Device: Type Detail bit 0
+    (17, 0x13, 0x01),
+    // Memory Device: Extended Size bit 31
+    (17, 0x1F, 0x80),
+    // Memory Device: Extended Speed bit 31
+    (17, 0x57, 0x80),
+    // Memory Device: Extended Configured Memory Speed bit 31
+    (17, 0x5B, 0x80),
+    // System Boot Information: Reserved
+    (32, 0x04, 0xFF),
+    (32, 0x05, 0xFF),
+    (32, 0x06, 0xFF),
+    (32, 0x07, 0xFF),
+    (32, 0x08, 0xFF),
+    (32, 0x09, 0xFF),
+];
+
+/// First handle of the range reserved by the specification
+const RESERVED_HANDLES_START: u16 = 0xFF00;
+
+impl SMBiosData {
+    /// Checks the table for conformance with its SMBIOS version
+    ///
+    /// Checks for required structures, minimum structure lengths, reserved bits,
+    /// and reserved or duplicate handles.  When the version is unknown only the
+    /// minimum lengths of the version which introduced each structure are applied.
+    pub fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        if self.supports(SMBiosVersion::new(2, 3, 0)) {
+            findings.extend(
+                REQUIRED_STRUCTURES
+                    .iter()
+                    .filter(|required| {
+                        !self.iter().any(|undefined_struct| {
+                            undefined_struct.header.struct_type() == **required
+                        })
+                    })
+                    .map(|required| ValidationFinding::MissingStructure {
+                        struct_type: *required,
+                    }),
+            );
+        }
+
+        let mut handles = HashSet::new();
+        for undefined_struct in self.iter() {
+            let struct_type = undefined_struct.header.struct_type();
+            let handle = undefined_struct.header.handle();
+            let length = undefined_struct.header.length();
+
+            let mut minimums = MINIMUM_LENGTHS
+                .iter()
+                .filter(|(minimum_type, ..)| *minimum_type == struct_type)
+                .map(|(_, major, minor, minimum)| {
+                    (SMBiosVersion::new(*major, *minor, 0), *minimum)
+                });
+            let minimum = match self.version {
+                Some(version) => minimums.take_while(|(since, _)| version >= *since).last(),
+                None => minimums.next(),
+            };
+            if let Some((since, minimum)) = minimum {
+                if length < minimum {
+                    findings.push(ValidationFinding::LengthTooShort {
+                        struct_type,
+                        handle,
+                        length,
+                        minimum,
+                        since,
+                    });
+                }
+            }
+
+            for (_, offset, mask) in RESERVED_BITS
+                .iter()
+                .filter(|(reserved_type, ..)| *reserved_type == struct_type)
+            {
+                if let Some(value) = undefined_struct.get_field_byte(*offset) {
+                    if value & mask != 0 {
+                        findings.push(ValidationFinding::ReservedBitsSet {
+                            struct_type,
+                            handle,
+                            offset: *offset,
+                            bits: value & mask,
+                        });
+                    }
+                }
+            }
+
+            if *handle >= RESERVED_HANDLES_START {
+                findings.push(ValidationFinding::ReservedHandle {
+                    struct_type,
+                    handle,
+                });
+            }
+            if !handles.insert(handle) {
+                findings.push(ValidationFinding::DuplicateHandle {
+                    struct_type,
+                    handle,
+                });
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
+
+    #[test]
+    fn test_validate() {
+        let mut builder = TableBuilder::new();
+        for (struct_type, length) in [(0, 0x1A), (1, 0x1B), (3, 0x15), (4, 0x30), (16, 0x17)] {
+            builder.add(StructBuilder::with_length(struct_type, length));
+        }
+        builder.add(StructBuilder::with_length(17, 0x28));
+        builder.add(StructBuilder::with_length(19, 0x1F));
+        builder.add(StructBuilder::with_length(32, 0x0B));
+        let table = builder.build();
+
+        let data =
+            SMBiosData::from_vec_and_version(table.clone(), Some(SMBiosVersion::new(3, 2, 0)));
+        assert_eq!(
+            data.validate(),
+            vec![ValidationFinding::LengthTooShort {
+                struct_type: 17,
+                handle: Handle(5),
+                length: 0x28,
+                minimum: 0x54,
+                since: SMBiosVersion::new(3, 2, 0),
+            }]
+        );
+
+        let data = SMBiosData::from_vec_and_version(table, Some(SMBiosVersion::new(2, 8, 0)));
+        assert!(data.validate().is_empty());
+
+        let mut builder = TableBuilder::new();
+        builder.add(StructBuilder::with_length(0, 0x12).byte(0x0A, 0x09));
+        builder.add(StructBuilder::new(0x80));
+        let mut table = builder.build();
+        // Give the OEM structure the same handle as the BIOS Information structure
+        table[0x12 + 2 + 2] = 0x00;
+        // Give the End-of-Table structure a reserved handle
+        table[0x12 + 2 + 6 + 2] = 0xFF;
+        table[0x12 + 2 + 6 + 3] = 0xFF;
+
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let findings = data.validate();
+        assert_eq!(
+            findings,
+            vec![
+                ValidationFinding::MissingStructure { struct_type: 1 },
+                ValidationFinding::MissingStructure { struct_type: 3 },
+                ValidationFinding::MissingStructure { struct_type: 4 },
+                ValidationFinding::MissingStructure { struct_type: 16 },
+                ValidationFinding::MissingStructure { struct_type: 17 },
+                ValidationFinding::MissingStructure { struct_type: 19 },
+                ValidationFinding::MissingStructure { struct_type: 32 },
+                ValidationFinding::ReservedBitsSet {
+                    struct_type: 0,
+                    handle: Handle(0),
+                    offset: 0x0A,
+                    bits: 0x01,
+                },
+                ValidationFinding::DuplicateHandle {
+                    struct_type: 0x80,
+                    handle: Handle(0),
+                },
+                ValidationFinding::ReservedHandle {
+                    struct_type: 127,
+                    handle: Handle(0xFFFF),
+                },
+            ]
+        );
+        assert_eq!(
+            findings[7].to_string(),
+            "type 0 handle 0x0000: reserved bits 0x01 set at offset 0x0A"
+        );
+    }
+}
@@ -0,0 +1,858 @@
+//! dmidecode compatible text output
+//!
+//! [dmidecode_decode] renders structures in the layout of dmidecode's default
+//! output, with section headings and indentation, showing the values this
+//! library decodes.  [dmidecode_dump] renders them in the layout of
+//! `dmidecode --dump` (`-u`), which prints each structure's header and raw data
+//! without decoding it; its output can be diffed against the reference tool
+//! after removing its preamble, for example `dmidecode -u | sed '1,/^$/d'`.
+//!
+//! [dmidecode_dump_bin] produces the file layout of `dmidecode --dump-bin`,
+//! which `dmidecode --from-dump` reads.
+
+use crate::core::{
+    entry_point_64_bytes, SMBiosData, SMBiosStringError, SMBiosVersion, UndefinedStruct,
+};
+use crate::structs::{DefinedStruct, SMBiosEndOfTable, SMBiosInactive, SMBiosStruct};
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use std::fmt::{self, Write};
+
+/// Renders the table in the layout of `dmidecode --dump`
+///
+/// Structures following the End-of-Table (type 127) structure are not included,
+/// matching dmidecode.
+pub fn dmidecode_dump(data: &SMBiosData) -> String {
+    let mut output = String::new();
+
+    for undefined_struct in data.iter() {
+        dmidecode_dump_struct(undefined_struct, &mut output);
+        output.push('\n');
+
+        if undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE {
+            break;
+        }
+    }
+
+    output
+}
+
+/// Renders a single structure in the layout of `dmidecode --dump`
+///
+/// The blank line dmidecode prints between structures is not included.
+pub fn dmidecode_dump_struct(undefined_struct: &UndefinedStruct, output: &mut String) {
+    push_handle_line(undefined_struct, output);
+    push_raw_data(undefined_struct, output);
+}
+
+/// Writes the line dmidecode prints before every structure
+fn push_handle_line(undefined_struct: &UndefinedStruct, output: &mut String) {
+    let header = &undefined_struct.header;
+    writeln!(
+        output,
+        "Handle 0x{:04X}, DMI type {}, {} bytes",
+        *header.handle(),
+        header.struct_type(),
+        header.length()
+    )
+    .expect("write to String");
+}
+
+/// Writes the raw formatted portion and strings of a structure
+fn push_raw_data(undefined_struct: &UndefinedStruct, output: &mut String) {
+    output.push_str("\tHeader and Data:\n");
+    push_hex_rows(&undefined_struct.fields, output);
+
+    let mut strings = undefined_struct.strings.iter().peekable();
+    if strings.peek().is_some() {
+        output.push_str("\tStrings:\n");
+        for string in strings {
+            let mut terminated = string.clone();
+            terminated.push(0);
+            push_hex_rows(&terminated, output);
+
+            // Non-printable characters are replaced as dmidecode does
+            let filtered: String = string
+                .iter()
+                .map(|&byte| {
+                    if (32..127).contains(&byte) {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            writeln!(output, "\t\t{}", filtered).expect("write to String");
+        }
+    }
+}
+
+/// Renders the table in the layout of dmidecode's default, decoded output
+///
+/// Each structure is decoded through its [DefinedStruct] and shown under its
+/// dmidecode heading, one field per line, using the field names and values of
+/// the structure's typed accessors (the same values [SMBiosData::to_json]
+/// reports).  Fields a structure's length or SMBIOS version does not provide
+/// are left out.  OEM-specific structures, and structures of a type whose
+/// category feature is disabled, are shown with their raw data as
+/// [dmidecode_dump] shows them.
+///
+/// Structures following the End-of-Table (type 127) structure are not included,
+/// matching dmidecode.
+pub fn dmidecode_decode(data: &SMBiosData) -> String {
+    let mut output = String::new();
+
+    for undefined_struct in data.iter() {
+        dmidecode_decode_struct(undefined_struct, &mut output);
+        output.push('\n');
+
+        if undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE {
+            break;
+        }
+    }
+
+    output
+}
+
+/// Renders a single structure in the layout of dmidecode's default, decoded output
+///
+/// The blank line dmidecode prints between structures is not included.
+pub fn dmidecode_decode_struct(undefined_struct: &UndefinedStruct, output: &mut String) {
+    push_handle_line(undefined_struct, output);
+
+    let struct_type = undefined_struct.header.struct_type();
+    let heading = match STRUCTURE_HEADINGS.get(struct_type as usize) {
+        Some(heading) => heading,
+        None if struct_type == SMBiosInactive::STRUCT_TYPE => "Inactive",
+        None if struct_type == SMBiosEndOfTable::STRUCT_TYPE => "End Of Table",
+        None if struct_type >= 128 => "OEM-specific Type",
+        None => "Unknown Type",
+    };
+    writeln!(output, "{}", heading).expect("write to String");
+
+    let defined_struct = undefined_struct.defined_struct();
+    let fields = match defined_struct {
+        DefinedStruct::Undefined(_) => None,
+        _ => match defined_struct.serialize(NodeSerializer) {
+            // The header is already shown by the handle line
+            Ok(Node::Variant(_, node)) => match *node {
+                Node::Record(fields) => Some(fields),
+                _ => None,
+            },
+            _ => None,
+        },
+    };
+
+    match fields {
+        Some(fields) => {
+            for (name, node) in fields.iter().filter(|(name, _)| name != "header") {
+                push_node(output, &field_title(name), node, 1);
+            }
+        }
+        None => push_raw_data(undefined_struct, output),
+    }
+}
+
+/// Headings of structure types 0 through 46
+const STRUCTURE_HEADINGS: [&str; 47] = [
+    "BIOS Information",
+    "System Information",
+    "Base Board Information",
+    "Chassis Information",
+    "Processor Information",
+    "Memory Controller Information",
+    "Memory Module Information",
+    "Cache Information",
+    "Port Connector Information",
+    "System Slot Information",
+    "On Board Device Information",
+    "OEM Strings",
+    "System Configuration Options",
+    "BIOS Language Information",
+    "Group Associations",
+    "System Event Log",
+    "Physical Memory Array",
+    "Memory Device",
+    "32-bit Memory Error Information",
+    "Memory Array Mapped Address",
+    "Memory Device Mapped Address",
+    "Built-in Pointing Device",
+    "Portable Battery",
+    "System Reset",
+    "Hardware Security",
+    "System Power Controls",
+    "Voltage Probe",
+    "Cooling Device",
+    "Temperature Probe",
+    "Electrical Current Probe",
+    "Out-of-band Remote Access",
+    "Boot Integrity Services Entry Point",
+    "System Boot Information",
+    "64-bit Memory Error Information",
+    "Management Device",
+    "Management Device Component",
+    "Management Device Threshold Data",
+    "Memory Channel",
+    "IPMI Device Information",
+    "System Power Supply",
+    "Additional Information",
+    "Onboard Device",
+    "Management Controller Host Interface",
+    "TPM Device",
+    "Processor Additional Information",
+    "Firmware Inventory Information",
+    "String Property",
+];
+
+/// A structure's serialized form, as collected by [NodeSerializer]
+enum Node {
+    /// `None` or `()`
+    Empty,
+    Bool(bool),
+    Unsigned(u64),
+    Text(String),
+    List(Vec<Node>),
+    Record(Vec<(String, Node)>),
+    /// A newtype, tuple or struct enum variant and its contents
+    Variant(&'static str, Box<Node>),
+}
+
+/// Writes `node` as the value of the field `name`, indented by `depth` tabs
+///
+/// Enum data (a record with a `value` field) is shown by its value, a record of
+/// flags by the names of the flags that are set, and lists one item per line.
+fn push_node(output: &mut String, name: &str, node: &Node, depth: usize) {
+    let indent = "\t".repeat(depth);
+    match node {
+        Node::Empty => (),
+        Node::Variant("Ok", node) => push_node(output, name, node, depth),
+        Node::Record(fields) if record_value(fields).is_some() => {
+            let value = record_value(fields).expect("record has a value");
+            writeln!(output, "{}{}: {}", indent, name, value).expect("write to String");
+            for (name, node) in fields
+                .iter()
+                .filter(|(name, _)| name != "value" && name != "raw")
+            {
+                push_node(output, &field_title(name), node, depth + 1);
+            }
+        }
+        Node::Record(fields) if is_flags(fields) => {
+            let set: Vec<&String> = fields
+                .iter()
+                .filter(|(_, node)| matches!(node, Node::Bool(true)))
+                .map(|(name, _)| name)
+                .collect();
+            if set.is_empty() {
+                writeln!(output, "{}{}: None", indent, name).expect("write to String");
+            } else {
+                writeln!(output, "{}{}:", indent, name).expect("write to String");
+                for name in set {
+                    writeln!(output, "{}\t{}", indent, field_title(name)).expect("write to String");
+                }
+            }
+        }
+        Node::Record(fields) => {
+            writeln!(output, "{}{}:", indent, name).expect("write to String");
+            for (name, node) in fields {
+                push_node(output, &field_title(name), node, depth + 1);
+            }
+        }
+        Node::List(items) if items.is_empty() => {
+            writeln!(output, "{}{}: None", indent, name).expect("write to String");
+        }
+        Node::List(items) => {
+            writeln!(output, "{}{}:", indent, name).expect("write to String");
+            for item in items {
+                writeln!(output, "{}\t{}", indent, inline(item)).expect("write to String");
+            }
+        }
+        _ => writeln!(output, "{}{}: {}", indent, name, inline(node)).expect("write to String"),
+    }
+}
+
+/// Formats `node` on a single line
+fn inline(node: &Node) -> String {
+    match node {
+        Node::Empty => "None".to_string(),
+        Node::Bool(value) => value.to_string(),
+        Node::Unsigned(value) => value.to_string(),
+        Node::Text(text) => text.clone(),
+        Node::List(items) => items.iter().map(inline).collect::<Vec<_>>().join(", "),
+        Node::Record(fields) => match record_value(fields) {
+            Some(value) => value,
+            None => fields
+                .iter()
+                .filter(|(_, node)| !matches!(node, Node::Empty))
+                .map(|(name, node)| format!("{}: {}", field_title(name), inline(node)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        },
+        Node::Variant("Ok", node) => inline(node),
+        Node::Variant(variant, node) => format!("{}({})", variant, inline(node)),
+    }
+}
+
+/// The value of enum data such as [crate::ChassisTypeData]
+///
+/// The raw value is shown when the library does not name it, as the `Display`
+/// implementations of those types do.
+fn record_value(fields: &[(String, Node)]) -> Option<String> {
+    let field = |name| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, node)| node)
+    };
+    match (field("value")?, field("raw")) {
+        (Node::Text(value), Some(raw)) if value == "None" => Some(inline(raw)),
+        (value, _) => Some(inline(value)),
+    }
+}
+
+/// Whether a record only holds flags, such as [crate::BiosCharacteristics]
+fn is_flags(fields: &[(String, Node)]) -> bool {
+    let mut flags = fields.iter().filter(|(name, _)| name != "raw").peekable();
+    flags.peek().is_some() && flags.all(|(_, node)| matches!(node, Node::Bool(_)))
+}
+
+/// Turns a field name such as `release_date` into `Release Date`
+fn field_title(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Error of [NodeSerializer]
+#[derive(Debug)]
+struct NodeError(String);
+
+impl fmt::Display for NodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for NodeError {}
+
+impl ser::Error for NodeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        NodeError(msg.to_string())
+    }
+}
+
+/// Collects the serialized form of a structure into a [Node] tree
+///
+/// Field order is kept, which a `serde_json::Value` does not do.
+struct NodeSerializer;
+
+/// Collects the elements of a sequence, tuple or tuple variant
+struct NodeList {
+    variant: Option<&'static str>,
+    items: Vec<Node>,
+}
+
+/// Collects the fields of a struct, struct variant or map
+struct NodeRecord {
+    variant: Option<&'static str>,
+    fields: Vec<(String, Node)>,
+    key: Option<String>,
+}
+
+impl NodeList {
+    fn node(self) -> Node {
+        let list = Node::List(self.items);
+        match self.variant {
+            Some(variant) => Node::Variant(variant, Box::new(list)),
+            None => list,
+        }
+    }
+}
+
+impl NodeRecord {
+    fn node(self) -> Node {
+        let record = Node::Record(self.fields);
+        match self.variant {
+            Some(variant) => Node::Variant(variant, Box::new(record)),
+            None => record,
+        }
+    }
+}
+
+impl Serializer for NodeSerializer {
+    type Ok = Node;
+    type Error = NodeError;
+    type SerializeSeq = NodeList;
+    type SerializeTuple = NodeList;
+    type SerializeTupleStruct = NodeList;
+    type SerializeTupleVariant = NodeList;
+    type SerializeMap = NodeRecord;
+    type SerializeStruct = NodeRecord;
+    type SerializeStructVariant = NodeRecord;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, NodeError> {
+        Ok(Node::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, NodeError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, NodeError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, NodeError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, NodeError> {
+        Ok(Node::Text(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, NodeError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, NodeError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, NodeError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, NodeError> {
+        Ok(Node::Unsigned(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, NodeError> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, NodeError> {
+        Ok(Node::Text(v.to_string()))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, NodeError> {
+        Ok(Node::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, NodeError> {
+        // A string field the structure is too short for is left out, and an
+        // unset one (string number 0) is shown as dmidecode shows it
+        if v == SMBiosStringError::FieldOutOfBounds.to_string() {
+            return Ok(Node::Empty);
+        }
+        match v.is_empty() {
+            true => Ok(Node::Text("Not Specified".to_string())),
+            false => Ok(Node::Text(v.to_string())),
+        }
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, NodeError> {
+        let hex: Vec<String> = v.iter().map(|byte| format!("{:02X}", byte)).collect();
+        Ok(Node::Text(hex.join(" ")))
+    }
+
+    fn serialize_none(self) -> Result<Node, NodeError> {
+        Ok(Node::Empty)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Node, NodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, NodeError> {
+        Ok(Node::Empty)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Node, NodeError> {
+        Ok(Node::Text(name.to_string()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Node, NodeError> {
+        Ok(Node::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Node, NodeError> {
+        match (name, value.serialize(self)?) {
+            // Handles are shown in hexadecimal, as dmidecode shows them
+            ("Handle", Node::Unsigned(handle)) => Ok(Node::Text(format!("0x{:04X}", handle))),
+            (_, node) => Ok(node),
+        }
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, NodeError> {
+        Ok(Node::Variant(variant, Box::new(value.serialize(self)?)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<NodeList, NodeError> {
+        Ok(NodeList {
+            variant: None,
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<NodeList, NodeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<NodeList, NodeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<NodeList, NodeError> {
+        Ok(NodeList {
+            variant: Some(variant),
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<NodeRecord, NodeError> {
+        Ok(NodeRecord {
+            variant: None,
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<NodeRecord, NodeError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<NodeRecord, NodeError> {
+        Ok(NodeRecord {
+            variant: Some(variant),
+            fields: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+}
+
+impl SerializeSeq for NodeList {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), NodeError> {
+        self.items.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeTuple for NodeList {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), NodeError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeTupleStruct for NodeList {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), NodeError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeTupleVariant for NodeList {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), NodeError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeMap for NodeRecord {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), NodeError> {
+        self.key = Some(inline(&key.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), NodeError> {
+        let key = self.key.take().unwrap_or_default();
+        self.fields.push((key, value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeStruct for NodeRecord {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), NodeError> {
+        self.fields
+            .push((key.to_string(), value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+impl SerializeStructVariant for NodeRecord {
+    type Ok = Node;
+    type Error = NodeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), NodeError> {
+        SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Node, NodeError> {
+        Ok(self.node())
+    }
+}
+
+/// Offset of the table within a `dmidecode --dump-bin` file
+const DUMP_BIN_TABLE_OFFSET: usize = 0x20;
+
+/// Produces the contents of a `dmidecode --dump-bin` file
+///
+/// The file holds a 64-bit (_SM3_) entry point whose table address is the
+/// offset of the table within the file (0x20), followed by the table.  Tables of
+/// unknown version are given version 3.0.0.
+pub fn dmidecode_dump_bin(data: &SMBiosData) -> Vec<u8> {
+    let table = data.to_bytes();
+    let version = data.version.unwrap_or_else(|| SMBiosVersion::new(3, 0, 0));
+
+    let mut dump = entry_point_64_bytes(version, table.len(), DUMP_BIN_TABLE_OFFSET as u64);
+    dump.resize(DUMP_BIN_TABLE_OFFSET, 0);
+    dump.extend(table);
+    dump
+}
+
+/// Writes `bytes` as rows of up to 16 space separated hex values
+fn push_hex_rows(bytes: &[u8], output: &mut String) {
+    for row in bytes.chunks(16) {
+        let hex: Vec<String> = row.iter().map(|byte| format!("{:02X}", byte)).collect();
+        writeln!(output, "\t\t{}", hex.join(" ")).expect("write to String");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SMBiosEntryPoint64;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_dmidecode_dump() {
+        // Type 0 (BIOS Information) with a vendor string containing a tab
+        let mut raw = vec![
+            0x00, 0x12, 0x00, 0x00, 0x01, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        raw.extend_from_slice(b"Acme\tInc. BIOS Vendor\0\0");
+        // End-of-Table
+        raw.extend_from_slice(&[0x7F, 0x04, 0x01, 0x00, 0x00, 0x00]);
+        // Ignored after End-of-Table
+        raw.extend_from_slice(&[0x01, 0x04, 0x02, 0x00, 0x00, 0x00]);
+        let data = SMBiosData::from_vec_and_version(raw, None);
+
+        let expected = "\
+Handle 0x0000, DMI type 0, 18 bytes
+\tHeader and Data:
+\t\t00 12 00 00 01 00 00 F0 00 00 00 00 00 00 00 00
+\t\t00 00
+\tStrings:
+\t\t41 63 6D 65 09 49 6E 63 2E 20 42 49 4F 53 20 56
+\t\t65 6E 64 6F 72 00
+\t\tAcme.Inc. BIOS Vendor
+
+Handle 0x0001, DMI type 127, 4 bytes
+\tHeader and Data:
+\t\t7F 04 01 00
+
+";
+        assert_eq!(dmidecode_dump(&data), expected);
+    }
+
+    #[test]
+    fn test_dmidecode_decode() {
+        // Type 0 (BIOS Information), SMBIOS 2.4 length
+        let mut raw = vec![
+            0x00, 0x18, 0x00, 0x00, 0x01, 0x02, 0x00, 0xE8, 0x00, 0x0F, 0x80, 0x98, 0x0B, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x01, 0x02, 0x05, 0xFF, 0xFF,
+        ];
+        raw.extend_from_slice(b"Acme\0V1.02\0\0");
+        // Type 1 (System Information) with a UUID and no SKU Number or Family
+        raw.extend_from_slice(&[
+            0x01, 0x19, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
+            0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x06,
+        ]);
+        raw.extend_from_slice(b"Acme\0\0");
+        // An OEM-specific structure is shown with its raw data
+        raw.extend_from_slice(&[0x80, 0x05, 0x02, 0x00, 0xAA, 0x00, 0x00]);
+        // End-of-Table
+        raw.extend_from_slice(&[0x7F, 0x04, 0x03, 0x00, 0x00, 0x00]);
+        let data = SMBiosData::from_vec_and_version(raw, Some(SMBiosVersion::new(2, 4, 0)));
+
+        let expected = "\
+Handle 0x0000, DMI type 0, 24 bytes
+BIOS Information
+\tVendor: Acme
+\tVersion: V1.02
+\tStarting Address Segment: 59392
+\tRelease Date: Not Specified
+\tRom Size: Kilobytes(1024)
+\tCharacteristics:
+\t\tPci Supported
+\t\tBios Upgradeable
+\t\tBios Shadowing Allowed
+\t\tBoot From Cdsupported
+\t\tSelectable Boot Supported
+\t\tBios Rom Socketed
+\t\tEdd Specification Supported
+\tBios Vendor Reserved Characteristics: 0
+\tSystem Vendor Reserved Characteristics: 0
+\tCharacteristics Extension0:
+\t\tAcpi Is Supported
+\t\tUsb Legacy Is Supported
+\tCharacteristics Extension1:
+\t\tBios Boot Specification Is Supported
+\tSystem Bios Major Release: 2
+\tSystem Bios Minor Release: 5
+\tE C Firmware Major Release: 255
+\tE C Firmware Minor Release: 255
+
+Handle 0x0001, DMI type 1, 25 bytes
+System Information
+\tManufacturer: Acme
+\tProduct Name: Not Specified
+\tVersion: Not Specified
+\tSerial Number: Not Specified
+\tUuid: Uuid(03020100-0504-0706-0809-0a0b0c0d0e0f)
+\tWakeup Type: PowerSwitch
+
+Handle 0x0002, DMI type 128, 5 bytes
+OEM-specific Type
+\tHeader and Data:
+\t\t80 05 02 00 AA
+
+Handle 0x0003, DMI type 127, 4 bytes
+End Of Table
+
+";
+        assert_eq!(dmidecode_decode(&data), expected);
+    }
+
+    #[cfg(feature = "platform")]
+    #[test]
+    fn test_dmidecode_decode_struct() {
+        // Type 3 (System Enclosure or Chassis), SMBIOS 2.1 length
+        let mut raw = vec![
+            0x03, 0x0D, 0x05, 0x00, 0x01, 0x83, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03,
+        ];
+        raw.extend_from_slice(b"Acme\0\0");
+        let data = SMBiosData::from_vec_and_version(raw, Some(SMBiosVersion::new(2, 1, 0)));
+
+        let mut output = String::new();
+        dmidecode_decode_struct(data.iter().next().unwrap(), &mut output);
+        let expected = "\
+Handle 0x0005, DMI type 3, 13 bytes
+Chassis Information
+\tManufacturer: Acme
+\tChassis Type: Desktop
+\t\tLock Presence: Present
+\tVersion: Not Specified
+\tSerial Number: Not Specified
+\tAsset Tag Number: Not Specified
+\tBootup State: Safe
+\tPower Supply State: Safe
+\tThermal State: Safe
+\tSecurity Status: StatusNone
+";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_dmidecode_dump_bin() {
+        let raw = vec![0x7F, 0x04, 0x01, 0x00, 0x00, 0x00];
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(3, 4, 0)));
+
+        let dump = dmidecode_dump_bin(&data);
+        assert_eq!(dump.len(), DUMP_BIN_TABLE_OFFSET + raw.len());
+        assert_eq!(&dump[DUMP_BIN_TABLE_OFFSET..], raw.as_slice());
+
+        let entry_point = SMBiosEntryPoint64::try_from(dump[..0x18].to_vec()).unwrap();
+        assert_eq!(entry_point.version(), SMBiosVersion::new(3, 4, 0));
+        assert_eq!(entry_point.structure_table_address(), 0x20);
+        assert_eq!(entry_point.structure_table_maximum_size(), 6);
+    }
+}
@@ -0,0 +1,935 @@
+//! System event log reader
+//!
+//! Reads the event log area described by a [SMBiosSystemEventLog] (Type 15)
+//! structure and parses its header and log records.  Access to the storage
+//! holding the log (physical memory, I/O ports or GPNV functions) is supplied
+//! by the caller through [EventLogAccess] as this library does not perform
+//! privileged operations itself.
+
+use crate::core::flag_set;
+use crate::{
+    AccessMethod, AccessMethodAddress, BcdValue, Handle, HeaderFormat, LogTypeData,
+    SMBiosSystemEventLog, VariableDataFormatType, VariableDataFormatTypeData,
+};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+use std::io::{Error, ErrorKind};
+
+/// Access to the storage holding the event log area
+///
+/// Only the operations required by the [AccessMethod] of the log being read
+/// need to be implemented; the default implementations fail with
+/// [ErrorKind::Other].
+pub trait EventLogAccess {
+    /// Fills `buffer` with the physical memory starting at `address`
+    fn read_memory(&mut self, address: u32, buffer: &mut [u8]) -> Result<(), Error> {
+        let _ = (address, buffer);
+        Err(unsupported("memory-mapped"))
+    }
+
+    /// Reads a byte from the I/O `port`
+    fn read_io_byte(&mut self, port: u16) -> Result<u8, Error> {
+        let _ = port;
+        Err(unsupported("indexed I/O"))
+    }
+
+    /// Writes a byte to the I/O `port`
+    fn write_io_byte(&mut self, port: u16, value: u8) -> Result<(), Error> {
+        let _ = (port, value);
+        Err(unsupported("indexed I/O"))
+    }
+
+    /// Writes a word to the I/O `port`
+    fn write_io_word(&mut self, port: u16, value: u16) -> Result<(), Error> {
+        let _ = (port, value);
+        Err(unsupported("indexed I/O"))
+    }
+
+    /// Fills `buffer` with the General-Purpose NonVolatile data identified by `handle`
+    fn read_gpnv(&mut self, handle: u16, buffer: &mut [u8]) -> Result<(), Error> {
+        let _ = (handle, buffer);
+        Err(unsupported("GPNV"))
+    }
+}
+
+fn unsupported(access_method: &str) -> Error {
+    Error::other(format!("{} access is not supported", access_method))
+}
+
+/// # System Event Log
+///
+/// The contents of the event log area together with the layout information
+/// taken from the [SMBiosSystemEventLog] structure that describes it.
+pub struct EventLog {
+    area: Vec<u8>,
+    header_start: usize,
+    data_start: usize,
+    header_format: HeaderFormat,
+    descriptors: Vec<(u8, u8)>,
+}
+
+impl EventLog {
+    /// Reads the log area described by `system_event_log` using `access`
+    ///
+    /// For the indexed I/O access methods each byte is read by writing its
+    /// offset to the index port and then reading the data port.  With two
+    /// 8-bit index ports (access method 01h) the least-significant byte of the
+    /// offset is written to the index port and the most-significant byte to
+    /// the following port.
+    pub fn read(
+        system_event_log: &SMBiosSystemEventLog<'_>,
+        access: &mut impl EventLogAccess,
+    ) -> Result<Self, Error> {
+        let invalid = |field: &str| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("System Event Log {} is missing", field),
+            )
+        };
+
+        let length = system_event_log
+            .log_area_length()
+            .ok_or_else(|| invalid("log area length"))? as usize;
+        let access_method = system_event_log
+            .access_method()
+            .ok_or_else(|| invalid("access method"))?;
+        let address = system_event_log
+            .access_method_address_decoded()
+            .ok_or_else(|| invalid("access method address"))?;
+
+        let mut area = vec![0u8; length];
+        match address {
+            AccessMethodAddress::IndexedIO { index, data } => {
+                for (offset, byte) in area.iter_mut().enumerate() {
+                    let offset = offset as u16;
+                    match access_method.value {
+                        AccessMethod::IndexedIO18Bit => {
+                            access.write_io_byte(index, offset as u8)?
+                        }
+                        AccessMethod::IndexedIO28Bit => {
+                            access.write_io_byte(index, offset as u8)?;
+                            access.write_io_byte(index.wrapping_add(1), (offset >> 8) as u8)?
+                        }
+                        _ => access.write_io_word(index, offset)?,
+                    }
+                    *byte = access.read_io_byte(data)?;
+                }
+            }
+            AccessMethodAddress::MemoryMapped32Bit(address) => {
+                access.read_memory(address, &mut area)?
+            }
+            AccessMethodAddress::GeneralPurposeNonVolatile(handle) => {
+                access.read_gpnv(handle, &mut area)?
+            }
+            AccessMethodAddress::None(_) => {
+                return Err(Error::other(format!(
+                    "Unknown access method {}",
+                    access_method.raw
+                )))
+            }
+        }
+
+        Ok(Self::from_area(system_event_log, area))
+    }
+
+    /// Parses an already retrieved log area described by `system_event_log`
+    pub fn from_area(system_event_log: &SMBiosSystemEventLog<'_>, area: Vec<u8>) -> Self {
+        let header_start = system_event_log.log_header_start_offset().unwrap_or(0) as usize;
+        let data_start = system_event_log
+            .log_data_start_offset()
+            .map(|offset| offset as usize)
+            .unwrap_or(header_start);
+        let header_format = system_event_log
+            .log_header_format()
+            .map(|format| format.value)
+            .unwrap_or(HeaderFormat::None);
+        let descriptors = system_event_log
+            .type_descriptors()
+            .map(|descriptors| {
+                descriptors
+                    .into_iter()
+                    .map(|descriptor| {
+                        (
+                            descriptor.log_type().raw,
+                            descriptor.variable_data_format_type().raw,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        EventLog {
+            area,
+            header_start,
+            data_start,
+            header_format,
+            descriptors,
+        }
+    }
+
+    /// Raw bytes of the entire log area
+    pub fn area(&self) -> &[u8] {
+        &self.area
+    }
+
+    /// The Type 1 log header, if the log uses that header format
+    pub fn header(&self) -> Option<LogHeaderType1<'_>> {
+        match self.header_format {
+            HeaderFormat::Type1LogHeader => self
+                .area
+                .get(self.header_start..self.data_start)
+                .and_then(LogHeaderType1::new),
+            _ => None,
+        }
+    }
+
+    /// The [VariableDataFormatTypeData] supported for the given raw log type
+    ///
+    /// Taken from the type descriptors of the [SMBiosSystemEventLog] structure.
+    pub fn variable_data_format(&self, log_type: u8) -> Option<VariableDataFormatTypeData> {
+        self.descriptors
+            .iter()
+            .find(|(descriptor_type, _)| *descriptor_type == log_type)
+            .map(|(_, format)| VariableDataFormatTypeData::from(*format))
+    }
+
+    /// Iterates over the [EventLogRecord] entries of the log
+    pub fn records(&self) -> EventLogRecordIterator<'_> {
+        EventLogRecordIterator {
+            log: self,
+            current_index: self.data_start,
+        }
+    }
+}
+
+impl fmt::Debug for EventLog {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<EventLog>())
+            .field("header", &self.header())
+            .field("records", &self.records().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Serialize for EventLog {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("EventLog", 2)?;
+        state.serialize_field("header", &self.header())?;
+        state.serialize_field("records", &self.records().collect::<Vec<_>>())?;
+        state.end()
+    }
+}
+
+/// # Log Header (Type 1 Format)
+pub struct LogHeaderType1<'a> {
+    /// Raw bytes of the header
+    pub raw: &'a [u8],
+}
+
+impl<'a> LogHeaderType1<'a> {
+    /// Size in bytes of a Type 1 log header
+    const SIZE: usize = 0x10;
+
+    fn new(raw: &'a [u8]) -> Option<Self> {
+        if raw.len() < Self::SIZE {
+            None
+        } else {
+            Some(Self { raw })
+        }
+    }
+
+    /// Reserved area for OEM customization
+    pub fn oem_reserved(&self) -> &'a [u8] {
+        &self.raw[0x00..0x05]
+    }
+
+    /// Number of minutes that must pass between duplicate log entries that
+    /// utilize a multiple-event counter
+    pub fn multiple_event_time_window(&self) -> u8 {
+        self.raw[0x05]
+    }
+
+    /// Number of occurrences of a duplicate event that must pass before the
+    /// multiple-event counter associated with the log entry is updated
+    pub fn multiple_event_count_increment(&self) -> u8 {
+        self.raw[0x06]
+    }
+
+    /// CMOS RAM address (in the range 10h - FFh) associated with the Pre-boot
+    /// Event Log Reset, or 00h if the feature is not supported
+    pub fn pre_boot_event_log_reset_cmos_address(&self) -> u8 {
+        self.raw[0x07]
+    }
+
+    /// Bit within the CMOS RAM location that is set to indicate that the log
+    /// should be cleared
+    pub fn pre_boot_event_log_reset_cmos_bit_index(&self) -> u8 {
+        self.raw[0x08]
+    }
+
+    /// CMOS RAM address associated with the start of the area that is to be
+    /// checksummed, or 00h if the CMOS checksum feature is not supported
+    pub fn cmos_checksum_starting_offset(&self) -> u8 {
+        self.raw[0x09]
+    }
+
+    /// Number of consecutive CMOS RAM addresses, beginning at the starting
+    /// offset, that participate in the CMOS checksum
+    pub fn cmos_checksum_byte_count(&self) -> u8 {
+        self.raw[0x0A]
+    }
+
+    /// CMOS RAM address associated with the start of the two-byte CMOS
+    /// checksum value
+    pub fn cmos_checksum_checksum_offset(&self) -> u8 {
+        self.raw[0x0B]
+    }
+
+    /// Version of the Type 1 header implemented (01h)
+    pub fn header_revision(&self) -> u8 {
+        self.raw[0x0F]
+    }
+}
+
+impl fmt::Debug for LogHeaderType1<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<LogHeaderType1<'_>>())
+            .field("oem_reserved", &self.oem_reserved())
+            .field(
+                "multiple_event_time_window",
+                &self.multiple_event_time_window(),
+            )
+            .field(
+                "multiple_event_count_increment",
+                &self.multiple_event_count_increment(),
+            )
+            .field(
+                "pre_boot_event_log_reset_cmos_address",
+                &self.pre_boot_event_log_reset_cmos_address(),
+            )
+            .field(
+                "pre_boot_event_log_reset_cmos_bit_index",
+                &self.pre_boot_event_log_reset_cmos_bit_index(),
+            )
+            .field(
+                "cmos_checksum_starting_offset",
+                &self.cmos_checksum_starting_offset(),
+            )
+            .field("cmos_checksum_byte_count", &self.cmos_checksum_byte_count())
+            .field(
+                "cmos_checksum_checksum_offset",
+                &self.cmos_checksum_checksum_offset(),
+            )
+            .field("header_revision", &self.header_revision())
+            .finish()
+    }
+}
+
+impl Serialize for LogHeaderType1<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LogHeaderType1", 9)?;
+        state.serialize_field("oem_reserved", &self.oem_reserved())?;
+        state.serialize_field(
+            "multiple_event_time_window",
+            &self.multiple_event_time_window(),
+        )?;
+        state.serialize_field(
+            "multiple_event_count_increment",
+            &self.multiple_event_count_increment(),
+        )?;
+        state.serialize_field(
+            "pre_boot_event_log_reset_cmos_address",
+            &self.pre_boot_event_log_reset_cmos_address(),
+        )?;
+        state.serialize_field(
+            "pre_boot_event_log_reset_cmos_bit_index",
+            &self.pre_boot_event_log_reset_cmos_bit_index(),
+        )?;
+        state.serialize_field(
+            "cmos_checksum_starting_offset",
+            &self.cmos_checksum_starting_offset(),
+        )?;
+        state.serialize_field("cmos_checksum_byte_count", &self.cmos_checksum_byte_count())?;
+        state.serialize_field(
+            "cmos_checksum_checksum_offset",
+            &self.cmos_checksum_checksum_offset(),
+        )?;
+        state.serialize_field("header_revision", &self.header_revision())?;
+        state.end()
+    }
+}
+
+/// # Event Log Record within [EventLog]
+pub struct EventLogRecord<'a> {
+    /// Raw bytes of the record, including the type and length fields
+    pub raw: &'a [u8],
+    variable_data_format: Option<VariableDataFormatTypeData>,
+}
+
+impl<'a> EventLogRecord<'a> {
+    /// Size in bytes of the fixed portion of a record
+    const MINIMUM_SIZE: usize = 8;
+    /// Log type marking the end of the log
+    const END_OF_LOG: u8 = 0xFF;
+
+    /// Event Type
+    pub fn log_type(&self) -> LogTypeData {
+        LogTypeData::from(self.raw[0x00])
+    }
+
+    /// Length of the record in bytes, including the type and length fields
+    pub fn length(&self) -> u8 {
+        self.raw[0x01] & 0x7F
+    }
+
+    /// If true, the record has been read (processed by higher level software)
+    pub fn read(&self) -> bool {
+        self.raw[0x01] & 0x80 == 0x80
+    }
+
+    /// Date and time the event was logged
+    pub fn timestamp(&self) -> Option<EventLogTimestamp> {
+        EventLogTimestamp::from_bcd(self.raw[0x02..0x08].try_into().ok()?)
+    }
+
+    /// Log variable data
+    pub fn variable_data(&self) -> &'a [u8] {
+        &self.raw[Self::MINIMUM_SIZE..]
+    }
+
+    /// Variable data format of this record's log type, as listed in the
+    /// [SMBiosSystemEventLog] type descriptors
+    pub fn variable_data_format(&self) -> Option<&VariableDataFormatTypeData> {
+        self.variable_data_format.as_ref()
+    }
+
+    /// Log variable data decoded according to the [variable_data_format](Self::variable_data_format)
+    ///
+    /// Records whose log type has no descriptor, or whose variable data is
+    /// too short for its format, are returned as [EventLogData::Unformatted].
+    pub fn data(&self) -> EventLogData<'a> {
+        let data = self.variable_data();
+        let word = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        };
+        let dword = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        };
+
+        let decoded = match self
+            .variable_data_format
+            .as_ref()
+            .map(|format| &format.value)
+        {
+            Some(VariableDataFormatType::Handle) => word(0).map(|handle| EventLogData::Handle {
+                handle: Handle(handle),
+            }),
+            Some(VariableDataFormatType::MultipleEvent) => {
+                dword(0).map(|counter| EventLogData::MultipleEvent { counter })
+            }
+            Some(VariableDataFormatType::MultipleEventHandle) => word(0).and_then(|handle| {
+                dword(2).map(|counter| EventLogData::MultipleEventHandle {
+                    handle: Handle(handle),
+                    counter,
+                })
+            }),
+            Some(VariableDataFormatType::PostResultsBitmap) => dword(0).and_then(|first| {
+                dword(4).map(|second| EventLogData::PostResults {
+                    first: PostResultsBitmap::from(first),
+                    second,
+                })
+            }),
+            Some(VariableDataFormatType::SystemManagementType) => {
+                dword(0).map(|system_management_type| EventLogData::SystemManagement {
+                    system_management_type,
+                })
+            }
+            Some(VariableDataFormatType::MultipleEventSystemManagementType) => {
+                dword(0).and_then(|system_management_type| {
+                    dword(4).map(|counter| EventLogData::MultipleEventSystemManagement {
+                        system_management_type,
+                        counter,
+                    })
+                })
+            }
+            _ => None,
+        };
+
+        decoded.unwrap_or(EventLogData::Unformatted(data))
+    }
+}
+
+impl fmt::Debug for EventLogRecord<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<EventLogRecord<'_>>())
+            .field("log_type", &self.log_type())
+            .field("length", &self.length())
+            .field("read", &self.read())
+            .field("timestamp", &self.timestamp())
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+impl Serialize for EventLogRecord<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("EventLogRecord", 5)?;
+        state.serialize_field("log_type", &self.log_type())?;
+        state.serialize_field("length", &self.length())?;
+        state.serialize_field("read", &self.read())?;
+        state.serialize_field("timestamp", &self.timestamp())?;
+        state.serialize_field("data", &self.data())?;
+        state.end()
+    }
+}
+
+/// # Iterates over the [EventLogRecord] entries within [EventLog]
+///
+/// Iteration stops at the end-of-log record (type FFh), at the end of the log
+/// area, or at the first record whose length is invalid.
+pub struct EventLogRecordIterator<'a> {
+    log: &'a EventLog,
+    current_index: usize,
+}
+
+impl<'a> Iterator for EventLogRecordIterator<'a> {
+    type Item = EventLogRecord<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.log.area.get(self.current_index..)?;
+        if remaining.len() < EventLogRecord::MINIMUM_SIZE
+            || remaining[0x00] == EventLogRecord::END_OF_LOG
+        {
+            return None;
+        }
+
+        let length = (remaining[0x01] & 0x7F) as usize;
+        if length < EventLogRecord::MINIMUM_SIZE || length > remaining.len() {
+            self.current_index = self.log.area.len();
+            return None;
+        }
+
+        self.current_index += length;
+        Some(EventLogRecord {
+            raw: &remaining[..length],
+            variable_data_format: self.log.variable_data_format(remaining[0x00]),
+        })
+    }
+}
+
+/// # Event Log Record Date and Time
+#[derive(Serialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct EventLogTimestamp {
+    /// Year (1980 - 2079)
+    pub year: u16,
+    /// Month (1 - 12)
+    pub month: u8,
+    /// Day of the month (1 - 31)
+    pub day: u8,
+    /// Hour (0 - 23)
+    pub hour: u8,
+    /// Minute (0 - 59)
+    pub minute: u8,
+    /// Second (0 - 59)
+    pub second: u8,
+}
+
+impl EventLogTimestamp {
+    /// Decodes the BCD year, month, day, hour, minute and second fields of a log record
+    ///
+    /// Years 80h - 99h are 1980 - 1999 and 00h - 79h are 2000 - 2079.
+    fn from_bcd(raw: [u8; 6]) -> Option<Self> {
+        let bcd = |raw: u8| BcdValue::from(raw).value();
+
+        let year = bcd(raw[0])? as u16;
+        Some(EventLogTimestamp {
+            year: if year >= 80 { 1900 + year } else { 2000 + year },
+            month: bcd(raw[1])?,
+            day: bcd(raw[2])?,
+            hour: bcd(raw[3])?,
+            minute: bcd(raw[4])?,
+            second: bcd(raw[5])?,
+        })
+    }
+}
+
+impl fmt::Display for EventLogTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// # Event Log Record Variable Data
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum EventLogData<'a> {
+    /// Variable data with no standard format (OEM-specific, unknown or truncated)
+    Unformatted(&'a [u8]),
+    /// Handle of the structure associated with the hardware element that failed
+    Handle {
+        /// Structure handle
+        handle: Handle,
+    },
+    /// Multiple-event counter
+    MultipleEvent {
+        /// Multiple-event counter
+        counter: u32,
+    },
+    /// Handle of the structure associated with the hardware element that
+    /// failed followed by a multiple-event counter
+    MultipleEventHandle {
+        /// Structure handle
+        handle: Handle,
+        /// Multiple-event counter
+        counter: u32,
+    },
+    /// POST results bitmap
+    PostResults {
+        /// First DWORD of the bitmap
+        first: PostResultsBitmap,
+        /// Second DWORD of the bitmap, valid when the first DWORD has
+        /// [PostResultsBitmap::SECOND_DWORD_VALID] set
+        second: u32,
+    },
+    /// System management condition
+    SystemManagement {
+        /// System management type
+        system_management_type: u32,
+    },
+    /// System management condition followed by a multiple-event counter
+    MultipleEventSystemManagement {
+        /// System management type
+        system_management_type: u32,
+        /// Multiple-event counter
+        counter: u32,
+    },
+}
+
+/// # POST Results Bitmap (first DWORD)
+///
+/// ```
+/// use smbioslib::*;
+///
+/// let bitmap = PostResultsBitmap::from(0x0000_0028);
+/// assert!(bitmap.contains(PostResultsBitmap::CMOS_BATTERY_FAILURE | PostResultsBitmap::CMOS_CHECKSUM_ERROR));
+/// assert!(!bitmap.contains(PostResultsBitmap::KEYBOARD_LOCKED));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct PostResultsBitmap {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl PostResultsBitmap {
+    /// Channel 2 Timer error
+    pub const CHANNEL_2_TIMER_ERROR: Self = Self::flag(1 << 0);
+    /// Master PIC (8259 #1) error
+    pub const MASTER_PIC_ERROR: Self = Self::flag(1 << 1);
+    /// Slave PIC (8259 #2) error
+    pub const SLAVE_PIC_ERROR: Self = Self::flag(1 << 2);
+    /// CMOS Battery Failure
+    pub const CMOS_BATTERY_FAILURE: Self = Self::flag(1 << 3);
+    /// CMOS System Options Not Set
+    pub const CMOS_SYSTEM_OPTIONS_NOT_SET: Self = Self::flag(1 << 4);
+    /// CMOS Checksum Error
+    pub const CMOS_CHECKSUM_ERROR: Self = Self::flag(1 << 5);
+    /// CMOS Configuration Error
+    pub const CMOS_CONFIGURATION_ERROR: Self = Self::flag(1 << 6);
+    /// Mouse and Keyboard Swapped
+    pub const MOUSE_AND_KEYBOARD_SWAPPED: Self = Self::flag(1 << 7);
+    /// Keyboard Locked
+    pub const KEYBOARD_LOCKED: Self = Self::flag(1 << 8);
+    /// Keyboard Not Functional
+    pub const KEYBOARD_NOT_FUNCTIONAL: Self = Self::flag(1 << 9);
+    /// Keyboard Controller Not Functional
+    pub const KEYBOARD_CONTROLLER_NOT_FUNCTIONAL: Self = Self::flag(1 << 10);
+    /// CMOS Memory Size Different
+    pub const CMOS_MEMORY_SIZE_DIFFERENT: Self = Self::flag(1 << 11);
+    /// Memory Decreased in Size
+    pub const MEMORY_DECREASED_IN_SIZE: Self = Self::flag(1 << 12);
+    /// Cache Memory Error
+    pub const CACHE_MEMORY_ERROR: Self = Self::flag(1 << 13);
+    /// Floppy Drive 0 Error
+    pub const FLOPPY_DRIVE_0_ERROR: Self = Self::flag(1 << 14);
+    /// Floppy Drive 1 Error
+    pub const FLOPPY_DRIVE_1_ERROR: Self = Self::flag(1 << 15);
+    /// Floppy Controller Failure
+    pub const FLOPPY_CONTROLLER_FAILURE: Self = Self::flag(1 << 16);
+    /// Number of ATA Drives Reduced Error
+    pub const ATA_DRIVES_REDUCED: Self = Self::flag(1 << 17);
+    /// CMOS Time Not Set
+    pub const CMOS_TIME_NOT_SET: Self = Self::flag(1 << 18);
+    /// DDC Monitor Configuration Change
+    pub const DDC_MONITOR_CONFIGURATION_CHANGE: Self = Self::flag(1 << 19);
+    /// Second DWORD has valid data
+    pub const SECOND_DWORD_VALID: Self = Self::flag(1 << 28);
+
+    const fn flag(raw: u32) -> Self {
+        Self { raw }
+    }
+}
+
+impl From<u32> for PostResultsBitmap {
+    fn from(raw: u32) -> Self {
+        PostResultsBitmap { raw }
+    }
+}
+
+flag_set!(
+    PostResultsBitmap,
+    [
+        ("Channel 2 Timer error", CHANNEL_2_TIMER_ERROR),
+        ("Master PIC (8259 #1) error", MASTER_PIC_ERROR),
+        ("Slave PIC (8259 #2) error", SLAVE_PIC_ERROR),
+        ("CMOS Battery Failure", CMOS_BATTERY_FAILURE),
+        ("CMOS System Options Not Set", CMOS_SYSTEM_OPTIONS_NOT_SET),
+        ("CMOS Checksum Error", CMOS_CHECKSUM_ERROR),
+        ("CMOS Configuration Error", CMOS_CONFIGURATION_ERROR),
+        ("Mouse and Keyboard Swapped", MOUSE_AND_KEYBOARD_SWAPPED),
+        ("Keyboard Locked", KEYBOARD_LOCKED),
+        ("Keyboard Not Functional", KEYBOARD_NOT_FUNCTIONAL),
+        (
+            "Keyboard Controller Not Functional",
+            KEYBOARD_CONTROLLER_NOT_FUNCTIONAL
+        ),
+        ("CMOS Memory Size Different", CMOS_MEMORY_SIZE_DIFFERENT),
+        ("Memory Decreased in Size", MEMORY_DECREASED_IN_SIZE),
+        ("Cache Memory Error", CACHE_MEMORY_ERROR),
+        ("Floppy Drive 0 Error", FLOPPY_DRIVE_0_ERROR),
+        ("Floppy Drive 1 Error", FLOPPY_DRIVE_1_ERROR),
+        ("Floppy Controller Failure", FLOPPY_CONTROLLER_FAILURE),
+        ("Number of ATA Drives Reduced Error", ATA_DRIVES_REDUCED),
+        ("CMOS Time Not Set", CMOS_TIME_NOT_SET),
+        (
+            "DDC Monitor Configuration Change",
+            DDC_MONITOR_CONFIGURATION_CHANGE
+        ),
+        ("Second DWORD has valid data", SECOND_DWORD_VALID),
+    ]
+);
+
+impl fmt::Debug for PostResultsBitmap {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<PostResultsBitmap>())
+            .field("raw", &self.raw)
+            .field(
+                "flags",
+                &self.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Serialize for PostResultsBitmap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PostResultsBitmap", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field(
+            "flags",
+            &self.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
+    use crate::{LogType, SMBiosStruct};
+    use std::collections::HashMap;
+
+    fn table(access_method: u8, address: u32) -> crate::SMBiosData {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::with_length(SMBiosSystemEventLog::STRUCT_TYPE, 0x17)
+                .word(0x04, 0x40)
+                .word(0x06, 0x00)
+                .word(0x08, 0x10)
+                .byte(0x0A, access_method)
+                .dword(0x10, address)
+                .byte(0x14, 0x01)
+                .byte(0x15, 3)
+                .byte(0x16, 2)
+                // POST error with a results bitmap, single-bit ECC error with
+                // a handle, and system boot with no standard format
+                .data(0x17, &[0x08, 0x04, 0x01, 0x01, 0x17, 0x00]),
+        );
+        builder.build_smbios_data(None)
+    }
+
+    fn log_area() -> Vec<u8> {
+        let mut area = vec![0u8; 0x40];
+        // Type 1 header
+        area[0x05] = 60;
+        area[0x06] = 2;
+        area[0x0F] = 0x01;
+        // POST error on 2021-09-15 10:20:30 with CMOS battery failure
+        area[0x10..0x20].copy_from_slice(&[
+            0x08, 0x10, 0x21, 0x09, 0x15, 0x10, 0x20, 0x30, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ]);
+        // Read single-bit ECC error on 1999-12-31 23:59:59 for handle 0x1100
+        area[0x20..0x2A]
+            .copy_from_slice(&[0x01, 0x8A, 0x99, 0x12, 0x31, 0x23, 0x59, 0x59, 0x00, 0x11]);
+        // System boot
+        area[0x2A..0x32].copy_from_slice(&[0x17, 0x08, 0x21, 0x09, 0x15, 0x10, 0x21, 0x00]);
+        // End of log
+        for byte in area[0x32..].iter_mut() {
+            *byte = 0xFF;
+        }
+        area
+    }
+
+    fn check_log(log: &EventLog) {
+        let header = log.header().unwrap();
+        assert_eq!(header.multiple_event_time_window(), 60);
+        assert_eq!(header.multiple_event_count_increment(), 2);
+        assert_eq!(header.header_revision(), 1);
+
+        let records: Vec<EventLogRecord<'_>> = log.records().collect();
+        assert_eq!(records.len(), 3);
+
+        assert_eq!(*records[0].log_type(), LogType::PostError);
+        assert!(!records[0].read());
+        assert_eq!(
+            records[0].timestamp().unwrap().to_string(),
+            "2021-09-15 10:20:30"
+        );
+        match records[0].data() {
+            EventLogData::PostResults { first, second } => {
+                assert!(first.contains(PostResultsBitmap::CMOS_BATTERY_FAILURE));
+                assert_eq!(first.iter().count(), 1);
+                assert!(!first.contains(PostResultsBitmap::SECOND_DWORD_VALID));
+                assert_eq!(second, 0);
+            }
+            data => panic!("unexpected {:?}", data),
+        }
+
+        assert_eq!(*records[1].log_type(), LogType::SingleBitEccMemoryError);
+        assert!(records[1].read());
+        assert_eq!(records[1].length(), 10);
+        assert_eq!(records[1].timestamp().unwrap().year, 1999);
+        assert_eq!(
+            records[1].data(),
+            EventLogData::Handle {
+                handle: Handle(0x1100)
+            }
+        );
+
+        assert_eq!(*records[2].log_type(), LogType::SystemBoot);
+        assert_eq!(records[2].data(), EventLogData::Unformatted(&[]));
+    }
+
+    #[test]
+    fn test_from_area() {
+        let data = table(0x03, 0x000F_0000);
+        let system_event_log = data.first::<SMBiosSystemEventLog<'_>>().unwrap();
+        check_log(&EventLog::from_area(&system_event_log, log_area()));
+    }
+
+    struct Memory(Vec<u8>);
+
+    impl EventLogAccess for Memory {
+        fn read_memory(&mut self, address: u32, buffer: &mut [u8]) -> Result<(), Error> {
+            assert_eq!(address, 0x000F_0000);
+            buffer.copy_from_slice(&self.0[..buffer.len()]);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_memory_mapped() {
+        let data = table(0x03, 0x000F_0000);
+        let system_event_log = data.first::<SMBiosSystemEventLog<'_>>().unwrap();
+        let log = EventLog::read(&system_event_log, &mut Memory(log_area())).unwrap();
+        check_log(&log);
+
+        // GPNV is not implemented by Memory
+        let data = table(0x04, 0x0001);
+        let system_event_log = data.first::<SMBiosSystemEventLog<'_>>().unwrap();
+        assert!(EventLog::read(&system_event_log, &mut Memory(log_area())).is_err());
+    }
+
+    struct Ports {
+        area: Vec<u8>,
+        ports: HashMap<u16, u8>,
+    }
+
+    impl EventLogAccess for Ports {
+        fn read_io_byte(&mut self, port: u16) -> Result<u8, Error> {
+            assert_eq!(port, 0x0C02);
+            let offset = self.ports[&0x0C00] as usize | (self.ports[&0x0C01] as usize) << 8;
+            Ok(self.area[offset])
+        }
+
+        fn write_io_byte(&mut self, port: u16, value: u8) -> Result<(), Error> {
+            self.ports.insert(port, value);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_indexed_io() {
+        // Two 8-bit index ports at 0C00h and 0C01h, data port at 0C02h
+        let data = table(0x01, 0x0C02_0C00);
+        let system_event_log = data.first::<SMBiosSystemEventLog<'_>>().unwrap();
+        let mut ports = Ports {
+            area: log_area(),
+            ports: HashMap::new(),
+        };
+        let log = EventLog::read(&system_event_log, &mut ports).unwrap();
+        check_log(&log);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(
+            EventLogTimestamp::from_bcd([0x79, 0x01, 0x02, 0x03, 0x04, 0x05]),
+            Some(EventLogTimestamp {
+                year: 2079,
+                month: 1,
+                day: 2,
+                hour: 3,
+                minute: 4,
+                second: 5
+            })
+        );
+        assert_eq!(
+            EventLogTimestamp::from_bcd([0x80, 0x01, 0x01, 0x00, 0x00, 0x00])
+                .unwrap()
+                .year,
+            1980
+        );
+        assert!(EventLogTimestamp::from_bcd([0x1A, 0x01, 0x01, 0x00, 0x00, 0x00]).is_none());
+    }
+
+    #[test]
+    fn test_invalid_record_length() {
+        let data = table(0x03, 0x000F_0000);
+        let system_event_log = data.first::<SMBiosSystemEventLog<'_>>().unwrap();
+        let mut area = log_area();
+        // Second record claims to extend beyond the log area
+        area[0x21] = 0x7F;
+        let log = EventLog::from_area(&system_event_log, area);
+        assert_eq!(log.records().count(), 1);
+    }
+}
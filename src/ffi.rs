@@ -0,0 +1,657 @@
+//! C ABI for consuming the library from C and C++
+//!
+//! A table is an opaque [SMBiosData] created by one of the `smbios_table_*`
+//! constructors and released with [smbios_table_free].  Structures are
+//! addressed by their position in the table; use [smbios_table_find] or
+//! [smbios_table_find_handle] to locate them.
+//!
+//! Strings returned by this module are allocated by the library and must be
+//! released with [smbios_string_free].  Functions return NULL or `false` when
+//! a pointer argument is NULL or the requested value is absent.
+//!
+//! The C declarations are in `include/smbioslib.h`, generated with
+//! `cbindgen --config cbindgen.toml --output include/smbioslib.h`.  Build a
+//! library to link against with
+//! `cargo rustc --release --lib --features ffi --crate-type staticlib` (or
+//! `cdylib`).
+
+use crate::core::{SMBiosData, SMBiosString, UndefinedStruct};
+#[cfg(feature = "memory")]
+use crate::structs::SMBiosMemoryDevice;
+#[cfg(feature = "processor")]
+use crate::structs::SMBiosProcessorInformation;
+use crate::structs::{SMBiosInformation, SMBiosStruct, SMBiosSystemInformation};
+#[cfg(feature = "os-loader")]
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+#[cfg(feature = "os-loader")]
+use std::path::Path;
+use std::ptr;
+
+/// Loads the table of the running system
+///
+/// Returns NULL if the table cannot be read.  Release the table with
+/// [smbios_table_free].  Requires the `os-loader` feature.
+#[cfg(all(
+    any(
+        target_family = "windows",
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "macos",
+        target_os = "ios"
+    ),
+    feature = "os-loader"
+))]
+#[no_mangle]
+pub extern "C" fn smbios_table_load_from_device() -> *mut SMBiosData {
+    crate::table_load_from_device().map_or(ptr::null_mut(), into_table)
+}
+
+/// Loads a table from a file
+///
+/// Accepts the formats read by
+/// [load_smbios_data_from_file](crate::load_smbios_data_from_file).  Returns
+/// NULL if the file cannot be read.  Release the table with
+/// [smbios_table_free].  Requires the `os-loader` feature.
+///
+/// # Safety
+///
+/// `path` must be NULL or a NUL-terminated UTF-8 string.
+#[cfg(feature = "os-loader")]
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_load_from_file(path: *const c_char) -> *mut SMBiosData {
+    if path.is_null() {
+        return ptr::null_mut();
+    }
+    match CStr::from_ptr(path).to_str() {
+        Ok(path) => {
+            crate::load_smbios_data_from_file(Path::new(path)).map_or(ptr::null_mut(), into_table)
+        }
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Parses raw table data (structures only, without an entry point) of an
+/// unknown SMBIOS version
+///
+/// Release the table with [smbios_table_free].
+///
+/// # Safety
+///
+/// `data` must be NULL or point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_from_bytes(
+    data: *const u8,
+    length: usize,
+) -> *mut SMBiosData {
+    if data.is_null() {
+        return ptr::null_mut();
+    }
+    let data = std::slice::from_raw_parts(data, length).to_vec();
+    into_table(SMBiosData::from_vec_and_version(data, None))
+}
+
+/// Releases a table
+///
+/// # Safety
+///
+/// `table` must be NULL or a table returned by this library which has not
+/// already been released.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_free(table: *mut SMBiosData) {
+    if !table.is_null() {
+        drop(Box::from_raw(table));
+    }
+}
+
+/// Gets the SMBIOS version of a table
+///
+/// Returns `false` if the version is unknown.
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  Each output pointer must be NULL or
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_version(
+    table: *const SMBiosData,
+    major: *mut u8,
+    minor: *mut u8,
+    revision: *mut u8,
+) -> bool {
+    match table.as_ref().and_then(|table| table.version) {
+        Some(version) => {
+            write_out(major, version.major);
+            write_out(minor, version.minor);
+            write_out(revision, version.revision);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Number of structures in a table
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_count(table: *const SMBiosData) -> usize {
+    table.as_ref().map_or(0, |table| table.iter().len())
+}
+
+/// Finds the position of the `instance`th (from 0) structure of a type
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  `position` must be NULL or writable.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_find(
+    table: *const SMBiosData,
+    struct_type: u8,
+    instance: usize,
+    position: *mut usize,
+) -> bool {
+    match table.as_ref().and_then(|table| {
+        table
+            .iter()
+            .enumerate()
+            .filter(|(_, undefined_struct)| undefined_struct.header.struct_type() == struct_type)
+            .nth(instance)
+    }) {
+        Some((found, _)) => {
+            write_out(position, found);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Finds the position of the structure with a handle
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  `position` must be NULL or writable.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_find_handle(
+    table: *const SMBiosData,
+    handle: u16,
+    position: *mut usize,
+) -> bool {
+    match table.as_ref().and_then(|table| {
+        table
+            .iter()
+            .position(|undefined_struct| undefined_struct.header.handle() == handle)
+    }) {
+        Some(found) => {
+            write_out(position, found);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Serializes a table to JSON
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_table_to_json(table: *const SMBiosData) -> *mut c_char {
+    table
+        .as_ref()
+        .and_then(|table| table.to_json().ok())
+        .map_or(ptr::null_mut(), into_c_string)
+}
+
+/// Gets the type of the structure at a position
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  `struct_type` must be NULL or
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_struct_type(
+    table: *const SMBiosData,
+    position: usize,
+    struct_type: *mut u8,
+) -> bool {
+    match struct_at(table, position) {
+        Some(undefined_struct) => {
+            write_out(struct_type, undefined_struct.header.struct_type());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Gets the handle of the structure at a position
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  `handle` must be NULL or writable.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_struct_handle(
+    table: *const SMBiosData,
+    position: usize,
+    handle: *mut u16,
+) -> bool {
+    match struct_at(table, position) {
+        Some(undefined_struct) => {
+            write_out(handle, *undefined_struct.header.handle());
+            true
+        }
+        None => false,
+    }
+}
+
+macro_rules! field_getter {
+    ($(#[$doc:meta])* $name:ident, $method:ident, $value:ty) => {
+        $(#[$doc])*
+        ///
+        /// Returns `false` if the structure or the field is absent.
+        ///
+        /// # Safety
+        ///
+        /// `table` must be NULL or a valid table.  `value` must be NULL or
+        /// writable.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            table: *const SMBiosData,
+            position: usize,
+            offset: usize,
+            value: *mut $value,
+        ) -> bool {
+            match struct_at(table, position).and_then(|undefined_struct| undefined_struct.$method(offset)) {
+                Some(field) => {
+                    write_out(value, field);
+                    true
+                }
+                None => false,
+            }
+        }
+    };
+}
+
+field_getter!(
+    /// Reads a BYTE field of the structure at a position
+    smbios_struct_field_byte,
+    get_field_byte,
+    u8
+);
+field_getter!(
+    /// Reads a WORD field of the structure at a position
+    smbios_struct_field_word,
+    get_field_word,
+    u16
+);
+field_getter!(
+    /// Reads a DWORD field of the structure at a position
+    smbios_struct_field_dword,
+    get_field_dword,
+    u32
+);
+field_getter!(
+    /// Reads a QWORD field of the structure at a position
+    smbios_struct_field_qword,
+    get_field_qword,
+    u64
+);
+
+/// Reads the string referenced by a string number field of the structure at
+/// a position
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_struct_field_string(
+    table: *const SMBiosData,
+    position: usize,
+    offset: usize,
+) -> *mut c_char {
+    struct_at(table, position).map_or(ptr::null_mut(), |undefined_struct| {
+        smbios_string(undefined_struct.get_field_string(offset))
+    })
+}
+
+/// Serializes the decoded fields of the structure at a position to JSON
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_struct_to_json(
+    table: *const SMBiosData,
+    position: usize,
+) -> *mut c_char {
+    struct_at(table, position)
+        .and_then(|undefined_struct| serde_json::to_string(&undefined_struct.defined_struct()).ok())
+        .map_or(ptr::null_mut(), into_c_string)
+}
+
+/// BIOS Vendor (Type 0)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_bios_vendor(table: *const SMBiosData) -> *mut c_char {
+    first_string(table, |bios: SMBiosInformation<'_>| bios.vendor())
+}
+
+/// BIOS Version (Type 0)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_bios_version(table: *const SMBiosData) -> *mut c_char {
+    first_string(table, |bios: SMBiosInformation<'_>| bios.version())
+}
+
+/// System Manufacturer (Type 1)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_system_manufacturer(table: *const SMBiosData) -> *mut c_char {
+    first_string(table, |system: SMBiosSystemInformation<'_>| {
+        system.manufacturer()
+    })
+}
+
+/// System Product Name (Type 1)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_system_product_name(table: *const SMBiosData) -> *mut c_char {
+    first_string(table, |system: SMBiosSystemInformation<'_>| {
+        system.product_name()
+    })
+}
+
+/// System Serial Number (Type 1)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_system_serial_number(table: *const SMBiosData) -> *mut c_char {
+    first_string(table, |system: SMBiosSystemInformation<'_>| {
+        system.serial_number()
+    })
+}
+
+/// System UUID (Type 1), formatted as by [SystemUuidData](crate::SystemUuidData)
+///
+/// Release the string with [smbios_string_free].
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_system_uuid(table: *const SMBiosData) -> *mut c_char {
+    table
+        .as_ref()
+        .and_then(|table| {
+            table.find_map(|system: SMBiosSystemInformation<'_>| {
+                system.uuid_for_version(table.version)
+            })
+        })
+        .map_or(ptr::null_mut(), |uuid| into_c_string(uuid.to_string()))
+}
+
+/// Processor Version of the `instance`th (from 0) Processor Information
+/// structure (Type 4)
+///
+/// Release the string with [smbios_string_free].  Requires the `processor`
+/// feature.
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.
+#[cfg(feature = "processor")]
+#[no_mangle]
+pub unsafe extern "C" fn smbios_processor_version(
+    table: *const SMBiosData,
+    instance: usize,
+) -> *mut c_char {
+    table
+        .as_ref()
+        .and_then(|table| {
+            table
+                .defined_struct_iter::<SMBiosProcessorInformation<'_>>()
+                .nth(instance)
+        })
+        .map_or(ptr::null_mut(), |processor| {
+            smbios_string(processor.processor_version())
+        })
+}
+
+/// Size in kilobytes of the `instance`th (from 0) Memory Device structure
+/// (Type 17)
+///
+/// An empty socket has a size of 0.  Returns `false` if the size is unknown.
+/// Requires the `memory` feature.
+///
+/// # Safety
+///
+/// `table` must be NULL or a valid table.  `kilobytes` must be NULL or
+/// writable.
+#[cfg(feature = "memory")]
+#[no_mangle]
+pub unsafe extern "C" fn smbios_memory_device_size_kb(
+    table: *const SMBiosData,
+    instance: usize,
+    kilobytes: *mut u64,
+) -> bool {
+    let memory_device = match table.as_ref().and_then(|table| {
+        table
+            .defined_struct_iter::<SMBiosMemoryDevice<'_>>()
+            .nth(instance)
+    }) {
+        Some(memory_device) => memory_device,
+        None => return false,
+    };
+
+    match memory_device.size_bytes().and_then(|size| size.bytes()) {
+        Some(size) => {
+            write_out(kilobytes, size / 1024);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Releases a string returned by this library
+///
+/// # Safety
+///
+/// `string` must be NULL or a string returned by this library which has not
+/// already been released.
+#[no_mangle]
+pub unsafe extern "C" fn smbios_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+fn into_table(data: SMBiosData) -> *mut SMBiosData {
+    Box::into_raw(Box::new(data))
+}
+
+fn into_c_string(string: String) -> *mut c_char {
+    CString::new(string).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+fn smbios_string(string: SMBiosString) -> *mut c_char {
+    string
+        .to_utf8_lossy()
+        .map_or(ptr::null_mut(), into_c_string)
+}
+
+unsafe fn struct_at<'a>(table: *const SMBiosData, position: usize) -> Option<&'a UndefinedStruct> {
+    table.as_ref().and_then(|table| table.iter().nth(position))
+}
+
+unsafe fn first_string<'a, T, F>(table: *const SMBiosData, f: F) -> *mut c_char
+where
+    T: SMBiosStruct<'a>,
+    F: FnOnce(T) -> SMBiosString,
+{
+    table
+        .as_ref()
+        .and_then(|table| table.first::<T>())
+        .map_or(ptr::null_mut(), |structure| smbios_string(f(structure)))
+}
+
+unsafe fn write_out<T>(destination: *mut T, value: T) {
+    if !destination.is_null() {
+        *destination = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
+    use crate::structs::SystemInformationBuilder;
+    use std::ffi::CStr;
+
+    fn table() -> *mut SMBiosData {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::with_length(0, 0x12)
+                .string(0x04, "Vendor")
+                .string(0x05, "1.2.3"),
+        );
+        builder.add(
+            SystemInformationBuilder::new()
+                .manufacturer("Maker")
+                .product_name("Model")
+                .serial_number("SN0001")
+                .uuid([0x11; 0x10]),
+        );
+        // Memory devices of 8 GB and of 64 GB (in the Extended Size field)
+        builder.add(StructBuilder::with_length(17, 0x28).word(0x0C, 8192));
+        builder.add(
+            StructBuilder::with_length(17, 0x28)
+                .word(0x0C, 0x7FFF)
+                .dword(0x1C, 65536),
+        );
+        let raw = builder.build();
+        unsafe { smbios_table_from_bytes(raw.as_ptr(), raw.len()) }
+    }
+
+    unsafe fn take_string(string: *mut c_char) -> Option<String> {
+        if string.is_null() {
+            return None;
+        }
+        let value = CStr::from_ptr(string).to_str().unwrap().to_string();
+        smbios_string_free(string);
+        Some(value)
+    }
+
+    #[test]
+    fn test_ffi() {
+        unsafe {
+            let table = table();
+            assert!(!table.is_null());
+            assert_eq!(smbios_table_count(table), 5);
+            assert!(!smbios_table_version(
+                table,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut()
+            ));
+
+            assert_eq!(take_string(smbios_bios_vendor(table)).unwrap(), "Vendor");
+            assert_eq!(take_string(smbios_bios_version(table)).unwrap(), "1.2.3");
+            assert_eq!(
+                take_string(smbios_system_manufacturer(table)).unwrap(),
+                "Maker"
+            );
+            assert_eq!(
+                take_string(smbios_system_product_name(table)).unwrap(),
+                "Model"
+            );
+            assert_eq!(
+                take_string(smbios_system_serial_number(table)).unwrap(),
+                "SN0001"
+            );
+            assert_eq!(
+                take_string(smbios_system_uuid(table)).unwrap(),
+                "11111111-1111-1111-1111-111111111111"
+            );
+            #[cfg(feature = "processor")]
+            assert!(take_string(smbios_processor_version(table, 0)).is_none());
+
+            #[cfg(feature = "memory")]
+            {
+                let mut kilobytes = 0u64;
+                assert!(smbios_memory_device_size_kb(table, 0, &mut kilobytes));
+                assert_eq!(kilobytes, 8192 * 1024);
+                assert!(smbios_memory_device_size_kb(table, 1, &mut kilobytes));
+                assert_eq!(kilobytes, 65536 * 1024);
+                assert!(!smbios_memory_device_size_kb(table, 2, &mut kilobytes));
+            }
+
+            let mut position = 0usize;
+            assert!(smbios_table_find(table, 17, 1, &mut position));
+            assert_eq!(position, 3);
+            assert!(!smbios_table_find(table, 4, 0, &mut position));
+            assert!(smbios_table_find_handle(table, 1, &mut position));
+            assert_eq!(position, 1);
+
+            let mut struct_type = 0u8;
+            let mut handle = 0u16;
+            assert!(smbios_struct_type(table, 4, &mut struct_type));
+            assert!(smbios_struct_handle(table, 4, &mut handle));
+            assert_eq!((struct_type, handle), (127, 4));
+            assert!(!smbios_struct_type(table, 5, &mut struct_type));
+
+            let mut word = 0u16;
+            assert!(smbios_struct_field_word(table, 2, 0x0C, &mut word));
+            assert_eq!(word, 8192);
+            assert!(!smbios_struct_field_word(table, 0, 0x12, &mut word));
+            assert_eq!(
+                take_string(smbios_struct_field_string(table, 1, 0x04)).unwrap(),
+                "Maker"
+            );
+
+            let json = take_string(smbios_struct_to_json(table, 1)).unwrap();
+            assert!(json.starts_with("{\"SystemInformation\":"));
+            assert!(take_string(smbios_table_to_json(table)).is_some());
+
+            smbios_table_free(table);
+
+            assert_eq!(smbios_table_count(ptr::null()), 0);
+            assert!(smbios_bios_vendor(ptr::null()).is_null());
+            #[cfg(feature = "os-loader")]
+            assert!(smbios_table_load_from_file(ptr::null()).is_null());
+            smbios_table_free(ptr::null_mut());
+        }
+    }
+}
@@ -2,9 +2,9 @@
 //!
 //! When testing this library it is useful to read stored
 //! raw data and then load it into the structures.
-use crate::core::SMBiosData;
+use crate::core::{SMBiosData, SMBiosEntryPoint32, SMBiosEntryPoint64};
 use crate::windows::WinSMBiosData;
-use std::io::{BufWriter, Error, Write};
+use std::io::{BufWriter, Error, ErrorKind, Write};
 use std::{
     fs::{read, read_dir, File},
     path::Path,
@@ -25,6 +25,32 @@ pub fn load_smbios_data_from_file(file_path: &Path) -> Result<SMBiosData, Error>
     }
 }
 
+/// Loads [SMBiosData] from an entry point file and a table file, returning [std::io::Error] on error.
+///
+/// The files are expected in the layout Linux exposes under /sys/firmware/dmi/tables:
+/// `entry_point_path` contains a 64-bit (_SM3_) or 32-bit (_SM_) entry point structure
+/// and `table_path` contains the raw SMBIOS structure table.  This allows copies of those
+/// files taken from another machine to be loaded along with their SMBIOS version.
+pub fn load_smbios_data_from_entry_point_and_table_files(
+    entry_point_path: &Path,
+    table_path: &Path,
+) -> Result<SMBiosData, Error> {
+    let version = match SMBiosEntryPoint64::try_load_from_file(entry_point_path) {
+        Ok(entry_point) => entry_point.version(),
+        Err(err) => match err.kind() {
+            ErrorKind::InvalidData => {
+                SMBiosEntryPoint32::try_load_from_file(entry_point_path)?.version()
+            }
+            _ => return Err(err),
+        },
+    };
+
+    Ok(SMBiosData::from_vec_and_version(
+        read(table_path)?,
+        Some(version),
+    ))
+}
+
 /// Loads raw smbios data files from a given _folder_ and returns [Vec<SMBiosStructTable>]
 pub fn load_raw_files(folder: &Path) -> Vec<SMBiosData> {
     assert!(folder.is_dir());
@@ -58,6 +84,7 @@ pub fn dump_raw(data: Vec<u8>, out_path: &Path) -> Result<(), Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::SMBiosVersion;
     use std::path::PathBuf;
 
     #[test]
@@ -77,4 +104,48 @@ mod tests {
             _ => panic!("Expected data!"),
         }
     }
+
+    #[test]
+    fn test_table_round_trip() {
+        let path = Path::new("./tests/jeffgerlap_3_2_0.dat");
+        let file_data = read(path).unwrap();
+        let table_data = &file_data[WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET..];
+        let data = load_smbios_data_from_file(path).unwrap();
+
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.as_slice(), &table_data[..bytes.len()]);
+        assert!(table_data[bytes.len()..].iter().all(|byte| *byte == 0));
+
+        let reloaded = SMBiosData::from_vec_and_version(bytes.clone(), None);
+        assert_eq!(reloaded.iter().count(), data.iter().count());
+        assert_eq!(reloaded.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_load_entry_point_and_table_files() {
+        // _SM3_ entry point for version 3.2.0
+        let entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x00, 0x18, 0x03, 0x02, 0x00, 0x01, 0x00, 0x00, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let checksum = entry_point
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        let mut entry_point = entry_point;
+        entry_point[0x05] = 0u8.wrapping_sub(checksum);
+
+        let entry_point_path = std::env::temp_dir().join("smbioslib_test_smbios_entry_point");
+        dump_raw(entry_point, &entry_point_path).unwrap();
+
+        // End-of-Table (Type 127)
+        let table = vec![0x7F, 0x04, 0x00, 0x00, 0x00, 0x00];
+        let table_path = std::env::temp_dir().join("smbioslib_test_dmi");
+        dump_raw(table, &table_path).unwrap();
+
+        let data =
+            load_smbios_data_from_entry_point_and_table_files(&entry_point_path, &table_path)
+                .unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 2, 0)));
+        assert_eq!(data.iter().count(), 1);
+    }
 }
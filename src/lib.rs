@@ -4,23 +4,39 @@
 //!
 //! This library focuses on the tasks involved with reading and interpreting
 //! BIOS data.
+//!
+//! Loading the table from the operating system or from files requires the
+//! default `os-loader` feature.  Without it the library parses caller-supplied
+//! data only and builds for targets such as `wasm32-unknown-unknown`.
 
 #![warn(missing_docs)]
 #![deny(rust_2018_idioms)]
 
 mod core;
+mod dmidecode;
+#[cfg(feature = "event-log")]
+mod event_log;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "os-loader")]
 mod file_io;
 mod macos;
 mod structs;
+#[cfg(feature = "uefi")]
+mod uefi;
 mod unix;
 mod windows;
 
 pub use structs::*;
 
 pub use crate::core::*;
+pub use dmidecode::*;
+#[cfg(feature = "event-log")]
+pub use event_log::*;
+#[cfg(feature = "os-loader")]
 pub use file_io::*;
 
-#[cfg(target_family = "windows")]
+#[cfg(all(target_family = "windows", feature = "os-loader"))]
 pub use windows::{load_windows_smbios_data, raw_smbios_from_device, table_load_from_device};
 
 pub use windows::WinSMBiosData;
@@ -30,3 +46,6 @@ pub use unix::*;
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 pub use macos::*;
+
+#[cfg(feature = "uefi")]
+pub use uefi::*;
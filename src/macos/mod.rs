@@ -1,7 +1,7 @@
-#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[cfg(all(any(target_os = "macos", target_os = "ios"), feature = "os-loader"))]
 mod platform;
 
-#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[cfg(all(any(target_os = "macos", target_os = "ios"), feature = "os-loader"))]
 pub use platform::*;
 
 pub use std::convert::TryInto;
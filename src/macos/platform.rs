@@ -48,7 +48,7 @@ impl Drop for AppleSMBiosService {
     }
 }
 
-fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint32, Error> {
+fn try_load_macos_entry_point() -> Result<Vec<u8>, Error> {
     let service = AppleSMBiosService::try_init()?;
 
     unsafe {
@@ -76,7 +76,7 @@ fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint32, Error> {
 
         CFRelease(data_ref.as_void_ptr());
 
-        SMBiosEntryPoint32::try_from(entry_point)
+        Ok(entry_point)
     }
 }
 
@@ -113,10 +113,24 @@ fn try_load_macos_table() -> Result<Vec<u8>, Error> {
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
     let entry_point = try_load_macos_entry_point()?;
 
-    let version = SMBiosVersion {
-        major: entry_point.major_version(),
-        minor: entry_point.minor_version(),
-        revision: 0,
+    // The SMBIOS-EPS property holds either a 64-bit (_SM3_) or a 32-bit (_SM_) entry point.
+    let version = match SMBiosEntryPoint64::try_from(entry_point.clone()) {
+        Ok(entry_point) => SMBiosVersion {
+            major: entry_point.major_version(),
+            minor: entry_point.minor_version(),
+            revision: entry_point.docrev(),
+        },
+        Err(err) => match err.kind() {
+            ErrorKind::InvalidData => {
+                let entry_point = SMBiosEntryPoint32::try_from(entry_point)?;
+                SMBiosVersion {
+                    major: entry_point.major_version(),
+                    minor: entry_point.minor_version(),
+                    revision: 0,
+                }
+            }
+            _ => return Err(err),
+        },
     };
 
     let table = try_load_macos_table()?;
@@ -31,6 +31,7 @@ enum BiosParseError {
     ProcessorVersionNotFound,
     ProcessorFrequencyNotFound,
     InvalidKeywordOnCommandLine,
+    InvalidTypeOnCommandLine,
 }
 
 impl Error for BiosParseError {
@@ -99,7 +100,9 @@ fn string_keyword(keyword: String, data: &SMBiosData) -> Result<String, BiosPars
             })
             .ok_or(BiosParseError::SystemSerialNumberNotFound),
         "system-uuid" => {
-            match data.find_map(|system_info: SMBiosSystemInformation| system_info.uuid()) {
+            match data.find_map(|system_info: SMBiosSystemInformation| {
+                system_info.uuid_for_version(data.version)
+            }) {
                 // SystemUuidData is an enum that can be broken down further if desired
                 Some(uuid) => Ok(format!("{}", uuid)),
                 None => Err(BiosParseError::SystemUuidNotFound),
@@ -199,11 +202,40 @@ fn string_keyword(keyword: String, data: &SMBiosData) -> Result<String, BiosPars
     }
 }
 
+/// Parses the structure types of a `--type` argument
+///
+/// Accepts comma separated type numbers and the keywords dmidecode uses for
+/// groups of types.
+fn type_keyword(keyword: &str) -> Result<Vec<u8>, BiosParseError> {
+    let mut types = Vec::new();
+    for item in keyword.split(',').map(|item| item.trim()) {
+        match item.to_lowercase().as_str() {
+            "bios" => types.extend_from_slice(&[0, 13]),
+            "system" => types.extend_from_slice(&[1, 12, 15, 23, 32]),
+            "baseboard" => types.extend_from_slice(&[2, 10, 41]),
+            "chassis" => types.push(3),
+            "processor" => types.push(4),
+            "memory" => types.extend_from_slice(&[5, 6, 16, 17]),
+            "cache" => types.push(7),
+            "connector" => types.push(8),
+            "slot" => types.push(9),
+            number => types.push(
+                number
+                    .parse::<u8>()
+                    .map_err(|_| BiosParseError::InvalidTypeOnCommandLine)?,
+            ),
+        }
+    }
+    Ok(types)
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let file_option = "f";
     let output_option = "o";
     let string_option = "s";
     let json_option = "j";
+    let type_option = "t";
+    let dump_bin_option = "dump-bin";
 
     let args: Vec<String> = std::env::args().collect();
     let mut opts = getopts::Options::new();
@@ -215,7 +247,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Only display the value of the DMI string identified by KEYWORD.",
         "KEYWORD",
     );
-    opts.optflag(json_option, "", "output in json format");
+    opts.optflag(json_option, "json", "output in json format");
+    opts.optmulti(
+        type_option,
+        "type",
+        "Only display the entries of type TYPE (a number or keyword such as memory).",
+        "TYPE",
+    );
+    opts.optopt(
+        "",
+        dump_bin_option,
+        "dump the entry point and smbios table to a file in dmidecode --dump-bin format",
+        "FILE",
+    );
 
     let matches = opts.parse(&args[1..])?;
 
@@ -223,17 +267,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         && !matches.opt_present(output_option)
         && !matches.opt_present(string_option)
         && !matches.opt_present(json_option)
+        && !matches.opt_present(type_option)
+        && !matches.opt_present(dump_bin_option)
     {
         println!("table_data: {:#?}", table_load_from_device()?);
         return Ok(());
     }
 
+    let decode = matches.opt_present(json_option) || matches.opt_present(type_option);
+
+    // -f alone prints the file's table, otherwise the options below use it
     match matches.opt_str(file_option) {
-        Some(filename) => {
+        Some(filename) if !decode && !matches.opt_present(dump_bin_option) => {
             let file_path = Path::new(&filename);
             println!("{:#?}", load_smbios_data_from_file(&file_path)?);
         }
-        None => (),
+        _ => (),
     }
 
     match matches.opt_str(output_option) {
@@ -252,10 +301,49 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         None => (),
     }
-    if matches.opt_present(json_option) {
-        let smbios_data = table_load_from_device()?;
-        if let Ok(output) = serde_json::to_string(&smbios_data) {
-            println!("{}", output)
+
+    // --dump-bin, --type and --json use the file given with -f, otherwise the device table
+    let load_smbios_data = || match matches.opt_str(file_option) {
+        Some(filename) => load_smbios_data_from_file(Path::new(&filename)),
+        None => table_load_from_device(),
+    };
+
+    if let Some(filename) = matches.opt_str(dump_bin_option) {
+        let out_path = Path::new(&filename);
+        dump_raw(dmidecode_dump_bin(&load_smbios_data()?), out_path)?;
+    }
+
+    if decode {
+        let mut types = Vec::new();
+        for keyword in matches.opt_strs(type_option) {
+            types.extend(type_keyword(&keyword)?);
+        }
+
+        let smbios_data = load_smbios_data()?;
+
+        if types.is_empty() {
+            if matches.opt_present(json_option) {
+                println!("{}", smbios_data.to_json()?);
+            } else {
+                println!("{:#?}", smbios_data);
+            }
+        } else {
+            let selected = smbios_data
+                .iter()
+                .filter(|undefined_struct| types.contains(&undefined_struct.header.struct_type()));
+
+            if matches.opt_present(json_option) {
+                let raw: Vec<u8> = selected.flat_map(UndefinedStruct::to_bytes).collect();
+                let selected_data = SMBiosData::from_vec_and_version(raw, smbios_data.version);
+                println!("{}", selected_data.to_json()?);
+            } else {
+                let mut output = String::new();
+                for undefined_struct in selected {
+                    dmidecode_decode_struct(undefined_struct, &mut output);
+                    output.push('\n');
+                }
+                print!("{}", output);
+            }
         }
     }
 
@@ -19,94 +19,139 @@ pub enum DefinedStruct<'a> {
     /// System Information (Type 1)
     SystemInformation(SMBiosSystemInformation<'a>),
     /// Baseboard (or Module) Information (Type 2)
+    #[cfg(feature = "platform")]
     BaseBoardInformation(SMBiosBaseboardInformation<'a>),
     /// System Enclosure or Chassis (Type 3)
+    #[cfg(feature = "platform")]
     SystemChassisInformation(SMBiosSystemChassisInformation<'a>),
     /// Processor Information (Type 4)
+    #[cfg(feature = "processor")]
     ProcessorInformation(SMBiosProcessorInformation<'a>),
     /// Memory Controller Information (Type 5, Obsolete)
+    #[cfg(feature = "memory")]
     MemoryControllerInformation(SMBiosMemoryControllerInformation<'a>),
     /// Memory Module Information (Type 6, Obsolete)
+    #[cfg(feature = "memory")]
     MemoryModuleInformation(SMBiosMemoryModuleInformation<'a>),
     /// Cache Informaiton (Type 7)
+    #[cfg(feature = "processor")]
     CacheInformation(SMBiosCacheInformation<'a>),
     /// Port Connector Information (Type 8)
+    #[cfg(feature = "platform")]
     PortConnectorInformation(SMBiosPortConnectorInformation<'a>),
     /// System Slot Information (Type 9)
+    #[cfg(feature = "platform")]
     SystemSlot(SMBiosSystemSlot<'a>),
     /// On Board Devices Information (Type 10, Obsolete)
+    #[cfg(feature = "platform")]
     OnBoardDeviceInformation(SMBiosOnBoardDeviceInformation<'a>),
     /// OEM Strings (Type 11)
+    #[cfg(feature = "oem")]
     OemStrings(SMBiosOemStrings<'a>),
     /// System Configuration Options (Type 12)
+    #[cfg(feature = "oem")]
     SystemConfigurationOptions(SMBiosSystemConfigurationOptions<'a>),
     /// BIOS Language Information (Type 13)
+    #[cfg(feature = "platform")]
     LanguageInformation(SMBiosBiosLanguageInformation<'a>),
     /// Group Associations (Type 14)
+    #[cfg(feature = "platform")]
     GroupAssociations(SMBiosGroupAssociations<'a>),
     /// System Event Log (Type 15)
+    #[cfg(feature = "platform")]
     EventLog(SMBiosSystemEventLog<'a>),
     /// Physical Memory Array (Type 16)
+    #[cfg(feature = "memory")]
     PhysicalMemoryArray(SMBiosPhysicalMemoryArray<'a>),
     /// Memory Device (Type 17)
+    #[cfg(feature = "memory")]
     MemoryDevice(SMBiosMemoryDevice<'a>),
     /// 32-Bit Memory Error Information (Type 18)
+    #[cfg(feature = "memory")]
     MemoryErrorInformation32Bit(SMBiosMemoryErrorInformation32<'a>),
     /// Memory Array Mapped Address (Type 19)
+    #[cfg(feature = "memory")]
     MemoryArrayMappedAddress(SMBiosMemoryArrayMappedAddress<'a>),
     /// Memory Device Mapped Address (Type 20)
+    #[cfg(feature = "memory")]
     MemoryDeviceMappedAddress(SMBiosMemoryDeviceMappedAddress<'a>),
     /// Built-in Pointing Device (Type 21)
+    #[cfg(feature = "platform")]
     BuiltInPointingDevice(SMBiosBuiltInPointingDevice<'a>),
     /// Portable Battery (Type 22)
+    #[cfg(feature = "platform")]
     PortableBattery(SMBiosPortableBattery<'a>),
     /// System Reset (Type 23)
+    #[cfg(feature = "platform")]
     SystemReset(SMBiosSystemReset<'a>),
     /// Hardware Security (Type 24)
+    #[cfg(feature = "platform")]
     HardwareSecurity(SMBiosHardwareSecurity<'a>),
     /// System Power Controls (Type 25)
+    #[cfg(feature = "platform")]
     SystemPowerControls(SMBiosSystemPowerControls<'a>),
     /// Voltage Probe (Type 26)
+    #[cfg(feature = "sensors")]
     VoltageProbe(SMBiosVoltageProbe<'a>),
     /// Cooling Device (Type 27)
+    #[cfg(feature = "sensors")]
     CoolingDevice(SMBiosCoolingDevice<'a>),
     /// Temperature Probe (Type 28)
+    #[cfg(feature = "sensors")]
     TemperatureProbe(SMBiosTemperatureProbe<'a>),
     /// Electrical Current Probe (Type 29)
+    #[cfg(feature = "sensors")]
     ElectricalCurrentProbe(SMBiosElectricalCurrentProbe<'a>),
     /// Out-of-Band Remote Access (Type 30)
+    #[cfg(feature = "platform")]
     OutOfBandRemoteAccess(SMBiosOutOfBandRemoteAccess<'a>),
     /// Boot Integrity Services (BIS) (Type 31)
+    #[cfg(feature = "platform")]
     BisEntryPoint(SMBiosBisEntryPoint<'a>),
     /// System Boot Information (Type 32)
+    #[cfg(feature = "platform")]
     SystemBootInformation(SMBiosSystemBootInformation<'a>),
     /// 64-Bit Memory Error Information (Type 33)
+    #[cfg(feature = "memory")]
     MemoryErrorInformation64Bit(SMBiosMemoryErrorInformation64<'a>),
     /// Management Device (Type 34)
+    #[cfg(feature = "sensors")]
     ManagementDevice(SMBiosManagementDevice<'a>),
     /// Management Device Component (Type 35)
+    #[cfg(feature = "sensors")]
     ManagementDeviceComponent(SMBiosManagementDeviceComponent<'a>),
     /// Management Device Threshold Data (Type 36)
+    #[cfg(feature = "sensors")]
     ManagementDeviceThresholdData(SMBiosManagementDeviceThresholdData<'a>),
     /// Memory Channel (Type 37)
+    #[cfg(feature = "memory")]
     MemoryChannel(SMBiosMemoryChannel<'a>),
     /// IPMI Device Information (Type 38)
+    #[cfg(feature = "platform")]
     IpmiDeviceInformation(SMBiosIpmiDeviceInformation<'a>),
     /// Power Supply (Type 39)
+    #[cfg(feature = "platform")]
     SystemPowerSupply(SMBiosSystemPowerSupply<'a>),
     /// Additional Information (Type 40)
+    #[cfg(feature = "oem")]
     AdditionalInformation(SMBiosAdditionalInformation<'a>),
     /// Onboard Devices Extended Information (Type 41)
+    #[cfg(feature = "platform")]
     OnboardDevicesExtendedInformation(SMBiosOnboardDevicesExtendedInformation<'a>),
     /// Management Controller Host Interface (Type 42)
+    #[cfg(feature = "platform")]
     ManagementControllerHostInterface(SMBiosManagementControllerHostInterface<'a>),
     /// TPM Device (Type 43)
+    #[cfg(feature = "platform")]
     TpmDevice(SMBiosTpmDevice<'a>),
     /// Processor Additional Information (Type 44)
+    #[cfg(feature = "processor")]
     ProcessorAdditionalInformation(SMBiosProcessorAdditionalInformation<'a>),
     /// Firmware Inventory Information (Type 45)
+    #[cfg(feature = "platform")]
     FirmwareInventoryInformation(SMBiosFirmwareInventoryInformation<'a>),
     /// String Property (Type 46)
+    #[cfg(feature = "platform")]
     StringProperty(SMBiosStringProperty<'a>),
     /// Inactive (Type 126)
     Inactive(SMBiosInactive<'a>),
@@ -117,9 +162,117 @@ pub enum DefinedStruct<'a> {
     /// - A structure with a type value not yet defined, such as by a DMTF specification
     /// that supercedes the types known by this library
     /// - An OEM type with a value > 127.
+    /// - A type whose category feature (e.g. `memory`) is not enabled.
     Undefined(SMBiosUnknown<'a>),
 }
 
+impl<'a> DefinedStruct<'a> {
+    /// The standard parts/sections of the contained structure
+    pub fn parts(&self) -> &'a UndefinedStruct {
+        match self {
+            DefinedStruct::Information(defined_struct) => defined_struct.parts(),
+            DefinedStruct::SystemInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::BaseBoardInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemChassisInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "processor")]
+            DefinedStruct::ProcessorInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryControllerInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryModuleInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "processor")]
+            DefinedStruct::CacheInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::PortConnectorInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemSlot(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::OnBoardDeviceInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "oem")]
+            DefinedStruct::OemStrings(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "oem")]
+            DefinedStruct::SystemConfigurationOptions(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::LanguageInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::GroupAssociations(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::EventLog(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::PhysicalMemoryArray(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryDevice(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryErrorInformation32Bit(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryArrayMappedAddress(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryDeviceMappedAddress(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::BuiltInPointingDevice(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::PortableBattery(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemReset(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::HardwareSecurity(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemPowerControls(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::VoltageProbe(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::CoolingDevice(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::TemperatureProbe(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::ElectricalCurrentProbe(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::OutOfBandRemoteAccess(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::BisEntryPoint(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemBootInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryErrorInformation64Bit(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::ManagementDevice(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::ManagementDeviceComponent(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "sensors")]
+            DefinedStruct::ManagementDeviceThresholdData(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "memory")]
+            DefinedStruct::MemoryChannel(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::IpmiDeviceInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::SystemPowerSupply(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "oem")]
+            DefinedStruct::AdditionalInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::OnboardDevicesExtendedInformation(defined_struct) => {
+                defined_struct.parts()
+            }
+            #[cfg(feature = "platform")]
+            DefinedStruct::ManagementControllerHostInterface(defined_struct) => {
+                defined_struct.parts()
+            }
+            #[cfg(feature = "platform")]
+            DefinedStruct::TpmDevice(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "processor")]
+            DefinedStruct::ProcessorAdditionalInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::FirmwareInventoryInformation(defined_struct) => defined_struct.parts(),
+            #[cfg(feature = "platform")]
+            DefinedStruct::StringProperty(defined_struct) => defined_struct.parts(),
+            DefinedStruct::Inactive(defined_struct) => defined_struct.parts(),
+            DefinedStruct::EndOfTable(defined_struct) => defined_struct.parts(),
+            DefinedStruct::Undefined(defined_struct) => defined_struct.parts(),
+        }
+    }
+}
+
 impl<'a> From<&'a UndefinedStruct> for DefinedStruct<'a> {
     fn from(undefined_struct: &'a UndefinedStruct) -> Self {
         match undefined_struct.header.struct_type() {
@@ -129,160 +282,205 @@ impl<'a> From<&'a UndefinedStruct> for DefinedStruct<'a> {
             SMBiosSystemInformation::STRUCT_TYPE => {
                 DefinedStruct::SystemInformation(SMBiosSystemInformation::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosBaseboardInformation::STRUCT_TYPE => DefinedStruct::BaseBoardInformation(
                 SMBiosBaseboardInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosSystemChassisInformation::STRUCT_TYPE => DefinedStruct::SystemChassisInformation(
                 SMBiosSystemChassisInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "processor")]
             SMBiosProcessorInformation::STRUCT_TYPE => DefinedStruct::ProcessorInformation(
                 SMBiosProcessorInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "memory")]
             SMBiosMemoryControllerInformation::STRUCT_TYPE => {
                 DefinedStruct::MemoryControllerInformation(SMBiosMemoryControllerInformation::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "memory")]
             SMBiosMemoryModuleInformation::STRUCT_TYPE => DefinedStruct::MemoryModuleInformation(
                 SMBiosMemoryModuleInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "processor")]
             SMBiosCacheInformation::STRUCT_TYPE => {
                 DefinedStruct::CacheInformation(SMBiosCacheInformation::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosPortConnectorInformation::STRUCT_TYPE => DefinedStruct::PortConnectorInformation(
                 SMBiosPortConnectorInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosSystemSlot::STRUCT_TYPE => {
                 DefinedStruct::SystemSlot(SMBiosSystemSlot::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosOnBoardDeviceInformation::STRUCT_TYPE => DefinedStruct::OnBoardDeviceInformation(
                 SMBiosOnBoardDeviceInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "oem")]
             SMBiosOemStrings::STRUCT_TYPE => {
                 DefinedStruct::OemStrings(SMBiosOemStrings::new(undefined_struct))
             }
+            #[cfg(feature = "oem")]
             SMBiosSystemConfigurationOptions::STRUCT_TYPE => {
                 DefinedStruct::SystemConfigurationOptions(SMBiosSystemConfigurationOptions::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "platform")]
             SMBiosBiosLanguageInformation::STRUCT_TYPE => DefinedStruct::LanguageInformation(
                 SMBiosBiosLanguageInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosGroupAssociations::STRUCT_TYPE => {
                 DefinedStruct::GroupAssociations(SMBiosGroupAssociations::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosSystemEventLog::STRUCT_TYPE => {
                 DefinedStruct::EventLog(SMBiosSystemEventLog::new(undefined_struct))
             }
+            #[cfg(feature = "memory")]
             SMBiosPhysicalMemoryArray::STRUCT_TYPE => {
                 DefinedStruct::PhysicalMemoryArray(SMBiosPhysicalMemoryArray::new(undefined_struct))
             }
+            #[cfg(feature = "memory")]
             SMBiosMemoryDevice::STRUCT_TYPE => {
                 DefinedStruct::MemoryDevice(SMBiosMemoryDevice::new(undefined_struct))
             }
+            #[cfg(feature = "memory")]
             SMBiosMemoryErrorInformation32::STRUCT_TYPE => {
                 DefinedStruct::MemoryErrorInformation32Bit(SMBiosMemoryErrorInformation32::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "memory")]
             SMBiosMemoryArrayMappedAddress::STRUCT_TYPE => DefinedStruct::MemoryArrayMappedAddress(
                 SMBiosMemoryArrayMappedAddress::new(undefined_struct),
             ),
+            #[cfg(feature = "memory")]
             SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE => {
                 DefinedStruct::MemoryDeviceMappedAddress(SMBiosMemoryDeviceMappedAddress::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "platform")]
             SMBiosBuiltInPointingDevice::STRUCT_TYPE => DefinedStruct::BuiltInPointingDevice(
                 SMBiosBuiltInPointingDevice::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosPortableBattery::STRUCT_TYPE => {
                 DefinedStruct::PortableBattery(SMBiosPortableBattery::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosSystemReset::STRUCT_TYPE => {
                 DefinedStruct::SystemReset(SMBiosSystemReset::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosHardwareSecurity::STRUCT_TYPE => {
                 DefinedStruct::HardwareSecurity(SMBiosHardwareSecurity::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosSystemPowerControls::STRUCT_TYPE => {
                 DefinedStruct::SystemPowerControls(SMBiosSystemPowerControls::new(undefined_struct))
             }
+            #[cfg(feature = "sensors")]
             SMBiosVoltageProbe::STRUCT_TYPE => {
                 DefinedStruct::VoltageProbe(SMBiosVoltageProbe::new(undefined_struct))
             }
+            #[cfg(feature = "sensors")]
             SMBiosCoolingDevice::STRUCT_TYPE => {
                 DefinedStruct::CoolingDevice(SMBiosCoolingDevice::new(undefined_struct))
             }
+            #[cfg(feature = "sensors")]
             SMBiosTemperatureProbe::STRUCT_TYPE => {
                 DefinedStruct::TemperatureProbe(SMBiosTemperatureProbe::new(undefined_struct))
             }
+            #[cfg(feature = "sensors")]
             SMBiosElectricalCurrentProbe::STRUCT_TYPE => DefinedStruct::ElectricalCurrentProbe(
                 SMBiosElectricalCurrentProbe::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosOutOfBandRemoteAccess::STRUCT_TYPE => DefinedStruct::OutOfBandRemoteAccess(
                 SMBiosOutOfBandRemoteAccess::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosBisEntryPoint::STRUCT_TYPE => {
                 DefinedStruct::BisEntryPoint(SMBiosBisEntryPoint::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosSystemBootInformation::STRUCT_TYPE => DefinedStruct::SystemBootInformation(
                 SMBiosSystemBootInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "memory")]
             SMBiosMemoryErrorInformation64::STRUCT_TYPE => {
                 DefinedStruct::MemoryErrorInformation64Bit(SMBiosMemoryErrorInformation64::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "sensors")]
             SMBiosManagementDevice::STRUCT_TYPE => {
                 DefinedStruct::ManagementDevice(SMBiosManagementDevice::new(undefined_struct))
             }
+            #[cfg(feature = "sensors")]
             SMBiosManagementDeviceComponent::STRUCT_TYPE => {
                 DefinedStruct::ManagementDeviceComponent(SMBiosManagementDeviceComponent::new(
                     undefined_struct,
                 ))
             }
+            #[cfg(feature = "sensors")]
             SMBiosManagementDeviceThresholdData::STRUCT_TYPE => {
                 DefinedStruct::ManagementDeviceThresholdData(
                     SMBiosManagementDeviceThresholdData::new(undefined_struct),
                 )
             }
+            #[cfg(feature = "memory")]
             SMBiosMemoryChannel::STRUCT_TYPE => {
                 DefinedStruct::MemoryChannel(SMBiosMemoryChannel::new(undefined_struct))
             }
+            #[cfg(feature = "platform")]
             SMBiosIpmiDeviceInformation::STRUCT_TYPE => DefinedStruct::IpmiDeviceInformation(
                 SMBiosIpmiDeviceInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosSystemPowerSupply::STRUCT_TYPE => {
                 DefinedStruct::SystemPowerSupply(SMBiosSystemPowerSupply::new(undefined_struct))
             }
+            #[cfg(feature = "oem")]
             SMBiosAdditionalInformation::STRUCT_TYPE => DefinedStruct::AdditionalInformation(
                 SMBiosAdditionalInformation::new(undefined_struct),
             ),
+            #[cfg(feature = "platform")]
             SMBiosOnboardDevicesExtendedInformation::STRUCT_TYPE => {
                 DefinedStruct::OnboardDevicesExtendedInformation(
                     SMBiosOnboardDevicesExtendedInformation::new(undefined_struct),
                 )
             }
+            #[cfg(feature = "platform")]
             SMBiosManagementControllerHostInterface::STRUCT_TYPE => {
                 DefinedStruct::ManagementControllerHostInterface(
                     SMBiosManagementControllerHostInterface::new(undefined_struct),
                 )
             }
+            #[cfg(feature = "platform")]
             SMBiosTpmDevice::STRUCT_TYPE => {
                 DefinedStruct::TpmDevice(SMBiosTpmDevice::new(undefined_struct))
             }
+            #[cfg(feature = "processor")]
             SMBiosProcessorAdditionalInformation::STRUCT_TYPE => {
                 DefinedStruct::ProcessorAdditionalInformation(
                     SMBiosProcessorAdditionalInformation::new(undefined_struct),
                 )
             }
+            #[cfg(feature = "platform")]
             SMBiosFirmwareInventoryInformation::STRUCT_TYPE => {
                 DefinedStruct::FirmwareInventoryInformation(
                     SMBiosFirmwareInventoryInformation::new(undefined_struct),
                 )
             }
+            #[cfg(feature = "platform")]
             SMBiosStringProperty::STRUCT_TYPE => {
                 DefinedStruct::StringProperty(SMBiosStringProperty::new(undefined_struct))
             }
@@ -311,6 +509,11 @@ impl<'a> DefinedStructTable<'a> {
     fn add(&mut self, elem: DefinedStruct<'a>) {
         self.0.push(elem);
     }
+
+    /// Iterator of the contained [DefinedStruct] items
+    pub fn iter(&self) -> std::slice::Iter<'_, DefinedStruct<'a>> {
+        self.0.iter()
+    }
 }
 
 impl<'a> IntoIterator for DefinedStructTable<'a> {
@@ -333,3 +536,37 @@ impl<'a> FromIterator<&'a UndefinedStruct> for DefinedStructTable<'a> {
         defined_struct_table
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Handle, UndefinedStructTable};
+
+    #[test]
+    fn test_defined_struct_table() {
+        // Type 1 (truncated to the header), an OEM type 0xC0, and End-of-Table
+        let mut data = vec![0x01, 0x04, 0x00, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0xC0, 0x05, 0x01, 0x00, 0xAA, 0x00, 0x00]);
+        data.extend_from_slice(&[0x7F, 0x04, 0x02, 0x00, 0x00, 0x00]);
+        let undefined_table = UndefinedStructTable::from(data);
+
+        let defined_table: DefinedStructTable<'_> = undefined_table.iter().collect();
+        let mut names = Vec::new();
+        for defined_struct in defined_table.iter() {
+            match defined_struct {
+                DefinedStruct::SystemInformation(_) => names.push("system"),
+                DefinedStruct::EndOfTable(_) => names.push("end"),
+                other => {
+                    assert!(matches!(other, DefinedStruct::Undefined(_)));
+                    assert_eq!(other.parts().header.struct_type(), 0xC0);
+                    names.push("oem");
+                }
+            }
+        }
+        assert_eq!(names, vec!["system", "oem", "end"]);
+
+        for (index, defined_struct) in defined_table.into_iter().enumerate() {
+            assert_eq!(defined_struct.parts().header.handle(), Handle(index as u16));
+        }
+    }
+}
@@ -60,13 +60,14 @@ impl<'a> AdditionalInformationEntry<'a> {
         let value_offset = self.entry_offset + VALUE_RELATIVE_OFFSET;
 
         match self.entry_length() {
-            Some(entry_length) => {
+            // A length shorter than the fixed portion of the entry is malformed
+            Some(entry_length) if entry_length as usize >= VALUE_RELATIVE_OFFSET => {
                 let value_size = entry_length as usize - VALUE_RELATIVE_OFFSET;
                 self.additional_information
                     .parts()
                     .get_field_data(value_offset, value_offset + value_size)
             }
-            None => None,
+            _ => None,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::core::{strings::*, Handle, UndefinedStruct};
+use crate::core::{flag_set, strings::*, Handle, SMBiosData, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
@@ -81,9 +81,22 @@ impl<'a> SMBiosBaseboardInformation<'a> {
     }
 
     /// List of handles of other structures that are contained by this baseboard.
-    pub fn contained_object_handle_iterator(&'a self) -> ObjectHandleIterator<'a> {
+    pub fn contained_object_handle_iterator(&self) -> ObjectHandleIterator<'a> {
         ObjectHandleIterator::new(self)
     }
+
+    /// Contained objects resolved to their structures in `data`
+    ///
+    /// Each handle is paired with the structure it refers to, or `None` when
+    /// the table has no structure with that handle.  Call `.defined_struct()`
+    /// on a structure to downcast it.
+    pub fn contained_objects(
+        &self,
+        data: &'a SMBiosData,
+    ) -> impl Iterator<Item = (Handle, Option<&'a UndefinedStruct>)> + 'a {
+        self.contained_object_handle_iterator()
+            .map(move |handle| (handle, data.find_by_handle(&handle)))
+    }
 }
 
 impl fmt::Debug for SMBiosBaseboardInformation<'_> {
@@ -215,7 +228,7 @@ impl From<u8> for BoardTypeData {
 }
 
 /// # Board Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BoardType {
     /// Unknown
     Unknown,
@@ -248,7 +261,17 @@ pub enum BoardType {
 }
 
 /// # Baseboard Features
-#[derive(PartialEq, Eq)]
+///
+/// Each defined bit is available as a named constant which can be combined
+/// and tested as flags:
+///
+/// ```
+/// # use smbioslib::*;
+/// let features = BaseboardFeatures::from(0x09);
+/// assert!(features.contains(BaseboardFeatures::HOSTING_BOARD | BaseboardFeatures::REPLACEABLE));
+/// assert!(!features.contains(BaseboardFeatures::HOT_SWAPPABLE));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct BaseboardFeatures {
     /// Raw value
     ///
@@ -274,32 +297,61 @@ impl From<u8> for BaseboardFeatures {
 }
 
 impl BaseboardFeatures {
+    /// Set if the board is a hosting board (for example, a motherboard).
+    pub const HOSTING_BOARD: Self = Self { raw: 0x01 };
+
+    /// Set if the board requires at least one daughter board or auxiliary card to function properly.
+    pub const REQUIRES_DAUGHTERBOARD: Self = Self { raw: 0x02 };
+
+    /// Set if the board is removable; it is designed to be taken in and out of the chassis without impairing the function of the chassis.
+    pub const REMOVABLE: Self = Self { raw: 0x04 };
+
+    /// Set if the board is replaceable; it is possible to replace (either as a field repair or as an upgrade) the board with a physically different board. The board is inherently removable.
+    pub const REPLACEABLE: Self = Self { raw: 0x08 };
+
+    /// Set if the board is hot swappable; it is possible to replace the board with a physically different but equivalent board while power is applied to the board. The board is inherently replaceable and removable.
+    pub const HOT_SWAPPABLE: Self = Self { raw: 0x10 };
+
     /// Set if the board is a hosting board (for example, a motherboard).
     pub fn hosting_board(&self) -> bool {
-        self.raw & 0x01 == 0x01
+        self.contains(Self::HOSTING_BOARD)
     }
 
     /// Set if the board requires at least one daughter board or auxiliary card to function properly.
     pub fn requires_daughterboard(&self) -> bool {
-        self.raw & 0x02 == 0x02
+        self.contains(Self::REQUIRES_DAUGHTERBOARD)
     }
 
     /// Set if the board is removable; it is designed to be taken in and out of the chassis without impairing the function of the chassis.
     pub fn is_removable(&self) -> bool {
-        self.raw & 0x04 == 0x04
+        self.contains(Self::REMOVABLE)
     }
 
     /// Set if the board is replaceable; it is possible to replace (either as a field repair or as an upgrade) the board with a physically different board. The board is inherently removable.
     pub fn is_replaceable(&self) -> bool {
-        self.raw & 0x08 == 0x08
+        self.contains(Self::REPLACEABLE)
     }
 
     /// Set if the board is hot swappable; it is possible to replace the board with a physically different but equivalent board while power is applied to the board. The board is inherently replaceable and removable.
     pub fn is_hot_swappable(&self) -> bool {
-        self.raw & 0x10 == 0x10
+        self.contains(Self::HOT_SWAPPABLE)
     }
 }
 
+flag_set!(
+    BaseboardFeatures,
+    [
+        ("Board is a hosting board", HOSTING_BOARD),
+        (
+            "Board requires at least one daughter board",
+            REQUIRES_DAUGHTERBOARD
+        ),
+        ("Board is removable", REMOVABLE),
+        ("Board is replaceable", REPLACEABLE),
+        ("Board is hot swappable", HOT_SWAPPABLE),
+    ]
+);
+
 impl fmt::Debug for BaseboardFeatures {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<BaseboardFeatures>())
@@ -333,7 +385,7 @@ impl Serialize for BaseboardFeatures {
 ///
 /// Iterates over the object handles contained within the [SMBiosBaseboardInformation] structure
 pub struct ObjectHandleIterator<'a> {
-    data: &'a SMBiosBaseboardInformation<'a>,
+    parts: &'a UndefinedStruct,
     current_index: usize,
     current_entry: u8,
     number_of_contained_object_handles: u8,
@@ -343,9 +395,9 @@ impl<'a> ObjectHandleIterator<'a> {
     const OBJECT_HANDLES_OFFSET: usize = 0x0Fusize;
 
     /// Creates an instance of the object handle iterator.
-    pub fn new(data: &'a SMBiosBaseboardInformation<'a>) -> Self {
+    pub fn new(data: &SMBiosBaseboardInformation<'a>) -> Self {
         ObjectHandleIterator {
-            data: data,
+            parts: data.parts(),
             current_index: Self::OBJECT_HANDLES_OFFSET,
             current_entry: 0,
             number_of_contained_object_handles: data
@@ -366,13 +418,10 @@ impl<'a> IntoIterator for &'a ObjectHandleIterator<'a> {
 
     fn into_iter(self) -> Self::IntoIter {
         ObjectHandleIterator {
-            data: self.data,
+            parts: self.parts,
             current_index: ObjectHandleIterator::OBJECT_HANDLES_OFFSET,
             current_entry: 0,
-            number_of_contained_object_handles: self
-                .data
-                .number_of_contained_object_handles()
-                .unwrap_or(0),
+            number_of_contained_object_handles: self.number_of_contained_object_handles,
         }
     }
 }
@@ -386,7 +435,7 @@ impl<'a> Iterator for ObjectHandleIterator<'a> {
             return None;
         }
 
-        match self.data.parts().get_field_handle(self.current_index) {
+        match self.parts.get_field_handle(self.current_index) {
             Some(current_handle) => {
                 self.current_index = self.current_index + Handle::SIZE;
                 self.current_entry = self.current_entry + 1;
@@ -513,4 +562,54 @@ mod tests {
         // debug print test
         println!("baseboard_information: {:?}", baseboard_information);
     }
+
+    #[test]
+    fn test_board_type() {
+        assert_eq!(*BoardTypeData::from(0x09), BoardType::Daughterboard);
+        assert_eq!(*BoardTypeData::from(0x0B), BoardType::ProcessorMemoryModule);
+        let unknown = BoardTypeData::from(0x0E);
+        assert_eq!(*unknown, BoardType::None);
+        assert_eq!(unknown.to_string(), "14");
+    }
+
+    #[test]
+    fn test_contained_objects() {
+        use crate::core::{StructBuilder, TableBuilder};
+
+        let mut builder = TableBuilder::new();
+        let chassis = builder.add(StructBuilder::new(3));
+        builder.add(
+            StructBuilder::new(2)
+                .string(0x04, "Maker")
+                .byte(0x0D, 0x0A)
+                .byte(0x0E, 3)
+                .handle(0x0F, chassis)
+                .handle(0x11, Handle(0x40))
+                .handle(0x13, chassis),
+        );
+        let data = builder.build_smbios_data(None);
+
+        // Usable on a temporary structure
+        let handles: Vec<Handle> = data
+            .first::<SMBiosBaseboardInformation<'_>>()
+            .unwrap()
+            .contained_object_handle_iterator()
+            .collect();
+        assert_eq!(handles, vec![chassis, Handle(0x40), chassis]);
+
+        let baseboard = data.first::<SMBiosBaseboardInformation<'_>>().unwrap();
+        let resolved: Vec<(Handle, Option<u8>)> = baseboard
+            .contained_objects(&data)
+            .map(|(handle, object)| {
+                (
+                    handle,
+                    object.map(|undefined_struct| undefined_struct.header.struct_type()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            resolved,
+            vec![(chassis, Some(3)), (Handle(0x40), None), (chassis, Some(3))]
+        );
+    }
 }
@@ -1,8 +1,9 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{flag_set, strings::*, SMBiosVersion, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
+use std::str::FromStr;
 
 /// #  BIOS Information (Type 0)
 ///
@@ -68,6 +69,14 @@ impl<'a> SMBiosInformation<'a> {
         self.parts.get_field_string(0x8)
     }
 
+    /// BIOS release date parsed from [SMBiosInformation::release_date]
+    ///
+    /// `None` when the string is absent or not a valid mm/dd/yy or
+    /// mm/dd/yyyy date.
+    pub fn release_date_parsed(&self) -> Option<BiosReleaseDate> {
+        self.release_date().ok()?.parse().ok()
+    }
+
     /// BIOS ROM size
     ///
     /// Size (n) where 64K * (n+1) is the size of the
@@ -101,19 +110,83 @@ impl<'a> SMBiosInformation<'a> {
     }
 
     /// Characteristics extension byte 0
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn characteristics_extension0(&self) -> Option<BiosCharacteristicsExtension0> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x12)
             .map(|raw| BiosCharacteristicsExtension0::from(raw))
     }
 
     /// Characteristics extension byte 1
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn characteristics_extension1(&self) -> Option<BiosCharacteristicsExtension1> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x13)
             .map(|raw| BiosCharacteristicsExtension1::from(raw))
     }
 
+    /// ACPI is supported
+    ///
+    /// `None` when the structure predates the first extension byte (2.1).
+    pub fn acpi_is_supported(&self) -> Option<bool> {
+        self.characteristics_extension0()
+            .map(|extension| extension.acpi_is_supported())
+    }
+
+    /// USB Legacy is supported
+    ///
+    /// `None` when the structure predates the first extension byte (2.1).
+    pub fn usb_legacy_is_supported(&self) -> Option<bool> {
+        self.characteristics_extension0()
+            .map(|extension| extension.usb_legacy_is_supported())
+    }
+
+    /// Targeted content distribution is enabled
+    ///
+    /// `None` when the structure predates the 2.4 layout, in which this bit
+    /// was reserved.
+    pub fn targeted_content_distribution_is_supported(&self) -> Option<bool> {
+        self.characteristics_extension1_since_2_4()
+            .map(|extension| extension.targeted_content_distribution_is_supported())
+    }
+
+    /// UEFI Specification is supported
+    ///
+    /// `None` when the structure predates the 2.4 layout.  The bit is defined
+    /// from version 2.7, which did not change the structure length, and is
+    /// zero in earlier 2.4 to 2.6 implementations.
+    pub fn uefi_is_supported(&self) -> Option<bool> {
+        self.characteristics_extension1_since_2_4()
+            .map(|extension| extension.uefi_specification_is_supported())
+    }
+
+    /// The table describes a virtual machine
+    ///
+    /// `None` when the structure predates the 2.4 layout.  The bit is defined
+    /// from version 2.7; when it is clear nothing can be inferred about the
+    /// virtuality of the system.
+    pub fn is_virtual_machine(&self) -> Option<bool> {
+        self.characteristics_extension1_since_2_4()
+            .map(|extension| extension.smbios_table_describes_avirtual_machine())
+    }
+
+    /// Characteristics extension byte 1 of a structure with the 2.4 or later
+    /// layout (at least 18h bytes)
+    ///
+    /// Version 2.3 structures include the byte, but only bits 0 and 1 are
+    /// defined for them.
+    fn characteristics_extension1_since_2_4(&self) -> Option<BiosCharacteristicsExtension1> {
+        if self.parts.header.length() >= 0x18 {
+            self.characteristics_extension1()
+        } else {
+            None
+        }
+    }
+
     /// System BIOS major release
     ///
     /// Identifies the major release of the System
@@ -128,8 +201,12 @@ impl<'a> SMBiosInformation<'a> {
     /// If the system does not support the use of
     /// this field, the value is 0FFh for both this field
     /// and the System BIOS Minor Release field.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn system_bios_major_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x14)
+        self.parts
+            .since(SMBiosVersion::new(2, 4, 0))?
+            .get_field_byte(0x14)
     }
 
     /// System BIOS minor release
@@ -137,8 +214,12 @@ impl<'a> SMBiosInformation<'a> {
     /// Identifies the minor release of the System
     /// BIOS; for example, the value is 16h for
     /// revision 10.22 and 01h for revision 2.1.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn system_bios_minor_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
+        self.parts
+            .since(SMBiosVersion::new(2, 4, 0))?
+            .get_field_byte(0x15)
     }
 
     /// Embedded controller firmware major release
@@ -157,8 +238,12 @@ impl<'a> SMBiosInformation<'a> {
     /// If the system does not have field
     /// upgradeable embedded controller firmware,
     /// the value is 0FFh.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn e_c_firmware_major_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x16)
+        self.parts
+            .since(SMBiosVersion::new(2, 4, 0))?
+            .get_field_byte(0x16)
     }
 
     /// Embedded controller firmware minor release
@@ -170,8 +255,12 @@ impl<'a> SMBiosInformation<'a> {
     /// If the system does not have field
     /// upgradeable embedded controller firmware,
     /// the value is 0FFh.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn e_c_firmware_minor_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x17)
+        self.parts
+            .since(SMBiosVersion::new(2, 4, 0))?
+            .get_field_byte(0x17)
     }
 
     /// Extended BIOS ROM size
@@ -192,11 +281,107 @@ impl<'a> SMBiosInformation<'a> {
     /// 0100_0000_0011_0000b or 4030h.
     pub fn extended_rom_size(&self) -> Option<RomSize> {
         self.parts
+            .since(SMBiosVersion::new(3, 1, 0))?
             .get_field_word(0x18)
             .map(|raw| RomSize::from(raw))
     }
+
+    /// Size of the physical device(s) containing the BIOS, in bytes
+    ///
+    /// Combines [SMBiosInformation::rom_size] with
+    /// [SMBiosInformation::extended_rom_size] when the former is FFh (16MB or
+    /// greater).  `None` when the extended size is absent or uses a reserved
+    /// unit.
+    pub fn rom_size_bytes(&self) -> Option<u64> {
+        match self.rom_size()? {
+            RomSize::SeeExtendedRomSize => self.extended_rom_size()?.bytes(),
+            rom_size => rom_size.bytes(),
+        }
+    }
+}
+
+/// # BIOS Release Date
+///
+/// Fields are ordered so that dates compare chronologically.
+///
+/// ```
+/// # use smbioslib::*;
+/// let release: BiosReleaseDate = "08/06/2019".parse().unwrap();
+/// assert_eq!(release, BiosReleaseDate::new(2019, 8, 6));
+/// assert!(release < BiosReleaseDate::new(2020, 1, 1));
+/// assert_eq!("12/31/99".parse(), Ok(BiosReleaseDate::new(1999, 12, 31)));
+/// ```
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BiosReleaseDate {
+    /// Year, four digits
+    pub year: u16,
+    /// Month, 1 through 12
+    pub month: u8,
+    /// Day of the month, 1 through 31
+    pub day: u8,
 }
 
+impl BiosReleaseDate {
+    /// Creates a date, for example a cutoff to compare against
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl FromStr for BiosReleaseDate {
+    type Err = ParseBiosReleaseDateError;
+
+    /// Parses mm/dd/yy, where the year is assumed to be 19yy, or mm/dd/yyyy
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('/');
+        let (month, day, year) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(month), Some(day), Some(year), None) => (month, day, year),
+            _ => return Err(ParseBiosReleaseDateError),
+        };
+
+        let is_number = |part: &str, len: usize| {
+            part.len() == len && part.bytes().all(|byte| byte.is_ascii_digit())
+        };
+        if !is_number(month, 2) || !is_number(day, 2) {
+            return Err(ParseBiosReleaseDateError);
+        }
+        let year = if is_number(year, 4) {
+            year.parse().map_err(|_| ParseBiosReleaseDateError)?
+        } else if is_number(year, 2) {
+            1900 + year.parse::<u16>().map_err(|_| ParseBiosReleaseDateError)?
+        } else {
+            return Err(ParseBiosReleaseDateError);
+        };
+        let month: u8 = month.parse().map_err(|_| ParseBiosReleaseDateError)?;
+        let day: u8 = day.parse().map_err(|_| ParseBiosReleaseDateError)?;
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(ParseBiosReleaseDateError);
+        }
+        Ok(BiosReleaseDate { year, month, day })
+    }
+}
+
+impl fmt::Display for BiosReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.month, self.day, self.year)
+    }
+}
+
+/// # Parse BIOS Release Date Error
+///
+/// The string is not a valid mm/dd/yy or mm/dd/yyyy date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseBiosReleaseDateError;
+
+impl fmt::Display for ParseBiosReleaseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Not an mm/dd/yy or mm/dd/yyyy date")
+    }
+}
+
+impl std::error::Error for ParseBiosReleaseDateError {}
+
 /// # BIOS ROM size
 #[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum RomSize {
@@ -218,6 +403,20 @@ pub enum RomSize {
     SeeExtendedRomSize,
 }
 
+impl RomSize {
+    /// The size in bytes
+    ///
+    /// `None` for [RomSize::Undefined] and [RomSize::SeeExtendedRomSize].
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            RomSize::Kilobytes(size) => Some(*size as u64 * 1024),
+            RomSize::Megabytes(size) => Some(*size as u64 * 1024 * 1024),
+            RomSize::Gigabytes(size) => Some(*size as u64 * 1024 * 1024 * 1024),
+            RomSize::Undefined(_) | RomSize::SeeExtendedRomSize => None,
+        }
+    }
+}
+
 impl From<u16> for RomSize {
     fn from(raw: u16) -> Self {
         // Bits 15:14 Unit
@@ -348,7 +547,24 @@ impl Serialize for SMBiosInformation<'_> {
 }
 
 /// # BIOS Characteristics
-#[derive(PartialEq, Eq)]
+///
+/// Bits 0 through 31 of the BIOS Characteristics QWORD.  Bits 32 through 47
+/// and 48 through 63 are reserved for the BIOS vendor and the system vendor
+/// and are available from [SMBiosInformation::bios_vendor_reserved_characteristics]
+/// and [SMBiosInformation::system_vendor_reserved_characteristics].
+///
+/// Each defined bit is available as a named constant which can be combined
+/// and tested as flags:
+///
+/// ```
+/// # use smbioslib::*;
+/// let characteristics = BiosCharacteristics::from(0x0000_8880);
+/// let wanted = BiosCharacteristics::PCI_SUPPORTED | BiosCharacteristics::BOOT_FROM_CD_SUPPORTED;
+/// assert!(characteristics.contains(wanted));
+/// assert!(!characteristics.contains(BiosCharacteristics::ISA_SUPPORTED));
+/// assert_eq!(characteristics.iter().count(), 3);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct BiosCharacteristics {
     /// Raw value
     pub raw: u32,
@@ -369,157 +585,283 @@ impl From<u32> for BiosCharacteristics {
 }
 
 impl BiosCharacteristics {
+    /// Unknown.
+    pub const UNKNOWN: Self = Self { raw: 0x00000004 };
+
+    /// BIOS Characteristics are not supported.
+    pub const NOT_SUPPORTED: Self = Self { raw: 0x00000008 };
+
+    /// ISA is supported.
+    pub const ISA_SUPPORTED: Self = Self { raw: 0x00000010 };
+
+    /// MCA is supported.
+    pub const MCA_SUPPORTED: Self = Self { raw: 0x00000020 };
+
+    /// EISA is supported.
+    pub const EISA_SUPPORTED: Self = Self { raw: 0x00000040 };
+
+    /// PCI is supported.
+    pub const PCI_SUPPORTED: Self = Self { raw: 0x00000080 };
+
+    /// PC card (PCMCIA) is supported.
+    pub const PCMCIA_SUPPORTED: Self = Self { raw: 0x00000100 };
+
+    /// Plug and Play is supported.
+    pub const PLUG_AND_PLAY_SUPPORTED: Self = Self { raw: 0x00000200 };
+
+    /// APM is supported.
+    pub const APM_SUPPORTED: Self = Self { raw: 0x00000400 };
+
+    /// BIOS is upgradeable (Flash).
+    pub const BIOS_UPGRADEABLE: Self = Self { raw: 0x00000800 };
+
+    /// BIOS shadowing is allowed.
+    pub const BIOS_SHADOWING_ALLOWED: Self = Self { raw: 0x00001000 };
+
+    /// VL-VESA is supported.
+    pub const VLVESA_SUPPORTED: Self = Self { raw: 0x00002000 };
+
+    /// ESCD support is available.
+    pub const ESCD_SUPPORT_AVAILABLE: Self = Self { raw: 0x00004000 };
+
+    /// Boot from CD is supported.
+    pub const BOOT_FROM_CD_SUPPORTED: Self = Self { raw: 0x00008000 };
+
+    /// Selectable boot is supported.
+    pub const SELECTABLE_BOOT_SUPPORTED: Self = Self { raw: 0x00010000 };
+
+    /// BIOS ROM is socketed (e.g. PLCC or SOP socket).
+    pub const BIOS_ROM_SOCKETED: Self = Self { raw: 0x00020000 };
+
+    /// Boot from PC card (PCMCIA) is supported.
+    pub const BOOT_FROM_PCMCIA_SUPPORTED: Self = Self { raw: 0x00040000 };
+
+    /// EDD specification is supported.
+    pub const EDD_SPECIFICATION_SUPPORTED: Self = Self { raw: 0x00080000 };
+
+    /// Int 13h — Japanese floppy for NEC 9800 1.2 MB (3.5”, 1K bytes/sector, 360 RPM) is supported.
+    pub const FLOPPY_NEC_JAPANESE_SUPPORTED: Self = Self { raw: 0x00100000 };
+
+    /// Int 13h — Japanese floppy for Toshiba 1.2 MB (3.5”, 360 RPM) is supported.
+    pub const FLOPPY_TOSHIBA_JAPANESE_SUPPORTED: Self = Self { raw: 0x00200000 };
+
+    /// Int 13h — 5.25” / 360 KB floppy services are supported.
+    pub const FLOPPY_525_360_SUPPORTED: Self = Self { raw: 0x00400000 };
+
+    /// Int 13h — 5.25” /1.2 MB floppy services are supported.
+    pub const FLOPPY_525_12_SUPPORTED: Self = Self { raw: 0x00800000 };
+
+    /// Int 13h — 3.5” / 720 KB floppy services are supported.
+    pub const FLOPPY_35_720_SUPPORTED: Self = Self { raw: 0x01000000 };
+
+    /// Int 13h — 3.5” / 2.88 MB floppy services are supported.
+    pub const FLOPPY_35_288_SUPPORTED: Self = Self { raw: 0x02000000 };
+
+    /// Int 5h, print screen Service is supported.
+    pub const PRINT_SCREEN_SERVICE_SUPPORTED: Self = Self { raw: 0x04000000 };
+
+    /// Int 9h, 8042 keyboard services are supported.
+    pub const KEYBOARD_8042_SERVICES_SUPPORTED: Self = Self { raw: 0x08000000 };
+
+    /// Int 14h, serial services are supported.
+    pub const SERIAL_SERVICES_SUPPORTED: Self = Self { raw: 0x10000000 };
+
+    /// Int 17h, printer services are supported.
+    pub const PRINTER_SERVICES_SUPPORTED: Self = Self { raw: 0x20000000 };
+
+    /// Int 10h, CGA/Mono Video Services are supported.
+    pub const CGA_MONO_VIDEO_SERVICES_SUPPORTED: Self = Self { raw: 0x40000000 };
+
+    /// NEC PC-98.
+    pub const NEC_PC_98: Self = Self { raw: 0x80000000 };
+
     /// Unknown.
     pub fn unknown(&self) -> bool {
-        self.raw & 0x00000004 == 0x00000004
+        self.contains(Self::UNKNOWN)
     }
 
     /// BIOS Characteristics are not supported.
     pub fn bios_characteristics_not_supported(&self) -> bool {
-        self.raw & 0x00000008 == 0x00000008
+        self.contains(Self::NOT_SUPPORTED)
     }
 
     /// ISA is supported.
     pub fn isa_supported(&self) -> bool {
-        self.raw & 0x00000010 == 0x00000010
+        self.contains(Self::ISA_SUPPORTED)
     }
 
     /// MCA is supported.
     pub fn mca_supported(&self) -> bool {
-        self.raw & 0x00000020 == 0x00000020
+        self.contains(Self::MCA_SUPPORTED)
     }
 
     /// EISA is supported.
     pub fn eisa_supported(&self) -> bool {
-        self.raw & 0x00000040 == 0x00000040
+        self.contains(Self::EISA_SUPPORTED)
     }
 
     /// PCI is supported.
     pub fn pci_supported(&self) -> bool {
-        self.raw & 0x00000080 == 0x00000080
+        self.contains(Self::PCI_SUPPORTED)
     }
 
     /// PC card (PCMCIA) is supported.
     pub fn pcmcia_supported(&self) -> bool {
-        self.raw & 0x00000100 == 0x00000100
+        self.contains(Self::PCMCIA_SUPPORTED)
     }
 
     /// Plug and Play is supported.
     pub fn plug_and_play_supported(&self) -> bool {
-        self.raw & 0x00000200 == 0x00000200
+        self.contains(Self::PLUG_AND_PLAY_SUPPORTED)
     }
 
     /// APM is supported.
     pub fn apm_supported(&self) -> bool {
-        self.raw & 0x00000400 == 0x00000400
+        self.contains(Self::APM_SUPPORTED)
     }
 
     /// BIOS is upgradeable (Flash).
     pub fn bios_upgradeable(&self) -> bool {
-        self.raw & 0x00000800 == 0x00000800
+        self.contains(Self::BIOS_UPGRADEABLE)
     }
 
     /// BIOS shadowing is allowed.
     pub fn bios_shadowing_allowed(&self) -> bool {
-        self.raw & 0x00001000 == 0x00001000
+        self.contains(Self::BIOS_SHADOWING_ALLOWED)
     }
 
     /// VL-VESA is supported.
     pub fn vlvesa_supported(&self) -> bool {
-        self.raw & 0x00002000 == 0x00002000
+        self.contains(Self::VLVESA_SUPPORTED)
     }
 
     /// ESCD support is available.
     pub fn escd_support_available(&self) -> bool {
-        self.raw & 0x00004000 == 0x00004000
+        self.contains(Self::ESCD_SUPPORT_AVAILABLE)
     }
 
     /// Boot from CD is supported.
     pub fn boot_from_cdsupported(&self) -> bool {
-        self.raw & 0x00008000 == 0x00008000
+        self.contains(Self::BOOT_FROM_CD_SUPPORTED)
     }
 
     /// Selectable boot is supported.
     pub fn selectable_boot_supported(&self) -> bool {
-        self.raw & 0x00010000 == 0x00010000
+        self.contains(Self::SELECTABLE_BOOT_SUPPORTED)
     }
 
     /// BIOS ROM is socketed (e.g. PLCC or SOP socket).
     pub fn bios_rom_socketed(&self) -> bool {
-        self.raw & 0x00020000 == 0x00020000
+        self.contains(Self::BIOS_ROM_SOCKETED)
     }
 
     /// Boot from PC card (PCMCIA) is supported.
     pub fn boot_from_pcmcia_supported(&self) -> bool {
-        self.raw & 0x00040000 == 0x00040000
+        self.contains(Self::BOOT_FROM_PCMCIA_SUPPORTED)
     }
 
     /// EDD specification is supported.
     pub fn edd_specification_supported(&self) -> bool {
-        self.raw & 0x00080000 == 0x00080000
+        self.contains(Self::EDD_SPECIFICATION_SUPPORTED)
     }
 
     /// Int 13h — Japanese floppy for NEC 9800 1.2 MB (3.5”, 1K bytes/sector, 360 RPM) is supported.
     pub fn floppy_nec_japanese_supported(&self) -> bool {
-        self.raw & 0x00100000 == 0x00100000
+        self.contains(Self::FLOPPY_NEC_JAPANESE_SUPPORTED)
     }
 
     /// Int 13h — Japanese floppy for Toshiba 1.2 MB (3.5”, 360 RPM) is supported.
     pub fn floppy_toshiba_japanese_supported(&self) -> bool {
-        self.raw & 0x00200000 == 0x00200000
+        self.contains(Self::FLOPPY_TOSHIBA_JAPANESE_SUPPORTED)
     }
 
     /// Int 13h — 5.25” / 360 KB floppy services are supported.
     pub fn floppy_525_360_supported(&self) -> bool {
-        self.raw & 0x00400000 == 0x00400000
+        self.contains(Self::FLOPPY_525_360_SUPPORTED)
     }
 
     /// Int 13h — 5.25” /1.2 MB floppy services are supported.
     pub fn floppy_525_12_supported(&self) -> bool {
-        self.raw & 0x00800000 == 0x00800000
+        self.contains(Self::FLOPPY_525_12_SUPPORTED)
     }
 
     /// Int 13h — 3.5” / 720 KB floppy services are supported.
     pub fn floppy_35_720_supported(&self) -> bool {
-        self.raw & 0x01000000 == 0x01000000
+        self.contains(Self::FLOPPY_35_720_SUPPORTED)
     }
 
     /// Int 13h — 3.5” / 2.88 MB floppy services are supported.
     pub fn floppy_35_288_supported(&self) -> bool {
-        self.raw & 0x02000000 == 0x02000000
+        self.contains(Self::FLOPPY_35_288_SUPPORTED)
     }
 
     /// Int 5h, print screen Service is supported.
     pub fn print_screen_service_supported(&self) -> bool {
-        self.raw & 0x04000000 == 0x04000000
+        self.contains(Self::PRINT_SCREEN_SERVICE_SUPPORTED)
     }
 
     /// Int 9h, 8042 keyboard services are supported.
     pub fn keyboard_8042services_supported(&self) -> bool {
-        self.raw & 0x08000000 == 0x08000000
+        self.contains(Self::KEYBOARD_8042_SERVICES_SUPPORTED)
     }
 
     /// Int 14h, serial services are supported.
     pub fn serial_services_supported(&self) -> bool {
-        self.raw & 0x10000000 == 0x10000000
+        self.contains(Self::SERIAL_SERVICES_SUPPORTED)
     }
 
     /// Int 17h, printer services are supported.
     pub fn printer_services_supported(&self) -> bool {
-        self.raw & 0x20000000 == 0x20000000
+        self.contains(Self::PRINTER_SERVICES_SUPPORTED)
     }
 
     /// Int 10h, CGA/Mono Video Services are supported.
     pub fn cga_mono_video_services_supported(&self) -> bool {
-        self.raw & 0x40000000 == 0x40000000
+        self.contains(Self::CGA_MONO_VIDEO_SERVICES_SUPPORTED)
     }
 
     /// NEC PC-98.
     pub fn nec_pc_98supported(&self) -> bool {
-        self.raw & 0x80000000 == 0x80000000
+        self.contains(Self::NEC_PC_98)
     }
 }
 
+flag_set!(
+    BiosCharacteristics,
+    [
+        ("Unknown", UNKNOWN),
+        ("BIOS Characteristics are not supported", NOT_SUPPORTED),
+        ("ISA is supported", ISA_SUPPORTED),
+        ("MCA is supported", MCA_SUPPORTED),
+        ("EISA is supported", EISA_SUPPORTED),
+        ("PCI is supported", PCI_SUPPORTED),
+        ("PC card (PCMCIA) is supported", PCMCIA_SUPPORTED),
+        ("Plug and Play is supported", PLUG_AND_PLAY_SUPPORTED),
+        ("APM is supported", APM_SUPPORTED),
+        ("BIOS is upgradeable (Flash)", BIOS_UPGRADEABLE),
+        ("BIOS shadowing is allowed", BIOS_SHADOWING_ALLOWED),
+        ("VL-VESA is supported", VLVESA_SUPPORTED),
+        ("ESCD support is available", ESCD_SUPPORT_AVAILABLE),
+        ("Boot from CD is supported", BOOT_FROM_CD_SUPPORTED),
+        ("Selectable boot is supported", SELECTABLE_BOOT_SUPPORTED),
+        ("BIOS ROM is socketed (e.g. PLCC or SOP socket)", BIOS_ROM_SOCKETED),
+        ("Boot from PC card (PCMCIA) is supported", BOOT_FROM_PCMCIA_SUPPORTED),
+        ("EDD specification is supported", EDD_SPECIFICATION_SUPPORTED),
+        ("Int 13h — Japanese floppy for NEC 9800 1.2 MB (3.5”, 1K bytes/sector, 360 RPM) is supported", FLOPPY_NEC_JAPANESE_SUPPORTED),
+        ("Int 13h — Japanese floppy for Toshiba 1.2 MB (3.5”, 360 RPM) is supported", FLOPPY_TOSHIBA_JAPANESE_SUPPORTED),
+        ("Int 13h — 5.25” / 360 KB floppy services are supported", FLOPPY_525_360_SUPPORTED),
+        ("Int 13h — 5.25” /1.2 MB floppy services are supported", FLOPPY_525_12_SUPPORTED),
+        ("Int 13h — 3.5” / 720 KB floppy services are supported", FLOPPY_35_720_SUPPORTED),
+        ("Int 13h — 3.5” / 2.88 MB floppy services are supported", FLOPPY_35_288_SUPPORTED),
+        ("Int 5h, print screen Service is supported", PRINT_SCREEN_SERVICE_SUPPORTED),
+        ("Int 9h, 8042 keyboard services are supported", KEYBOARD_8042_SERVICES_SUPPORTED),
+        ("Int 14h, serial services are supported", SERIAL_SERVICES_SUPPORTED),
+        ("Int 17h, printer services are supported", PRINTER_SERVICES_SUPPORTED),
+        ("Int 10h, CGA/Mono Video Services are supported", CGA_MONO_VIDEO_SERVICES_SUPPORTED),
+        ("NEC PC-98", NEC_PC_98),
+    ]
+);
+
 impl fmt::Debug for BiosCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<BiosCharacteristics>())
@@ -902,7 +1244,7 @@ impl Serialize for BiosCharacteristicsExtension1 {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("BiosCharacteristicsExtension1", 6)?;
+        let mut state = serializer.serialize_struct("BiosCharacteristicsExtension1", 8)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field(
             "bios_boot_specification_is_supported",
@@ -984,6 +1326,10 @@ mod tests {
             test_struct.release_date().to_string(),
             "08/06/2019".to_string()
         );
+        assert_eq!(
+            test_struct.release_date_parsed(),
+            Some(BiosReleaseDate::new(2019, 8, 6))
+        );
         assert_eq!(test_struct.rom_size(), Some(RomSize::SeeExtendedRomSize));
         assert_eq!(
             test_struct.characteristics(),
@@ -1044,6 +1390,123 @@ mod tests {
 
         let parts = UndefinedStruct::new(&struct_type0);
         let test_struct = SMBiosInformation::new(&parts);
-        assert_eq!(test_struct.rom_size(), Some(RomSize::Kilobytes(16320)))
+        assert_eq!(test_struct.rom_size(), Some(RomSize::Kilobytes(16320)));
+        assert_eq!(test_struct.rom_size_bytes(), Some(16320 * 1024));
+    }
+
+    #[test]
+    fn test_rom_size_bytes() {
+        // 3.1 layout with FFh in the legacy field
+        let mut raw = vec![
+            0x00, 0x1A, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, 0xFF, 0x80, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF, 0x20, 0x00, 0x00, 0x00,
+        ];
+        let rom_size_bytes =
+            |raw: &Vec<u8>| SMBiosInformation::new(&UndefinedStruct::new(raw)).rom_size_bytes();
+
+        // 32 MB
+        assert_eq!(rom_size_bytes(&raw), Some(32 * 1024 * 1024));
+
+        // 48 GB
+        raw[0x18..0x1A].copy_from_slice(&0x4030u16.to_le_bytes());
+        assert_eq!(rom_size_bytes(&raw), Some(48 * 1024 * 1024 * 1024));
+
+        // Reserved unit
+        raw[0x18..0x1A].copy_from_slice(&0x8010u16.to_le_bytes());
+        assert_eq!(rom_size_bytes(&raw), None);
+
+        // The extended size is ignored when the legacy field holds the size
+        raw[0x09] = 0x0F;
+        assert_eq!(rom_size_bytes(&raw), Some(1024 * 1024));
+
+        // 2.4 layout with FFh in the legacy field and no extended size
+        let mut raw = raw[..0x18].to_vec();
+        raw[0x01] = 0x18;
+        raw[0x09] = 0xFF;
+        raw.extend_from_slice(&[0x00, 0x00]);
+        assert_eq!(rom_size_bytes(&raw), None);
+    }
+
+    #[test]
+    fn test_characteristics_extensions() {
+        // 2.4 layout: ACPI, USB Legacy; BBS, targeted content, UEFI
+        let mut raw = vec![
+            0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.acpi_is_supported(), Some(true));
+        assert_eq!(test_struct.usb_legacy_is_supported(), Some(true));
+        assert_eq!(
+            test_struct.targeted_content_distribution_is_supported(),
+            Some(true)
+        );
+        assert_eq!(test_struct.uefi_is_supported(), Some(true));
+        assert_eq!(test_struct.is_virtual_machine(), Some(false));
+
+        // 2.3 layout: the second extension byte is present but its upper bits are reserved
+        raw[0x01] = 0x14;
+        raw.drain(0x14..0x18);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.acpi_is_supported(), Some(true));
+        assert!(test_struct.characteristics_extension1().is_some());
+        assert!(test_struct.uefi_is_supported().is_none());
+        assert!(test_struct.is_virtual_machine().is_none());
+        assert!(test_struct
+            .targeted_content_distribution_is_supported()
+            .is_none());
+
+        // 2.0 layout: no extension bytes
+        raw[0x01] = 0x12;
+        raw.drain(0x12..0x14);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosInformation::new(&parts);
+        assert!(test_struct.acpi_is_supported().is_none());
+        assert!(test_struct.usb_legacy_is_supported().is_none());
+    }
+
+    #[test]
+    fn test_release_date_parsing() {
+        assert_eq!(
+            "01/02/03".parse::<BiosReleaseDate>(),
+            Ok(BiosReleaseDate::new(1903, 1, 2))
+        );
+        assert_eq!(
+            " 11/30/2021 ".parse::<BiosReleaseDate>(),
+            Ok(BiosReleaseDate::new(2021, 11, 30))
+        );
+        for invalid in [
+            "",
+            "2019-08-06",
+            "8/6/2019",
+            "13/01/2019",
+            "00/10/2019",
+            "01/32/2019",
+            "01/01/019",
+            "01/01/2019/01",
+            "+1/01/2019",
+        ] {
+            assert_eq!(
+                invalid.parse::<BiosReleaseDate>(),
+                Err(ParseBiosReleaseDateError),
+                "{}",
+                invalid
+            );
+        }
+
+        let mut dates: Vec<BiosReleaseDate> = ["06/01/2020", "12/31/99", "01/15/2020"]
+            .iter()
+            .map(|date| date.parse().unwrap())
+            .collect();
+        dates.sort();
+        assert_eq!(
+            dates
+                .iter()
+                .map(|date| date.to_string())
+                .collect::<Vec<_>>(),
+            vec!["12/31/1999", "01/15/2020", "06/01/2020"]
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::{strings::*, SMBiosStruct, UndefinedStruct};
+use crate::{strings::*, SMBiosStruct, SMBiosVersion, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -36,8 +36,11 @@ impl<'a> SMBiosBiosLanguageInformation<'a> {
     }
 
     /// Bit field indicating the format of the languages.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn flags(&self) -> Option<BiosLanguageFlags> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x5)
             .map(|raw| BiosLanguageFlags::from(raw))
     }
@@ -51,6 +54,15 @@ impl<'a> SMBiosBiosLanguageInformation<'a> {
     pub fn installable_langauges(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// Iterates over the [number_of_installable_languages](Self::number_of_installable_languages)
+    /// language strings in order
+    pub fn installable_language_iterator(&self) -> InstallableLanguageIterator<'a> {
+        CountedStringIterator::new(
+            &self.parts.strings,
+            self.number_of_installable_languages().unwrap_or(0),
+        )
+    }
 }
 
 impl fmt::Debug for SMBiosBiosLanguageInformation<'_> {
@@ -87,7 +99,7 @@ impl Serialize for SMBiosBiosLanguageInformation<'_> {
 }
 
 /// # Language Format
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LanguageFormat {
     /// Language strings use the abbreviated format.
     ///
@@ -100,7 +112,7 @@ pub enum LanguageFormat {
 }
 
 /// # BIOS Language Flags
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct BiosLanguageFlags {
     /// Raw value
     pub raw: u8,
@@ -152,9 +164,79 @@ impl Serialize for BiosLanguageFlags {
     }
 }
 
+/// # Language Name
+///
+/// The parts of a BIOS language string, split according to its [LanguageFormat].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct LanguageName {
+    /// ISO 639-1 language name (e.g. "en")
+    pub language: String,
+    /// ISO 3166-1-alpha-2 territory name (e.g. "US")
+    pub region: Option<String>,
+    /// Encoding method (e.g. "iso8859-1"), only present in the long format
+    pub encoding: Option<String>,
+}
+
+impl LanguageName {
+    /// Splits a language string of the given format into its parts
+    ///
+    /// The long format "en|US|iso8859-1" is split on '|'; the abbreviated
+    /// format "enUS" is split after the two character language name.
+    ///
+    /// ```
+    /// use smbioslib::*;
+    ///
+    /// let name = LanguageName::parse("en|US|iso8859-1", LanguageFormat::Long);
+    /// assert_eq!(name.language, "en");
+    /// assert_eq!(name.region.as_deref(), Some("US"));
+    /// assert_eq!(name.encoding.as_deref(), Some("iso8859-1"));
+    ///
+    /// let name = LanguageName::parse("frCA", LanguageFormat::Abbreviated);
+    /// assert_eq!(name.language, "fr");
+    /// assert_eq!(name.region.as_deref(), Some("CA"));
+    /// assert_eq!(name.encoding, None);
+    /// ```
+    pub fn parse(value: &str, format: LanguageFormat) -> Self {
+        fn non_empty(part: &str) -> Option<String> {
+            if part.is_empty() {
+                None
+            } else {
+                Some(part.to_string())
+            }
+        }
+
+        match format {
+            LanguageFormat::Long => {
+                let mut parts = value.splitn(3, '|');
+                LanguageName {
+                    language: parts.next().unwrap_or_default().to_string(),
+                    region: parts.next().and_then(non_empty),
+                    encoding: parts.next().and_then(non_empty),
+                }
+            }
+            LanguageFormat::Abbreviated => match value.get(..2) {
+                Some(language) => LanguageName {
+                    language: language.to_string(),
+                    region: non_empty(&value[2..]),
+                    encoding: None,
+                },
+                None => LanguageName {
+                    language: value.to_string(),
+                    region: None,
+                    encoding: None,
+                },
+            },
+        }
+    }
+}
+
+/// # Installable Language Iterator for the strings contained within [SMBiosBiosLanguageInformation]
+pub type InstallableLanguageIterator<'a> = CountedStringIterator<'a>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
 
     #[test]
     fn test_bios_language_information() {
@@ -210,4 +292,50 @@ mod tests {
         // debug print test
         println!("bios_language_information: {:?}", bios_language_information);
     }
+
+    #[test]
+    fn test_installable_language_iterator() {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::with_length(SMBiosBiosLanguageInformation::STRUCT_TYPE, 0x16)
+                .byte(0x04, 2)
+                .byte(0x05, 0x01)
+                .string(0x15, "enUS")
+                .push_string("frCA"),
+        );
+        let data = builder.build_smbios_data(None);
+        let languages = data.first::<SMBiosBiosLanguageInformation<'_>>().unwrap();
+
+        let format = languages.flags().unwrap().language_format();
+        assert_eq!(format, LanguageFormat::Abbreviated);
+
+        let names: Vec<LanguageName> = languages
+            .installable_language_iterator()
+            .map(|string| LanguageName::parse(&string.to_string(), format))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                LanguageName {
+                    language: "en".to_string(),
+                    region: Some("US".to_string()),
+                    encoding: None,
+                },
+                LanguageName {
+                    language: "fr".to_string(),
+                    region: Some("CA".to_string()),
+                    encoding: None,
+                },
+            ]
+        );
+
+        let name = LanguageName::parse("ja|JP|unicode", LanguageFormat::Long);
+        assert_eq!(name.language, "ja");
+        assert_eq!(name.region.as_deref(), Some("JP"));
+        assert_eq!(name.encoding.as_deref(), Some("unicode"));
+
+        let name = LanguageName::parse("en", LanguageFormat::Long);
+        assert_eq!(name.region, None);
+        assert_eq!(name.encoding, None);
+    }
 }
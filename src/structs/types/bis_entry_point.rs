@@ -55,6 +55,18 @@ impl<'a> SMBiosBisEntryPoint<'a> {
         self.parts.get_field_byte(0x04)
     }
 
+    /// Returns true when the bytes of the structure sum to zero
+    ///
+    /// A structure truncated before the end of the 32-bit entry point is
+    /// never valid.
+    pub fn is_checksum_valid(&self) -> bool {
+        let fields = &self.parts.fields;
+        if fields.len() < 0x10 {
+            return false;
+        }
+        fields.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) == 0
+    }
+
     // fn reserved_1(&self) -> Option<u8> {
     //     self.parts.get_field_byte(0x05)
     // }
@@ -75,6 +87,17 @@ impl<'a> SMBiosBisEntryPoint<'a> {
         self.parts.get_field_dword(0x08)
     }
 
+    /// The 20-bit physical address of [SMBiosBisEntryPoint::bis_entry_16]
+    ///
+    /// The far pointer holds the offset in its low word and the segment in
+    /// its high word; the address is segment * 16 + offset.
+    pub fn bis_entry_16_address(&self) -> Option<u32> {
+        let pointer = self.bis_entry_16()?;
+        let segment = pointer >> 16;
+        let offset = pointer & 0xFFFF;
+        Some((segment << 4) + offset)
+    }
+
     /// BIS entry point pointer for use by 32-bit flat physical address mode callers. This is a 32-bit physical address.
     ///
     /// Flat Mode: A 32-bit (protected) IA-32 processor mode where CS:0, DS:0, and SS:0 all
@@ -119,3 +142,39 @@ impl Serialize for SMBiosBisEntryPoint<'_> {
         state.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test() {
+        let mut struct_type31 = vec![
+            0x1F, 0x1C, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0xE0, 0x00, 0x10,
+            0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let sum = struct_type31
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        struct_type31[0x04] = 0u8.wrapping_sub(sum);
+
+        let parts = UndefinedStruct::new(&struct_type31);
+        let test_struct = SMBiosBisEntryPoint::new(&parts);
+
+        assert_eq!(test_struct.checksum(), Some(struct_type31[0x04]));
+        assert!(test_struct.is_checksum_valid());
+        assert_eq!(test_struct.bis_entry_16(), Some(0xE000_0010));
+        assert_eq!(test_struct.bis_entry_16_address(), Some(0xE_0010));
+        assert_eq!(test_struct.bis_entry_32(), Some(0x000E_1000));
+
+        struct_type31[0x04] = struct_type31[0x04].wrapping_add(1);
+        let parts = UndefinedStruct::new(&struct_type31);
+        assert!(!SMBiosBisEntryPoint::new(&parts).is_checksum_valid());
+        // The declared length runs past the end of the buffer
+        let truncated = vec![0x1F, 0x1C, 0x31, 0x00, 0x00, 0x00];
+        let parts = UndefinedStruct::new(&truncated);
+        assert!(parts.fields.is_empty());
+        assert!(!SMBiosBisEntryPoint::new(&parts).is_checksum_valid());
+    }
+}
@@ -128,7 +128,7 @@ impl Deref for PointingDeviceTypeData {
 }
 
 /// # Built-in Pointing Device Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PointingDeviceType {
     /// Other
     Other,
@@ -206,6 +206,15 @@ impl Serialize for PointingDeviceInterfaceData {
     }
 }
 
+impl fmt::Display for PointingDeviceInterfaceData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            PointingDeviceInterface::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for PointingDeviceInterfaceData {
     type Target = PointingDeviceInterface;
 
@@ -215,7 +224,7 @@ impl Deref for PointingDeviceInterfaceData {
 }
 
 /// # Built-in Pointing Device Interface
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PointingDeviceInterface {
     /// Other field
     Other,
@@ -296,4 +305,20 @@ mod tests {
         );
         assert_eq!(test_struct.number_of_buttons(), Some(3));
     }
+
+    #[test]
+    fn test_display() {
+        let struct_type21 = vec![0x15, 0x07, 0x31, 0x00, 0x08, 0xA3, 0x00, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type21);
+        let test_struct = SMBiosBuiltInPointingDevice::new(&parts);
+
+        assert_eq!(
+            test_struct.device_type().unwrap().to_string(),
+            "TouchScreen"
+        );
+        assert_eq!(test_struct.interface().unwrap().to_string(), "I2C");
+        assert_eq!(PointingDeviceInterfaceData::from(0xA5).to_string(), "165");
+        assert_eq!(PointingDeviceTypeData::from(0x0A).to_string(), "10");
+    }
 }
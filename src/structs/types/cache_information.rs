@@ -1,4 +1,4 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{flag_set, strings::*, SMBiosVersion, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
@@ -73,44 +73,87 @@ impl<'a> SMBiosCacheInformation<'a> {
 
     /// Cache module speed, in nanoseconds.
     /// The value is 0 if the speed is unknown.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn cache_speed(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0F)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_byte(0x0F)
     }
 
     /// Error-correction scheme supported by this cache component
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn error_correction_type(&self) -> Option<ErrorCorrectionTypeData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x10)
             .map(|raw| ErrorCorrectionTypeData::from(raw))
     }
 
     /// Logical type of cache
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn system_cache_type(&self) -> Option<SystemCacheTypeData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x11)
             .map(|raw| SystemCacheTypeData::from(raw))
     }
 
     /// Associativity of the cache
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn associativity(&self) -> Option<CacheAssociativityData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x12)
             .map(|raw| CacheAssociativityData::from(raw))
     }
 
     /// Maximum cache size
+    ///
+    /// Added in SMBIOS 3.1; absent from tables of earlier versions.
     pub fn maximum_cache_size_2(&self) -> Option<CacheMemorySize> {
         self.parts
+            .since(SMBiosVersion::new(3, 1, 0))?
             .get_field_dword(0x13)
             .map(|raw| CacheMemorySize::from(raw))
     }
 
     /// Installed cache size
+    ///
+    /// Added in SMBIOS 3.1; absent from tables of earlier versions.
     pub fn installed_cache_size_2(&self) -> Option<CacheMemorySize> {
         self.parts
+            .since(SMBiosVersion::new(3, 1, 0))?
             .get_field_dword(0x17)
             .map(|raw| CacheMemorySize::from(raw))
     }
+
+    /// Maximum size that can be installed, in bytes
+    ///
+    /// Taken from [SMBiosCacheInformation::maximum_cache_size_2] when
+    /// [SMBiosCacheInformation::maximum_cache_size] is
+    /// [CacheMemorySize::SeeCacheSize2].
+    pub fn maximum_cache_size_bytes(&self) -> Option<u64> {
+        match self.maximum_cache_size()? {
+            CacheMemorySize::SeeCacheSize2 => self.maximum_cache_size_2()?.bytes(),
+            size => size.bytes(),
+        }
+    }
+
+    /// Installed size, in bytes; 0 if no cache is installed
+    ///
+    /// Taken from [SMBiosCacheInformation::installed_cache_size_2] when
+    /// [SMBiosCacheInformation::installed_size] is
+    /// [CacheMemorySize::SeeCacheSize2].
+    pub fn installed_size_bytes(&self) -> Option<u64> {
+        match self.installed_size()? {
+            CacheMemorySize::SeeCacheSize2 => self.installed_cache_size_2()?.bytes(),
+            size => size.bytes(),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosCacheInformation<'_> {
@@ -165,6 +208,16 @@ pub enum CacheMemorySize {
     SeeCacheSize2,
 }
 
+impl CacheMemorySize {
+    /// The size in bytes, `None` for [CacheMemorySize::SeeCacheSize2]
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            CacheMemorySize::Kilobytes(kilobytes) => Some(kilobytes * 1024),
+            CacheMemorySize::SeeCacheSize2 => None,
+        }
+    }
+}
+
 impl From<u16> for CacheMemorySize {
     fn from(raw: u16) -> Self {
         match raw {
@@ -222,6 +275,15 @@ impl Serialize for CacheAssociativityData {
     }
 }
 
+impl fmt::Display for CacheAssociativityData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            CacheAssociativity::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for CacheAssociativityData {
     type Target = CacheAssociativity;
 
@@ -231,7 +293,7 @@ impl Deref for CacheAssociativityData {
 }
 
 /// # Cache Associativity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CacheAssociativity {
     /// Other
     Other = 0x01,
@@ -265,6 +327,32 @@ pub enum CacheAssociativity {
     None,
 }
 
+impl CacheAssociativity {
+    /// Number of ways of a set-associative cache, 1 for direct mapped
+    ///
+    /// `None` for a fully associative cache or when the associativity is
+    /// not known.
+    pub fn ways(&self) -> Option<u8> {
+        match self {
+            CacheAssociativity::DirectMapped => Some(1),
+            CacheAssociativity::SetAssociative2Way => Some(2),
+            CacheAssociativity::SetAssociative4Way => Some(4),
+            CacheAssociativity::SetAssociative8Way => Some(8),
+            CacheAssociativity::SetAssociative12Way => Some(12),
+            CacheAssociativity::SetAssociative16Way => Some(16),
+            CacheAssociativity::SetAssociative20Way => Some(20),
+            CacheAssociativity::SetAssociative24Way => Some(24),
+            CacheAssociativity::SetAssociative32Way => Some(32),
+            CacheAssociativity::SetAssociative48Way => Some(48),
+            CacheAssociativity::SetAssociative64Way => Some(64),
+            CacheAssociativity::Other
+            | CacheAssociativity::Unknown
+            | CacheAssociativity::FullyAssociative
+            | CacheAssociativity::None => None,
+        }
+    }
+}
+
 impl From<u8> for CacheAssociativityData {
     fn from(raw: u8) -> Self {
         CacheAssociativityData {
@@ -342,7 +430,7 @@ impl Deref for SystemCacheTypeData {
 }
 
 /// # System Cache Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemCacheType {
     /// Other
     Other,
@@ -426,7 +514,7 @@ impl Deref for ErrorCorrectionTypeData {
 }
 
 /// # System Cache Error Correction Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ErrorCorrectionType {
     /// Other
     Other = 0x01,
@@ -462,7 +550,14 @@ impl From<u8> for ErrorCorrectionTypeData {
 }
 
 /// # System Cache SRAM Types
-#[derive(PartialEq, Eq)]
+///
+/// ```
+/// # use smbioslib::*;
+/// let types = SramTypes::from(0x0020);
+/// assert!(types.contains(SramTypes::SYNCHRONOUS));
+/// assert!(!types.intersects(SramTypes::BURST | SramTypes::PIPELINE_BURST));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct SramTypes {
     /// Raw value
     pub raw: u16,
@@ -483,42 +578,76 @@ impl From<u16> for SramTypes {
 }
 
 impl SramTypes {
+    /// Other
+    pub const OTHER: Self = Self { raw: 0x0001 };
+
+    /// Unknown
+    pub const UNKNOWN: Self = Self { raw: 0x0002 };
+
+    /// Non-Burst
+    pub const NON_BURST: Self = Self { raw: 0x0004 };
+
+    /// Burst
+    pub const BURST: Self = Self { raw: 0x0008 };
+
+    /// Pipeline Burst
+    pub const PIPELINE_BURST: Self = Self { raw: 0x0010 };
+
+    /// Synchronous
+    pub const SYNCHRONOUS: Self = Self { raw: 0x0020 };
+
+    /// Asynchronous
+    pub const ASYNCHRONOUS: Self = Self { raw: 0x0040 };
+
     /// Other
     pub fn other(&self) -> bool {
-        self.raw & 0x0001 == 0x0001
+        self.contains(Self::OTHER)
     }
 
     /// Unknown
     pub fn unknown(&self) -> bool {
-        self.raw & 0x0002 == 0x0002
+        self.contains(Self::UNKNOWN)
     }
 
     /// Non-Burst
     pub fn non_burst(&self) -> bool {
-        self.raw & 0x0004 == 0x0004
+        self.contains(Self::NON_BURST)
     }
 
     /// Burst
     pub fn burst(&self) -> bool {
-        self.raw & 0x0008 == 0x0008
+        self.contains(Self::BURST)
     }
 
     /// Pipeline Burst
     pub fn pipeline_burst(&self) -> bool {
-        self.raw & 0x0010 == 0x0010
+        self.contains(Self::PIPELINE_BURST)
     }
 
     /// Synchronous
     pub fn synchronous(&self) -> bool {
-        self.raw & 0x0020 == 0x0020
+        self.contains(Self::SYNCHRONOUS)
     }
 
     /// Asynchronous
     pub fn asynchronous(&self) -> bool {
-        self.raw & 0x0040 == 0x0040
+        self.contains(Self::ASYNCHRONOUS)
     }
 }
 
+flag_set!(
+    SramTypes,
+    [
+        ("Other", OTHER),
+        ("Unknown", UNKNOWN),
+        ("Non-Burst", NON_BURST),
+        ("Burst", BURST),
+        ("Pipeline Burst", PIPELINE_BURST),
+        ("Synchronous", SYNCHRONOUS),
+        ("Asynchronous", ASYNCHRONOUS),
+    ]
+);
+
 impl fmt::Debug for SramTypes {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<SramTypes>())
@@ -689,6 +818,7 @@ pub enum CacheOperationalMode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder};
 
     #[test]
     fn unit_test() {
@@ -717,6 +847,34 @@ mod tests {
         println!("{:?}", test_struct);
     }
 
+    #[test]
+    fn test_version_gating() {
+        // A 3.1 length structure with 2 GB in the Maximum Cache Size 2 field
+        let raw = StructBuilder::with_length(SMBiosCacheInformation::STRUCT_TYPE, 0x1B)
+            .word(0x07, 0xFFFF)
+            .byte(0x0F, 5)
+            .dword(0x13, 0x8000_8000)
+            .build(Handle(7));
+        let mut parts = UndefinedStruct::new(&raw);
+
+        // Fields added in 3.1 are padding in a 3.0 table
+        parts.version = Some(SMBiosVersion::new(3, 0, 0));
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert_eq!(cache.cache_speed(), Some(5));
+        assert!(cache.maximum_cache_size_2().is_none());
+        assert!(cache.installed_cache_size_2().is_none());
+
+        parts.version = Some(SMBiosVersion::new(3, 1, 0));
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert!(cache.maximum_cache_size_2().is_some());
+
+        // Fields added in 2.1 are padding in a 2.0 table
+        parts.version = Some(SMBiosVersion::new(2, 0, 0));
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert_eq!(cache.cache_speed(), None);
+        assert!(cache.associativity().is_none());
+    }
+
     #[test]
     fn memory_size_parsing_test() {
         assert_eq!(
@@ -736,4 +894,83 @@ mod tests {
             CacheMemorySize::Kilobytes(2u64.pow(37) - 64)
         );
     }
+
+    #[test]
+    fn test_size_bytes() {
+        // 512 KB with 1K granularity, 32 MB with 64K granularity
+        let raw = StructBuilder::new(SMBiosCacheInformation::STRUCT_TYPE)
+            .word(0x07, 0x8200)
+            .word(0x09, 0x0200)
+            .build(Handle(7));
+        let parts = UndefinedStruct::new(&raw);
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert_eq!(cache.maximum_cache_size_bytes(), Some(32 * 1024 * 1024));
+        assert_eq!(cache.installed_size_bytes(), Some(512 * 1024));
+
+        // 4 GB installed and 8 GB maximum through the 3.1+ size 2 fields
+        let raw = StructBuilder::new(SMBiosCacheInformation::STRUCT_TYPE)
+            .word(0x07, 0xFFFF)
+            .word(0x09, 0xFFFF)
+            .dword(0x13, 0x8002_0000)
+            .dword(0x17, 0x8001_0000)
+            .build(Handle(7));
+        let parts = UndefinedStruct::new(&raw);
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert_eq!(cache.maximum_cache_size_bytes(), Some(8 << 30));
+        assert_eq!(cache.installed_size_bytes(), Some(4 << 30));
+
+        // The size 2 fields are absent before 3.1
+        let raw = StructBuilder::new(SMBiosCacheInformation::STRUCT_TYPE)
+            .word(0x07, 0xFFFF)
+            .word(0x09, 0x0000)
+            .build(Handle(7));
+        let parts = UndefinedStruct::new(&raw);
+        let cache = SMBiosCacheInformation::new(&parts);
+        assert_eq!(cache.maximum_cache_size_bytes(), None);
+        assert_eq!(cache.installed_size_bytes(), Some(0));
+    }
+
+    #[test]
+    fn test_types_and_associativity() {
+        let raw = StructBuilder::new(SMBiosCacheInformation::STRUCT_TYPE)
+            .word(0x0B, 0x0030)
+            .word(0x0D, 0x0010)
+            .byte(0x10, 0x06)
+            .byte(0x11, 0x05)
+            .byte(0x12, 0x0E)
+            .build(Handle(7));
+        let parts = UndefinedStruct::new(&raw);
+        let cache = SMBiosCacheInformation::new(&parts);
+
+        let supported = cache.supported_sram_type().unwrap();
+        assert_eq!(
+            supported,
+            SramTypes::PIPELINE_BURST | SramTypes::SYNCHRONOUS
+        );
+        assert_eq!(
+            supported.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            vec!["Pipeline Burst", "Synchronous"]
+        );
+        let current = cache.current_sram_type().unwrap();
+        assert!(current.pipeline_burst());
+        assert!(supported.contains(current));
+        assert_eq!(supported & SramTypes::BURST, SramTypes::empty());
+
+        let error_correction = cache.error_correction_type().unwrap();
+        assert_eq!(*error_correction, ErrorCorrectionType::MultiBitEcc);
+        assert_eq!(error_correction.to_string(), "MultiBitEcc");
+
+        assert_eq!(
+            *cache.system_cache_type().unwrap(),
+            SystemCacheType::Unified
+        );
+
+        let associativity = cache.associativity().unwrap();
+        assert_eq!(*associativity, CacheAssociativity::SetAssociative20Way);
+        assert_eq!(associativity.ways(), Some(20));
+        assert_eq!(associativity.to_string(), "SetAssociative20Way");
+        assert_eq!(CacheAssociativityData::from(0x03).ways(), Some(1));
+        assert_eq!(CacheAssociativityData::from(0x06).ways(), None);
+        assert_eq!(CacheAssociativityData::from(0x20).to_string(), "32");
+    }
 }
@@ -1,5 +1,5 @@
-use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{strings::*, Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::{SMBiosStruct, SMBiosTemperatureProbe};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -35,6 +35,18 @@ impl<'a> SMBiosCoolingDevice<'a> {
         self.parts.get_field_handle(0x04)
     }
 
+    /// The [SMBiosTemperatureProbe] monitoring this cooling device, found in
+    /// `data`
+    ///
+    /// `None` when the handle is 0xFFFF or `data` has no Temperature Probe
+    /// structure with the handle.
+    pub fn temperature_probe(&self, data: &'a SMBiosData) -> Option<SMBiosTemperatureProbe<'a>> {
+        match self.temperature_probe_handle()? {
+            Handle::NONE => None,
+            handle => data.find_by_handle_as(&handle),
+        }
+    }
+
     /// Cooling device type and status.
     pub fn device_type_and_status(&self) -> Option<CoolingDeviceTypeAndStatus> {
         self.parts
@@ -73,13 +85,18 @@ impl<'a> SMBiosCoolingDevice<'a> {
     /// Additional descriptive information about the cooling device or its location
     /// This field is present in the structure only if the
     /// structure’s length is 0Fh or larger.
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn description(&self) -> SMBiosString {
-        self.parts.get_field_string(0x0E)
+        match self.parts.supports(SMBiosVersion::new(2, 7, 0)) {
+            true => self.parts.get_field_string(0x0E),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 }
 
 /// # Rotational Speed
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RotationalSpeed {
     /// Revolutions per minute (RPM)
     Rpm(u16),
@@ -96,6 +113,26 @@ impl From<u16> for RotationalSpeed {
     }
 }
 
+impl RotationalSpeed {
+    /// The speed in revolutions per minute, `None` when unknown or
+    /// non-rotating
+    pub fn rpm(&self) -> Option<u16> {
+        match self {
+            RotationalSpeed::Rpm(rpm) => Some(*rpm),
+            RotationalSpeed::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for RotationalSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RotationalSpeed::Rpm(rpm) => write!(f, "{} rpm", rpm),
+            RotationalSpeed::Unknown => write!(f, "Unknown Or Non-rotating"),
+        }
+    }
+}
+
 impl fmt::Debug for SMBiosCoolingDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<SMBiosCoolingDevice<'_>>())
@@ -128,7 +165,7 @@ impl Serialize for SMBiosCoolingDevice<'_> {
 }
 
 /// # Cooling Device Type and Status
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct CoolingDeviceTypeAndStatus {
     /// Raw value
     ///
@@ -167,7 +204,7 @@ impl Serialize for CoolingDeviceTypeAndStatus {
 }
 
 /// # Cooling Device Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CoolingDeviceStatus {
     /// Other
     Other,
@@ -186,7 +223,7 @@ pub enum CoolingDeviceStatus {
 }
 
 /// # Cooling Device Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CoolingDeviceType {
     /// Other
     Other,
@@ -260,7 +297,7 @@ mod tests {
         let parts = UndefinedStruct::new(&struct_type27);
         let test_struct = SMBiosCoolingDevice::new(&parts);
 
-        //assert_eq!(test_struct.temperature_probe_handle(), Some(Handle(42)));
+        assert_eq!(test_struct.temperature_probe_handle(), Some(Handle(42)));
 
         let device_type_and_status = test_struct.device_type_and_status().unwrap();
         assert_eq!(
@@ -282,4 +319,59 @@ mod tests {
             "Cooling Dev 1".to_string()
         );
     }
+
+    #[test]
+    fn test_temperature_probe() {
+        use crate::core::{StructBuilder, TableBuilder};
+
+        let mut builder = TableBuilder::new();
+        let probe = builder.add(
+            StructBuilder::with_length(SMBiosTemperatureProbe::STRUCT_TYPE, 0x16)
+                .string(0x04, "CPU Thermal Probe"),
+        );
+        // An SMBIOS 2.1 structure without the nominal speed and description
+        builder.add(
+            StructBuilder::with_length(SMBiosCoolingDevice::STRUCT_TYPE, 0x0C)
+                .handle(0x04, probe)
+                .byte(0x06, 0x65),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosCoolingDevice::STRUCT_TYPE, 0x0F)
+                .handle(0x04, Handle::NONE)
+                .byte(0x06, 0x71)
+                .word(0x0C, 2400)
+                .string(0x0E, "Rear Fan"),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let cooling_devices = data.collect::<SMBiosCoolingDevice<'_>>();
+        let test_struct = &cooling_devices[0];
+        assert_eq!(
+            test_struct
+                .temperature_probe(&data)
+                .unwrap()
+                .description()
+                .to_string(),
+            "CPU Thermal Probe"
+        );
+        assert_eq!(
+            test_struct.device_type_and_status().unwrap().device_type,
+            CoolingDeviceType::ChipFan
+        );
+        assert!(test_struct.nominal_speed().is_none());
+        assert!(matches!(
+            test_struct.description().err(),
+            Some(SMBiosStringError::FieldOutOfBounds)
+        ));
+
+        let test_struct = &cooling_devices[1];
+        assert!(test_struct.temperature_probe(&data).is_none());
+        assert_eq!(
+            test_struct.device_type_and_status().unwrap().device_type,
+            CoolingDeviceType::PassiveCooling
+        );
+        assert_eq!(test_struct.nominal_speed().unwrap().rpm(), Some(2400));
+        assert_eq!(test_struct.nominal_speed().unwrap().to_string(), "2400 rpm");
+        assert_eq!(test_struct.description().to_string(), "Rear Fan");
+    }
 }
@@ -1,5 +1,5 @@
-use crate::SMBiosStruct;
 use crate::{strings::*, UndefinedStruct};
+use crate::{ProbeAccuracy, ProbeStatus, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -125,7 +125,7 @@ impl Serialize for SMBiosElectricalCurrentProbe<'_> {
 }
 
 /// # Electrical Current Probe Location and Status
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct CurrentProbeLocationAndStatus {
     /// Raw value
     ///
@@ -164,26 +164,10 @@ impl Serialize for CurrentProbeLocationAndStatus {
 }
 
 /// # Electrical Current Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub enum CurrentProbeStatus {
-    /// Other
-    Other,
-    /// Unknown
-    Unknown,
-    /// OK
-    OK,
-    /// Non-critical
-    NonCritical,
-    /// Critical
-    Critical,
-    /// Non-recoverable
-    NonRecoverable,
-    /// A value unknown to this standard, check the raw value
-    None,
-}
+pub type CurrentProbeStatus = ProbeStatus;
 
 /// # Electrical Current Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CurrentProbeLocation {
     /// Other
     Other,
@@ -214,15 +198,7 @@ pub enum CurrentProbeLocation {
 impl From<u8> for CurrentProbeLocationAndStatus {
     fn from(raw: u8) -> Self {
         CurrentProbeLocationAndStatus {
-            status: match raw & 0b111_00000 {
-                0b001_00000 => CurrentProbeStatus::Other,
-                0b010_00000 => CurrentProbeStatus::Unknown,
-                0b011_00000 => CurrentProbeStatus::OK,
-                0b100_00000 => CurrentProbeStatus::NonCritical,
-                0b101_00000 => CurrentProbeStatus::Critical,
-                0b110_00000 => CurrentProbeStatus::NonRecoverable,
-                _ => CurrentProbeStatus::None,
-            },
+            status: ProbeStatus::from(raw),
             location: match raw & 0b000_11111 {
                 0b000_00001 => CurrentProbeLocation::Other,
                 0b000_00010 => CurrentProbeLocation::Unknown,
@@ -243,7 +219,7 @@ impl From<u8> for CurrentProbeLocationAndStatus {
 }
 
 /// # Probe Amperage
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProbeAmperage {
     /// Amperage in milliamps
     Milliamps(u16),
@@ -260,8 +236,30 @@ impl From<u16> for ProbeAmperage {
     }
 }
 
+impl ProbeAmperage {
+    /// The current in amps, `None` when unknown
+    ///
+    /// The milliamp word is read as two's complement, matching the voltage
+    /// and temperature probes.
+    pub fn amps(&self) -> Option<f32> {
+        match self {
+            ProbeAmperage::Milliamps(milliamps) => Some(*milliamps as i16 as f32 / 1000.0),
+            ProbeAmperage::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for ProbeAmperage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.amps() {
+            Some(amps) => write!(f, "{:.3} A", amps),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// # Current Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CurrentProbeResolution {
     /// Resolution for the probe's reading in tenths of milliamps
     TenthsOfMilliamps(u16),
@@ -278,24 +276,28 @@ impl From<u16> for CurrentProbeResolution {
     }
 }
 
-/// # Current Probe Accuracy
-#[derive(Serialize, Debug)]
-pub enum CurrentProbeAccuracy {
-    /// Accuracy for the probe's reading in 1/100th of a percent
-    OneOneHundredthPercent(u16),
-    /// Accuracy is unknown
-    Unknown,
+impl CurrentProbeResolution {
+    /// The resolution in milliamps, `None` when unknown
+    pub fn milliamps(&self) -> Option<f32> {
+        match self {
+            CurrentProbeResolution::TenthsOfMilliamps(tenths) => Some(*tenths as f32 / 10.0),
+            CurrentProbeResolution::Unknown => None,
+        }
+    }
 }
 
-impl From<u16> for CurrentProbeAccuracy {
-    fn from(raw: u16) -> Self {
-        match raw {
-            0x8000 => CurrentProbeAccuracy::Unknown,
-            _ => CurrentProbeAccuracy::OneOneHundredthPercent(raw),
+impl fmt::Display for CurrentProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.milliamps() {
+            Some(milliamps) => write!(f, "{:.1} mA", milliamps),
+            None => write!(f, "Unknown"),
         }
     }
 }
 
+/// # Current Probe Accuracy
+pub type CurrentProbeAccuracy = ProbeAccuracy;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,4 +349,29 @@ mod tests {
             ProbeAmperage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_scaling() {
+        let struct_type29 = vec![
+            0x1D, 0x16, 0x33, 0x00, 0x01, 0x6A, 0x88, 0x13, 0x00, 0x00, 0x0A, 0x00, 0x32, 0x00,
+            0x96, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC4, 0x09, b'A', b'B', b'C', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type29);
+        let test_struct = SMBiosElectricalCurrentProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(location_and_status.status, CurrentProbeStatus::OK);
+        assert_eq!(
+            location_and_status.location,
+            CurrentProbeLocation::PowerUnit
+        );
+        assert_eq!(test_struct.maximum_value().unwrap().amps(), Some(5.0));
+        assert_eq!(test_struct.minimum_value().unwrap().amps(), Some(0.0));
+        assert_eq!(test_struct.resolution().unwrap().milliamps(), Some(1.0));
+        assert_eq!(test_struct.tolerance().unwrap().to_string(), "0.050 A");
+        assert_eq!(test_struct.accuracy().unwrap().to_string(), "1.50%");
+        assert_eq!(test_struct.nominal_value().unwrap().to_string(), "2.500 A");
+        assert_eq!(ProbeAmperage::from(0x8000).to_string(), "Unknown");
+    }
 }
@@ -167,7 +167,7 @@ impl Serialize for SMBiosFirmwareInventoryInformation<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosFirmwareInventoryInformation", 12)?;
+        let mut state = serializer.serialize_struct("SMBiosFirmwareInventoryInformation", 14)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("firmware_component_name", &self.firmware_component_name())?;
         state.serialize_field("firmware_version", &self.firmware_version())?;
@@ -453,7 +453,7 @@ impl Serialize for FirmwareInventoryCharacteristics {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("FirmwareInventoryCharacteristics", 6)?;
+        let mut state = serializer.serialize_struct("FirmwareInventoryCharacteristics", 3)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field("updatable", &self.updatable())?;
         state.serialize_field("write_protect", &self.write_protect())?;
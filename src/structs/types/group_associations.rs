@@ -1,5 +1,5 @@
-use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{strings::*, Handle, SMBiosData, UndefinedStruct};
+use crate::{DefinedStruct, SMBiosStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -52,9 +52,17 @@ impl<'a> SMBiosGroupAssociations<'a> {
     }
 
     /// Iterates over the [GroupAssociationItem] entries
-    pub fn item_iterator(&'a self) -> GroupAssociationItemIterator<'a> {
+    pub fn item_iterator(&self) -> GroupAssociationItemIterator<'a> {
         GroupAssociationItemIterator::new(self)
     }
+
+    /// Resolves each [GroupAssociationItem] to the structure it references
+    ///
+    /// Items which cannot be resolved (see [GroupAssociationItem::resolve]) are skipped.
+    pub fn members(&self, data: &'a SMBiosData) -> impl Iterator<Item = DefinedStruct<'a>> + 'a {
+        self.item_iterator()
+            .filter_map(move |item| item.resolve(data))
+    }
 }
 
 impl fmt::Debug for SMBiosGroupAssociations<'_> {
@@ -84,7 +92,7 @@ impl Serialize for SMBiosGroupAssociations<'_> {
 
 /// # Group Association Item contained within [SMBiosGroupAssociations]
 pub struct GroupAssociationItem<'a> {
-    group_associations: &'a SMBiosGroupAssociations<'a>,
+    parts: &'a UndefinedStruct,
     entry_offset: usize,
 }
 
@@ -92,9 +100,9 @@ impl<'a> GroupAssociationItem<'a> {
     /// Size in bytes of a GroupAssociationItem
     const SIZE: usize = 3usize;
 
-    fn new(group_associations: &'a SMBiosGroupAssociations<'a>, entry_offset: usize) -> Self {
+    fn new(parts: &'a UndefinedStruct, entry_offset: usize) -> Self {
         Self {
-            group_associations,
+            parts,
             entry_offset,
         }
     }
@@ -103,18 +111,26 @@ impl<'a> GroupAssociationItem<'a> {
     ///
     /// Item (Structure) Type of this member
     pub fn struct_type(&self) -> Option<u8> {
-        self.group_associations
-            .parts()
-            .get_field_byte(self.entry_offset)
+        self.parts.get_field_byte(self.entry_offset)
     }
 
     /// Item Handle
     ///
     /// Handle corresponding to this structure
     pub fn item_handle(&self) -> Option<Handle> {
-        self.group_associations
-            .parts()
-            .get_field_handle(self.entry_offset + 1)
+        self.parts.get_field_handle(self.entry_offset + 1)
+    }
+
+    /// Finds the structure this item references within `data`
+    ///
+    /// Returns `None` if no structure has the [item_handle](Self::item_handle)
+    /// or if the structure found is not of the item's [struct_type](Self::struct_type).
+    pub fn resolve(&self, data: &'a SMBiosData) -> Option<DefinedStruct<'a>> {
+        let struct_type = self.struct_type()?;
+        let handle = self.item_handle()?;
+        data.find_by_handle(&handle)
+            .filter(|undefined_struct| undefined_struct.header.struct_type() == struct_type)
+            .map(|undefined_struct| undefined_struct.defined_struct())
     }
 }
 
@@ -141,7 +157,7 @@ impl Serialize for GroupAssociationItem<'_> {
 
 /// Iterates over the [GroupAssociationItem] entries contained within [SMBiosGroupAssociations]
 pub struct GroupAssociationItemIterator<'a> {
-    data: &'a SMBiosGroupAssociations<'a>,
+    parts: &'a UndefinedStruct,
     current_index: usize,
     current_entry: usize,
     number_of_entries: usize,
@@ -150,9 +166,9 @@ pub struct GroupAssociationItemIterator<'a> {
 impl<'a> GroupAssociationItemIterator<'a> {
     const ITEMS_OFFSET: usize = 5usize;
 
-    fn new(data: &'a SMBiosGroupAssociations<'a>) -> Self {
+    fn new(data: &SMBiosGroupAssociations<'a>) -> Self {
         GroupAssociationItemIterator {
-            data: data,
+            parts: data.parts(),
             current_index: Self::ITEMS_OFFSET,
             current_entry: 0,
             number_of_entries: data.number_of_items().unwrap_or(0),
@@ -171,10 +187,10 @@ impl<'a> IntoIterator for &'a GroupAssociationItemIterator<'a> {
 
     fn into_iter(self) -> Self::IntoIter {
         GroupAssociationItemIterator {
-            data: self.data,
+            parts: self.parts,
             current_index: GroupAssociationItemIterator::ITEMS_OFFSET,
             current_entry: 0,
-            number_of_entries: self.data.number_of_items().unwrap_or(0),
+            number_of_entries: self.number_of_entries,
         }
     }
 }
@@ -189,13 +205,9 @@ impl<'a> Iterator for GroupAssociationItemIterator<'a> {
         }
 
         let next_index = self.current_index + GroupAssociationItem::SIZE;
-        match self
-            .data
-            .parts()
-            .get_field_data(self.current_index, next_index)
-        {
+        match self.parts.get_field_data(self.current_index, next_index) {
             Some(_entry_block) => {
-                let result = GroupAssociationItem::new(self.data, self.current_index);
+                let result = GroupAssociationItem::new(self.parts, self.current_index);
                 self.current_index = next_index;
                 self.current_entry += 1;
                 Some(result)
@@ -231,6 +243,8 @@ impl<'a> Serialize for GroupAssociationItemIterator<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
+    use crate::{SMBiosInformation, SMBiosSystemInformation};
 
     #[test]
     fn unit_test() {
@@ -254,4 +268,55 @@ mod tests {
         assert_eq!(first_item.struct_type(), Some(221));
         assert_eq!(*first_item.item_handle().unwrap(), 91);
     }
+
+    #[test]
+    fn test_resolve_members() {
+        let mut builder = TableBuilder::new();
+        let node_a = builder.add(
+            StructBuilder::with_length(SMBiosSystemInformation::STRUCT_TYPE, 0x1B)
+                .string(0x05, "Node A"),
+        );
+        let node_b = builder.add(
+            StructBuilder::with_length(SMBiosSystemInformation::STRUCT_TYPE, 0x1B)
+                .string(0x05, "Node B"),
+        );
+        builder.add(
+            StructBuilder::new(SMBiosGroupAssociations::STRUCT_TYPE)
+                .string(0x04, "Cluster")
+                .byte(0x05, SMBiosSystemInformation::STRUCT_TYPE)
+                .handle(0x06, node_a)
+                .byte(0x08, SMBiosSystemInformation::STRUCT_TYPE)
+                .handle(0x09, node_b)
+                // Wrong type for the referenced handle
+                .byte(0x0B, SMBiosInformation::STRUCT_TYPE)
+                .handle(0x0C, node_a)
+                // Dangling handle
+                .byte(0x0E, SMBiosSystemInformation::STRUCT_TYPE)
+                .handle(0x0F, Handle(0x1234)),
+        );
+        let data = builder.build_smbios_data(None);
+
+        // Items are reachable from a temporary structure
+        let items: Vec<GroupAssociationItem<'_>> = data
+            .first::<SMBiosGroupAssociations<'_>>()
+            .unwrap()
+            .item_iterator()
+            .collect();
+        assert_eq!(items.len(), 4);
+        assert_eq!(items[1].item_handle(), Some(node_b));
+        assert!(items[2].resolve(&data).is_none());
+        assert!(items[3].resolve(&data).is_none());
+
+        let group = data.first::<SMBiosGroupAssociations<'_>>().unwrap();
+        assert_eq!(group.group_name().to_string(), "Cluster");
+
+        let products: Vec<String> = group
+            .members(&data)
+            .map(|member| match member {
+                DefinedStruct::SystemInformation(system) => system.product_name().to_string(),
+                _ => panic!("expected system information"),
+            })
+            .collect();
+        assert_eq!(products, vec!["Node A", "Node B"]);
+    }
 }
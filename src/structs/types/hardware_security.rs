@@ -62,7 +62,7 @@ impl Serialize for SMBiosHardwareSecurity<'_> {
 }
 
 /// # Hardware Security Settings
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct HardwareSecuritySettings {
     /// Raw value
     pub raw: u8,
@@ -110,7 +110,7 @@ impl Serialize for HardwareSecuritySettings {
 }
 
 /// # Hardware Security Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HardwareSecurityStatus {
     /// Disabled
     Disabled,
@@ -122,41 +122,41 @@ pub enum HardwareSecurityStatus {
     Unknown,
 }
 
+impl fmt::Display for HardwareSecurityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardwareSecurityStatus::Disabled => write!(f, "Disabled"),
+            HardwareSecurityStatus::Enabled => write!(f, "Enabled"),
+            HardwareSecurityStatus::NotImplemented => write!(f, "Not Implemented"),
+            HardwareSecurityStatus::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Bits 1:0 hold the status
+impl From<u8> for HardwareSecurityStatus {
+    fn from(raw: u8) -> Self {
+        match raw & 0b11 {
+            0b00 => HardwareSecurityStatus::Disabled,
+            0b01 => HardwareSecurityStatus::Enabled,
+            0b10 => HardwareSecurityStatus::NotImplemented,
+            _ => HardwareSecurityStatus::Unknown,
+        }
+    }
+}
+
 impl From<u8> for HardwareSecuritySettings {
     fn from(raw: u8) -> Self {
         HardwareSecuritySettings {
-            power_on_password_status: match raw & 0b11_000000 {
-                0b00_000000 => HardwareSecurityStatus::Disabled,
-                0b01_000000 => HardwareSecurityStatus::Enabled,
-                0b10_000000 => HardwareSecurityStatus::NotImplemented,
-                0b11_000000 => HardwareSecurityStatus::Unknown,
-                _ => panic!("Impossible value"),
-            },
-            keyboard_password_status: match raw & 0b00_11_0000 {
-                0b00_00_0000 => HardwareSecurityStatus::Disabled,
-                0b00_01_0000 => HardwareSecurityStatus::Enabled,
-                0b00_10_0000 => HardwareSecurityStatus::NotImplemented,
-                0b00_11_0000 => HardwareSecurityStatus::Unknown,
-                _ => panic!("Impossible value"),
-            },
-            administrator_password_status: match raw & 0b0000_11_00 {
-                0b0000_00_00 => HardwareSecurityStatus::Disabled,
-                0b0000_01_00 => HardwareSecurityStatus::Enabled,
-                0b0000_10_00 => HardwareSecurityStatus::NotImplemented,
-                0b0000_11_00 => HardwareSecurityStatus::Unknown,
-                _ => panic!("Impossible value"),
-            },
-            front_panel_reset_status: match raw & 0b000000_11 {
-                0b000000_00 => HardwareSecurityStatus::Disabled,
-                0b000000_01 => HardwareSecurityStatus::Enabled,
-                0b000000_10 => HardwareSecurityStatus::NotImplemented,
-                0b000000_11 => HardwareSecurityStatus::Unknown,
-                _ => panic!("Impossible value"),
-            },
+            power_on_password_status: HardwareSecurityStatus::from(raw >> 6),
+            keyboard_password_status: HardwareSecurityStatus::from(raw >> 4),
+            administrator_password_status: HardwareSecurityStatus::from(raw >> 2),
+            front_panel_reset_status: HardwareSecurityStatus::from(raw),
             raw,
         }
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,5 +172,35 @@ mod tests {
             test_struct.hardware_security_settings(),
             Some(HardwareSecuritySettings::from(22))
         );
+
+        let settings = test_struct.hardware_security_settings().unwrap();
+        assert_eq!(
+            settings.power_on_password_status,
+            HardwareSecurityStatus::Disabled
+        );
+        assert_eq!(
+            settings.keyboard_password_status,
+            HardwareSecurityStatus::Enabled
+        );
+        assert_eq!(
+            settings.administrator_password_status,
+            HardwareSecurityStatus::Enabled
+        );
+        assert_eq!(
+            settings.front_panel_reset_status,
+            HardwareSecurityStatus::NotImplemented
+        );
+
+        let settings = HardwareSecuritySettings::from(0b11_10_01_00);
+        assert_eq!(settings.power_on_password_status.to_string(), "Unknown");
+        assert_eq!(
+            settings.keyboard_password_status.to_string(),
+            "Not Implemented"
+        );
+        assert_eq!(
+            settings.administrator_password_status.to_string(),
+            "Enabled"
+        );
+        assert_eq!(settings.front_panel_reset_status.to_string(), "Disabled");
     }
 }
@@ -100,5 +100,18 @@ mod tests {
         assert_eq!(*test_struct.management_device_handle().unwrap(), 38);
         assert_eq!(*test_struct.component_handle().unwrap(), 39);
         assert_eq!(*test_struct.threshold_handle().unwrap(), 40);
+        assert_eq!(test_struct.threshold_handle().unwrap(), 40u16);
+        assert!(!test_struct.threshold_handle().unwrap().is_unassigned());
+
+        // No threshold data
+        let mut struct_type35 = struct_type35;
+        struct_type35[0x09] = 0xFF;
+        struct_type35[0x0A] = 0xFF;
+        let parts = UndefinedStruct::new(&struct_type35);
+        let test_struct = SMBiosManagementDeviceComponent::new(&parts);
+        assert_eq!(test_struct.threshold_handle(), Some(Handle::NONE));
+        assert!(test_struct.threshold_handle().unwrap().is_unassigned());
+        assert_eq!(Handle::from(0xFFFE), Handle::NOT_PROVIDED);
+        assert_eq!(u16::from(Handle::NONE), 0xFFFF);
     }
 }
@@ -1,7 +1,8 @@
-use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::{SMBiosPhysicalMemoryArray, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
+use std::ops::Range;
 
 /// # Memory Array Mapped Address (Type 19)
 ///
@@ -79,8 +80,12 @@ impl<'a> SMBiosMemoryArrayMappedAddress<'a> {
     /// contains zeros. When this field contains a valid
     /// address, Extended Ending Address must also
     /// contain a valid address.
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn extended_starting_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0xF)
+        self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
+            .get_field_qword(0xF)
     }
 
     /// Physical ending address, in bytes, of the last of a
@@ -92,8 +97,56 @@ impl<'a> SMBiosMemoryArrayMappedAddress<'a> {
     /// FFFFh, this field contains zeros. When this field
     /// contains a valid address, Extended Starting
     /// Address must also contain a valid address.
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn extended_ending_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x17)
+        self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
+            .get_field_qword(0x17)
+    }
+
+    /// Physical starting address in bytes, from
+    /// [SMBiosMemoryArrayMappedAddress::starting_address] or, when it is
+    /// FFFF FFFFh, [SMBiosMemoryArrayMappedAddress::extended_starting_address]
+    pub fn starting_address_bytes(&self) -> Option<u64> {
+        self.mapped_address().starting_bytes()
+    }
+
+    /// Physical address of the last byte of the range, from
+    /// [SMBiosMemoryArrayMappedAddress::ending_address] or, when both
+    /// Starting and Ending Address are FFFF FFFFh,
+    /// [SMBiosMemoryArrayMappedAddress::extended_ending_address]
+    pub fn ending_address_bytes(&self) -> Option<u64> {
+        self.mapped_address().ending_bytes()
+    }
+
+    /// The mapped range of physical addresses in bytes
+    ///
+    /// `None` when the addresses are absent or the ending address is below
+    /// the starting address.
+    pub fn address_range(&self) -> Option<Range<u64>> {
+        self.mapped_address().range()
+    }
+
+    /// The [SMBiosPhysicalMemoryArray] to which this range is mapped, found
+    /// in `data`
+    ///
+    /// `None` when `data` has no Physical Memory Array structure with the
+    /// handle.
+    pub fn physical_memory_array(
+        &self,
+        data: &'a SMBiosData,
+    ) -> Option<SMBiosPhysicalMemoryArray<'a>> {
+        data.find_by_handle_as(&self.physical_memory_array_handle()?)
+    }
+
+    fn mapped_address(&self) -> MappedAddress {
+        MappedAddress {
+            starting: self.starting_address(),
+            ending: self.ending_address(),
+            extended_starting: self.extended_starting_address(),
+            extended_ending: self.extended_ending_address(),
+        }
     }
 }
 
@@ -140,6 +193,49 @@ impl Serialize for SMBiosMemoryArrayMappedAddress<'_> {
     }
 }
 
+/// The address fields shared by the Memory Array Mapped Address (Type 19)
+/// and Memory Device Mapped Address (Type 20) structures
+///
+/// The legacy addresses are in kilobytes; when they are FFFF FFFFh the
+/// extended addresses hold the range in bytes.
+pub(crate) struct MappedAddress {
+    pub(crate) starting: Option<u32>,
+    pub(crate) ending: Option<u32>,
+    pub(crate) extended_starting: Option<u64>,
+    pub(crate) extended_ending: Option<u64>,
+}
+
+impl MappedAddress {
+    const USE_EXTENDED: u32 = 0xFFFF_FFFF;
+
+    /// Physical starting address in bytes
+    pub(crate) fn starting_bytes(&self) -> Option<u64> {
+        match self.starting? {
+            Self::USE_EXTENDED => self.extended_starting,
+            kilobytes => Some(kilobytes as u64 * 1024),
+        }
+    }
+
+    /// Physical address of the last byte of the range
+    pub(crate) fn ending_bytes(&self) -> Option<u64> {
+        match (self.starting?, self.ending?) {
+            (Self::USE_EXTENDED, Self::USE_EXTENDED) => self.extended_ending,
+            (_, kilobytes) => Some(kilobytes as u64 * 1024 + 1023),
+        }
+    }
+
+    /// The mapped range in bytes, `None` when the ending address is below
+    /// the starting address
+    pub(crate) fn range(&self) -> Option<Range<u64>> {
+        let start = self.starting_bytes()?;
+        let end = self.ending_bytes()?.checked_add(1)?;
+        if end < start {
+            return None;
+        }
+        Some(start..end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,5 +257,86 @@ mod tests {
         assert_eq!(test_struct.partition_width(), Some(4));
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+        assert_eq!(
+            test_struct.address_range(),
+            Some(0..16 * 1024 * 1024 * 1024)
+        );
+    }
+
+    #[test]
+    fn test_version_gating() {
+        let struct_type19 = vec![
+            0x13, 0x1F, 0x3F, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3E, 0x00,
+            0x04, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB,
+            0xBB, 0xBB, 0xBB, 0x00, 0x00,
+        ];
+        let mut parts = UndefinedStruct::new(&struct_type19);
+
+        // The extended addresses were added in 2.7
+        parts.version = Some(SMBiosVersion::new(2, 6, 0));
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+        assert_eq!(test_struct.extended_starting_address(), None);
+        assert_eq!(test_struct.extended_ending_address(), None);
+
+        parts.version = Some(SMBiosVersion::new(2, 7, 0));
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+        assert_eq!(
+            test_struct.extended_starting_address(),
+            Some(0xAAAA_AAAA_AAAA_AAAA)
+        );
+        assert_eq!(
+            test_struct.extended_ending_address(),
+            Some(0xBBBB_BBBB_BBBB_BBBB)
+        );
+    }
+
+    #[test]
+    fn test_extended_address() {
+        use crate::core::{StructBuilder, TableBuilder};
+
+        let mut builder = TableBuilder::new();
+        let array = builder.add(
+            StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x17)
+                .byte(0x04, 0x03),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryArrayMappedAddress::STRUCT_TYPE, 0x1F)
+                .dword(0x04, 0xFFFF_FFFF)
+                .dword(0x08, 0xFFFF_FFFF)
+                .handle(0x0C, array)
+                .byte(0x0E, 2)
+                .qword(0x0F, 0x1_0000_0000)
+                .qword(0x17, 0x20_7FFF_FFFF),
+        );
+        // An SMBIOS 2.1 structure without the extended addresses
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryArrayMappedAddress::STRUCT_TYPE, 0x0F)
+                .dword(0x04, 0xFFFF_FFFF)
+                .dword(0x08, 0xFFFF_FFFF)
+                .handle(0x0C, Handle(0x4242)),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let mapped_addresses = data.collect::<SMBiosMemoryArrayMappedAddress<'_>>();
+        let test_struct = &mapped_addresses[0];
+        assert_eq!(test_struct.starting_address_bytes(), Some(0x1_0000_0000));
+        assert_eq!(test_struct.ending_address_bytes(), Some(0x20_7FFF_FFFF));
+        assert_eq!(
+            test_struct.address_range(),
+            Some(0x1_0000_0000..0x20_8000_0000)
+        );
+        assert_eq!(
+            test_struct
+                .physical_memory_array(&data)
+                .unwrap()
+                .parts()
+                .header
+                .handle(),
+            array
+        );
+
+        let test_struct = &mapped_addresses[1];
+        assert!(test_struct.address_range().is_none());
+        assert!(test_struct.physical_memory_array(&data).is_none());
     }
 }
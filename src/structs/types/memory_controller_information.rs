@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, SMBiosVersion, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
@@ -69,6 +69,21 @@ impl<'a> SMBiosMemoryControllerInformation<'a> {
         self.parts.get_field_byte(0x08)
     }
 
+    /// Size of the largest memory module supported (per slot), in MB
+    ///
+    /// `None` when the field is absent or the size does not fit a u64.
+    pub fn maximum_memory_module_size_mb(&self) -> Option<u64> {
+        1u64.checked_shl(self.maximum_memory_module_size()? as u32)
+    }
+
+    /// Maximum amount of memory supported by this controller, in MB
+    ///
+    /// The maximum module size times the number of associated memory slots.
+    pub fn maximum_total_memory_size_mb(&self) -> Option<u64> {
+        self.maximum_memory_module_size_mb()?
+            .checked_mul(self.number_of_associated_memory_slots()? as u64)
+    }
+
     /// Supported Speeds
     pub fn supported_speeds(&self) -> Option<MemorySpeeds> {
         self.parts
@@ -100,6 +115,21 @@ impl<'a> SMBiosMemoryControllerInformation<'a> {
         ModuleHandleIterator::new(self)
     }
 
+    /// Enabled Error Correcting Capabilities (version 2.1+)
+    ///
+    /// The error correcting capability enabled, found following the memory
+    /// module configuration handles.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
+    pub fn enabled_error_correcting_capabilities(&self) -> Option<ErrorCorrectingCapabilities> {
+        let offset = ModuleHandleIterator::MODULE_HANDLES_OFFSET
+            + self.number_of_associated_memory_slots()? as usize * Handle::SIZE;
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_byte(offset)
+            .map(ErrorCorrectingCapabilities::from)
+    }
+
     /// Memory Moduel Error Correcting Capabilities
     pub fn error_correcting_capabilities_iterator(&self) -> ErrorCapabilitiesIterator<'_> {
         ErrorCapabilitiesIterator::new(self)
@@ -935,6 +965,13 @@ mod tests {
 
         assert_eq!(test_struct.number_of_associated_memory_slots(), Some(2));
 
+        assert_eq!(test_struct.maximum_memory_module_size_mb(), Some(4));
+        assert_eq!(test_struct.maximum_total_memory_size_mb(), Some(8));
+        assert!(test_struct
+            .enabled_error_correcting_capabilities()
+            .unwrap()
+            .single_bit_error_correcting());
+
         let mut iterator = test_struct
             .error_correcting_capabilities_iterator()
             .into_iter();
@@ -1,5 +1,7 @@
-use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{
+    flag_set, strings::*, Handle, SMBiosData, SMBiosVersion, StructBuilder, UndefinedStruct,
+};
+use crate::{MemoryErrorInformation, SMBiosPhysicalMemoryArray, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -34,6 +36,18 @@ impl<'a> SMBiosMemoryDevice<'a> {
         self.parts.get_field_handle(0x04)
     }
 
+    /// The [SMBiosPhysicalMemoryArray] to which this device belongs, found in
+    /// `data`
+    ///
+    /// `None` when `data` has no Physical Memory Array structure with the
+    /// handle.
+    pub fn physical_memory_array(
+        &self,
+        data: &'a SMBiosData,
+    ) -> Option<SMBiosPhysicalMemoryArray<'a>> {
+        data.find_by_handle_as(&self.physical_memory_array_handle()?)
+    }
+
     /// Handle, or instance number, associated with any
     /// error that was previously detected for the device
     /// If the system does not provide the error information
@@ -46,6 +60,14 @@ impl<'a> SMBiosMemoryDevice<'a> {
         self.parts.get_field_handle(0x06)
     }
 
+    /// [SMBiosMemoryDevice::memory_error_information_handle] resolved within `data`
+    pub fn memory_error_information(
+        &self,
+        data: &'a SMBiosData,
+    ) -> Option<MemoryErrorInformation<'a>> {
+        MemoryErrorInformation::resolve(self.memory_error_information_handle()?, data)
+    }
+
     /// Total width, in bits, of this memory device, including
     /// any check or error-correction bits
     /// If there are no error-correction bits, this value
@@ -121,51 +143,122 @@ impl<'a> SMBiosMemoryDevice<'a> {
 
     /// The maximum capable speed of the
     /// device, in megatransfers per second (MT/s).
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn speed(&self) -> Option<MemorySpeed> {
         self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
             .get_field_word(0x15)
             .map(|raw| MemorySpeed::from(raw))
     }
 
+    /// Maximum capable speed of the memory device, combining
+    /// [SMBiosMemoryDevice::speed] and [SMBiosMemoryDevice::extended_speed]
+    ///
+    /// A speed of FFFFh whose Extended Speed is absent or zero is reported as
+    /// [MemoryDeviceSpeed::Unknown].
+    pub fn speed_mts(&self) -> Option<MemoryDeviceSpeed> {
+        self.speed()
+            .map(|speed| MemoryDeviceSpeed::new(speed, self.extended_speed()))
+    }
+
     /// The manufacturer of this memory device
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn manufacturer(&self) -> SMBiosString {
-        self.parts.get_field_string(0x17)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x17),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// The serial number of this memory device.
     /// This value is set by the manufacturer and normally
     /// is not changeable.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn serial_number(&self) -> SMBiosString {
-        self.parts.get_field_string(0x18)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x18),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// The asset tag of this memory device
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn asset_tag(&self) -> SMBiosString {
-        self.parts.get_field_string(0x19)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x19),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// The part number of this memory device.
     /// This value is set by the manufacturer and normally
     /// is not changeable.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn part_number(&self) -> SMBiosString {
-        self.parts.get_field_string(0x1A)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x1A),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// Bits 7-4: reserved
     /// Bits 3-0: rank
     /// Value=0 for unknown rank information
+    ///
+    /// Added in SMBIOS 2.6; absent from tables of earlier versions.
     pub fn attributes(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x1B)
+        self.parts
+            .since(SMBiosVersion::new(2, 6, 0))?
+            .get_field_byte(0x1B)
+    }
+
+    /// The rank of this memory device, from bits 3-0 of
+    /// [SMBiosMemoryDevice::attributes]
+    ///
+    /// `None` when the attributes are absent or the rank is unknown.
+    pub fn rank(&self) -> Option<u8> {
+        match self.attributes()? & 0x0F {
+            0 => None,
+            rank => Some(rank),
+        }
     }
 
     /// Extended size of the memory device in MB (complements
     /// the Size field at offset 0Ch)
     pub fn extended_size(&self) -> Option<MemorySizeExtended> {
         self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
             .get_field_dword(0x1C)
             .map(|raw| MemorySizeExtended::from(raw))
     }
 
+    /// Size of the memory device, combining [SMBiosMemoryDevice::size] and
+    /// [SMBiosMemoryDevice::extended_size]
+    ///
+    /// A size of 7FFFh whose Extended Size is absent or zero is reported as
+    /// [MemoryDeviceSize::Unknown].
+    pub fn size_bytes(&self) -> Option<MemoryDeviceSize> {
+        Some(match self.size()? {
+            MemorySize::NotInstalled => MemoryDeviceSize::NotInstalled,
+            MemorySize::Unknown => MemoryDeviceSize::Unknown,
+            MemorySize::Kilobytes(kilobytes) => MemoryDeviceSize::Bytes(kilobytes as u64 * 1024),
+            MemorySize::Megabytes(megabytes) => {
+                MemoryDeviceSize::Bytes(megabytes as u64 * 1024 * 1024)
+            }
+            MemorySize::SeeExtendedSize => match self.extended_size() {
+                Some(MemorySizeExtended::Megabytes(megabytes)) => {
+                    MemoryDeviceSize::Bytes(megabytes as u64 * 1024 * 1024)
+                }
+                _ => MemoryDeviceSize::Unknown,
+            },
+        })
+    }
+
     /// Identifies the configured speed of the memory
     /// device, in megatransfers per second (MT/s). See
     /// 7.18.4 for details.
@@ -175,33 +268,67 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// Configured Memory Speed field
     pub fn configured_memory_speed(&self) -> Option<MemorySpeed> {
         self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
             .get_field_word(0x20)
             .map(|raw| MemorySpeed::from(raw))
     }
 
+    /// Configured speed of the memory device, combining
+    /// [SMBiosMemoryDevice::configured_memory_speed] and
+    /// [SMBiosMemoryDevice::extended_configured_memory_speed]
+    ///
+    /// A speed of FFFFh whose Extended Configured Memory Speed is absent or
+    /// zero is reported as [MemoryDeviceSpeed::Unknown].
+    pub fn configured_memory_speed_mts(&self) -> Option<MemoryDeviceSpeed> {
+        self.configured_memory_speed()
+            .map(|speed| MemoryDeviceSpeed::new(speed, self.extended_configured_memory_speed()))
+    }
+
     /// Minimum operating voltage for this device, in
     /// millivolts
     /// If the value is 0, the voltage is unknown.
     pub fn minimum_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x22)
+        self.parts
+            .since(SMBiosVersion::new(2, 8, 0))?
+            .get_field_word(0x22)
     }
 
     /// Maximum operating voltage for this device, in
     /// millivolts
     /// If the value is 0, the voltage is unknown.
     pub fn maximum_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x24)
+        self.parts
+            .since(SMBiosVersion::new(2, 8, 0))?
+            .get_field_word(0x24)
     }
 
     /// Configured voltage for this device, in millivolts
     /// If the value is 0, the voltage is unknown.
     pub fn configured_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x26)
+        self.parts
+            .since(SMBiosVersion::new(2, 8, 0))?
+            .get_field_word(0x26)
+    }
+
+    /// [SMBiosMemoryDevice::minimum_voltage] with 0 decoded as unknown
+    pub fn minimum_voltage_decoded(&self) -> Option<MemoryDeviceVoltage> {
+        self.minimum_voltage().map(MemoryDeviceVoltage::from)
+    }
+
+    /// [SMBiosMemoryDevice::maximum_voltage] with 0 decoded as unknown
+    pub fn maximum_voltage_decoded(&self) -> Option<MemoryDeviceVoltage> {
+        self.maximum_voltage().map(MemoryDeviceVoltage::from)
+    }
+
+    /// [SMBiosMemoryDevice::configured_voltage] with 0 decoded as unknown
+    pub fn configured_voltage_decoded(&self) -> Option<MemoryDeviceVoltage> {
+        self.configured_voltage().map(MemoryDeviceVoltage::from)
     }
 
     /// Memory technology type for this memory device.
     pub fn memory_technology(&self) -> Option<MemoryDeviceTechnologyData> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_byte(0x28)
             .map(|raw| MemoryDeviceTechnologyData::from(raw))
     }
@@ -209,6 +336,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// The operating modes supported by this memory device.
     pub fn memory_operating_mode_capability(&self) -> Option<MemoryOperatingModeCapabilities> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_word(0x29)
             .map(|raw| MemoryOperatingModeCapabilities::from(raw))
     }
@@ -220,22 +348,42 @@ impl<'a> SMBiosMemoryDevice<'a> {
 
     /// The two-byte module manufacturer ID found in the SPD of this memory device; LSB first.
     pub fn module_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x2C)
+        self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
+            .get_field_word(0x2C)
+    }
+
+    /// The module manufacturer ID decoded as a JEDEC JEP-106 identifier.
+    pub fn module_manufacturer(&self) -> Option<JedecManufacturerId> {
+        self.module_manufacturer_id().map(JedecManufacturerId::from)
     }
 
     /// The two-byte module product ID found in the SPD of this memory device; LSB first.
     pub fn module_product_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x2E)
+        self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
+            .get_field_word(0x2E)
     }
 
     /// The two-byte memory subsystem controller manufacturer ID found in the SPD of this memory device; LSB first.
     pub fn memory_subsystem_controller_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x30)
+        self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
+            .get_field_word(0x30)
+    }
+
+    /// The memory subsystem controller manufacturer ID decoded as a JEDEC
+    /// JEP-106 identifier.
+    pub fn memory_subsystem_controller_manufacturer(&self) -> Option<JedecManufacturerId> {
+        self.memory_subsystem_controller_manufacturer_id()
+            .map(JedecManufacturerId::from)
     }
 
     /// The two-byte memory subsystem controller product ID found in the SPD of this memory device; LSB first.
     pub fn memory_subsystem_controller_product_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x32)
+        self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
+            .get_field_word(0x32)
     }
 
     /// Size of the Non-volatile portion of the memory
@@ -245,6 +393,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// non-volatile portion.
     pub fn non_volatile_size(&self) -> Option<MemoryIndicatedSize> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_qword(0x34)
             .map(|raw| MemoryIndicatedSize::from(raw))
     }
@@ -256,6 +405,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// portion.
     pub fn volatile_size(&self) -> Option<MemoryIndicatedSize> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_qword(0x3C)
             .map(|raw| MemoryIndicatedSize::from(raw))
     }
@@ -267,6 +417,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// portion.
     pub fn cache_size(&self) -> Option<MemoryIndicatedSize> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_qword(0x44)
             .map(|raw| MemoryIndicatedSize::from(raw))
     }
@@ -274,6 +425,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// Size of the Logical memory device in Bytes.
     pub fn logical_size(&self) -> Option<MemoryIndicatedSize> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_qword(0x4C)
             .map(|raw| MemoryIndicatedSize::from(raw))
     }
@@ -284,6 +436,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// device, in megatransfers per second (MT/s).
     pub fn extended_speed(&self) -> Option<MemorySpeedExtended> {
         self.parts
+            .since(SMBiosVersion::new(3, 3, 0))?
             .get_field_dword(0x54)
             .map(|raw| MemorySpeedExtended::from(raw))
     }
@@ -308,6 +461,7 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// Bits 30:0 represent the speed or configured memory speed of the device in MT/s.
     pub fn extended_configured_memory_speed(&self) -> Option<MemorySpeedExtended> {
         self.parts
+            .since(SMBiosVersion::new(3, 3, 0))?
             .get_field_dword(0x58)
             .map(|raw| MemorySpeedExtended::from(raw))
     }
@@ -324,7 +478,14 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// first byte correspond to the value in byte 198 and the second byte corresponds to the value in byte 199.
     /// If SPD doesn't contain Register Revision Number, this field shall be set to 0000h
     pub fn pmic0_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x5C)
+        self.parts
+            .since(SMBiosVersion::new(3, 7, 0))?
+            .get_field_word(0x5C)
+    }
+
+    /// The PMIC 0 manufacturer ID decoded as a JEDEC JEP-106 identifier.
+    pub fn pmic0_manufacturer(&self) -> Option<JedecManufacturerId> {
+        self.pmic0_manufacturer_id().map(JedecManufacturerId::from)
     }
 
     /// The PMIC 0 Revision Number found in the SPD of
@@ -339,7 +500,9 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// correspond to the value in byte 201 and the second byte shall be set to 00h. If SPD doesn't contain
     /// Register Revision Number, this field shall be set to FF00h.
     pub fn pmic0_revision_number(&self) -> Option<u16> {
-        self.parts.get_field_word(0x5E)
+        self.parts
+            .since(SMBiosVersion::new(3, 7, 0))?
+            .get_field_word(0x5E)
     }
 
     /// The two-byte RCD manufacturer ID found in the
@@ -354,7 +517,14 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// field will have the first byte correspond to the value in byte 240 and the second byte corresponds to the
     /// value in byte 241. If SPD doesn't contain Register Revision Number, this field shall be set to 0000h.
     pub fn rcd_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x60)
+        self.parts
+            .since(SMBiosVersion::new(3, 7, 0))?
+            .get_field_word(0x60)
+    }
+
+    /// The RCD manufacturer ID decoded as a JEDEC JEP-106 identifier.
+    pub fn rcd_manufacturer(&self) -> Option<JedecManufacturerId> {
+        self.rcd_manufacturer_id().map(JedecManufacturerId::from)
     }
 
     /// The RCD 0 Revision Number found in the SPD of
@@ -369,7 +539,9 @@ impl<'a> SMBiosMemoryDevice<'a> {
     /// byte correspond to the value in byte 243 and the second byte shall be set to 00h. If SPD doesn't contain
     /// Register Revision Number, this field shall be set to FF00h
     pub fn rcd_revision_number(&self) -> Option<u16> {
-        self.parts.get_field_word(0x62)
+        self.parts
+            .since(SMBiosVersion::new(3, 7, 0))?
+            .get_field_word(0x62)
     }
 }
 
@@ -443,7 +615,7 @@ impl Serialize for SMBiosMemoryDevice<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosMemoryDevice", 36)?;
+        let mut state = serializer.serialize_struct("SMBiosMemoryDevice", 40)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field(
             "physical_memory_array_handle",
@@ -506,6 +678,329 @@ impl Serialize for SMBiosMemoryDevice<'_> {
     }
 }
 
+/// # Installed Memory
+///
+/// The memory installed in a system, summed from its [SMBiosMemoryDevice]
+/// structures by [SMBiosData::installed_memory].
+///
+/// ```
+/// # use smbioslib::*;
+/// let mut table = TableBuilder::new();
+/// table.add(MemoryDeviceBuilder::new().size_mb(16384).memory_type(MemoryDeviceType::Ddr4));
+/// table.add(MemoryDeviceBuilder::new().size_mb(16384).memory_type(MemoryDeviceType::Ddr4));
+/// table.add(MemoryDeviceBuilder::new().size_mb(16).memory_type(MemoryDeviceType::Flash));
+/// let data = table.build_smbios_data(None);
+///
+/// let installed_memory = data.installed_memory();
+/// assert_eq!(installed_memory.total_bytes, 32 * 1024 * 1024 * 1024);
+/// assert_eq!(installed_memory.arrays.len(), 1);
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstalledMemory {
+    /// Total size of the installed memory devices in bytes
+    pub total_bytes: u64,
+    /// Subtotals for each Physical Memory Array, in order of first appearance
+    pub arrays: Vec<ArrayInstalledMemory>,
+    /// Number of installed memory devices whose size is unknown, which are
+    /// not included in the totals
+    pub unknown_size_devices: usize,
+}
+
+/// # Installed Memory of a Physical Memory Array
+///
+/// A subtotal of [InstalledMemory].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ArrayInstalledMemory {
+    /// Handle of the [super::SMBiosPhysicalMemoryArray] the devices belong to
+    pub handle: Handle,
+    /// Total size of the installed memory devices of the array in bytes
+    pub bytes: u64,
+    /// Number of installed memory devices of known size in the array
+    pub devices: usize,
+}
+
+impl SMBiosData {
+    /// Sums the sizes of the installed [SMBiosMemoryDevice] structures in the
+    /// table, in total and for each Physical Memory Array
+    ///
+    /// Flash, ROM and EPROM devices are excluded so the totals report RAM.
+    pub fn installed_memory(&self) -> InstalledMemory {
+        let mut installed_memory = InstalledMemory::default();
+
+        for memory_device in self.defined_struct_iter::<SMBiosMemoryDevice<'_>>() {
+            let is_ram = !matches!(
+                memory_device
+                    .memory_type()
+                    .map(|memory_type| memory_type.value),
+                Some(
+                    MemoryDeviceType::Rom
+                        | MemoryDeviceType::Flash
+                        | MemoryDeviceType::Eeprom
+                        | MemoryDeviceType::Feprom
+                        | MemoryDeviceType::Eprom
+                )
+            );
+            if !is_ram {
+                continue;
+            }
+
+            let bytes = match memory_device.size_bytes() {
+                Some(MemoryDeviceSize::Bytes(bytes)) => bytes,
+                Some(MemoryDeviceSize::NotInstalled) => continue,
+                Some(MemoryDeviceSize::Unknown) | None => {
+                    installed_memory.unknown_size_devices += 1;
+                    continue;
+                }
+            };
+
+            installed_memory.total_bytes += bytes;
+            let handle = memory_device
+                .physical_memory_array_handle()
+                .unwrap_or(Handle::NOT_PROVIDED);
+            match installed_memory
+                .arrays
+                .iter_mut()
+                .find(|array| array.handle == handle)
+            {
+                Some(array) => {
+                    array.bytes += bytes;
+                    array.devices += 1;
+                }
+                None => installed_memory.arrays.push(ArrayInstalledMemory {
+                    handle,
+                    bytes,
+                    devices: 1,
+                }),
+            }
+        }
+
+        installed_memory
+    }
+}
+
+/// # Memory Device (Type 17) Builder
+///
+/// Builds a [SMBiosMemoryDevice] structure for use with a
+/// [TableBuilder](crate::TableBuilder).
+///
+/// ```
+/// # use smbioslib::*;
+/// let mut table = TableBuilder::new();
+/// table.add(
+///     MemoryDeviceBuilder::new()
+///         .device_locator("DIMM_A1")
+///         .size_mb(16384)
+///         .memory_type(MemoryDeviceType::Ddr4),
+/// );
+/// let data = table.build_smbios_data(None);
+///
+/// let memory_device = data.first::<SMBiosMemoryDevice>().unwrap();
+/// assert_eq!(memory_device.size(), Some(MemorySize::Megabytes(16384)));
+/// assert_eq!(*memory_device.memory_type().unwrap(), MemoryDeviceType::Ddr4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryDeviceBuilder(StructBuilder);
+
+impl MemoryDeviceBuilder {
+    /// Creates a builder for an SMBIOS 2.8 length structure describing an empty
+    /// socket of unknown width, form factor and type
+    pub fn new() -> Self {
+        Self(
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x28)
+                .handle(0x04, Handle::NOT_PROVIDED)
+                .handle(0x06, Handle::NOT_PROVIDED)
+                .word(0x08, 0xFFFF)
+                .word(0x0A, 0xFFFF)
+                .byte(0x0E, 0x02)
+                .byte(0x12, 0x02)
+                .word(0x13, 0x0004),
+        )
+    }
+
+    /// Handle of the [super::SMBiosPhysicalMemoryArray] to which this device belongs
+    pub fn physical_memory_array_handle(self, value: Handle) -> Self {
+        Self(self.0.handle(0x04, value))
+    }
+
+    /// Handle of the memory error information structure for this device
+    pub fn memory_error_information_handle(self, value: Handle) -> Self {
+        Self(self.0.handle(0x06, value))
+    }
+
+    /// Total width, in bits, including any check or error-correction bits
+    pub fn total_width(self, value: u16) -> Self {
+        Self(self.0.word(0x08, value))
+    }
+
+    /// Data width, in bits
+    pub fn data_width(self, value: u16) -> Self {
+        Self(self.0.word(0x0A, value))
+    }
+
+    /// Size in megabytes
+    ///
+    /// Sizes of 32 GB - 1 MB or greater are written to the Extended Size field,
+    /// which saturates at its 31-bit maximum of 2 PB - 1 MB.
+    pub fn size_mb(self, value: u32) -> Self {
+        if value < 0x7FFF {
+            Self(self.0.word(0x0C, value as u16).dword(0x1C, 0))
+        } else {
+            Self(
+                self.0
+                    .word(0x0C, 0x7FFF)
+                    .dword(0x1C, value.min(0x7FFF_FFFF)),
+            )
+        }
+    }
+
+    /// Form factor
+    ///
+    /// [MemoryFormFactor::None] is written as Unknown.
+    pub fn form_factor(self, value: MemoryFormFactor) -> Self {
+        let raw = MEMORY_FORM_FACTORS
+            .iter()
+            .find(|(_, defined)| *defined == value)
+            .map_or(0x02, |(raw, _)| *raw);
+        Self(self.0.byte(0x0E, raw))
+    }
+
+    /// Device set
+    pub fn device_set(self, value: u8) -> Self {
+        Self(self.0.byte(0x0F, value))
+    }
+
+    /// Device locator
+    pub fn device_locator(self, value: &str) -> Self {
+        Self(self.0.string(0x10, value))
+    }
+
+    /// Bank locator
+    pub fn bank_locator(self, value: &str) -> Self {
+        Self(self.0.string(0x11, value))
+    }
+
+    /// Memory type
+    ///
+    /// [MemoryDeviceType::None] is written as Unknown.
+    pub fn memory_type(self, value: MemoryDeviceType) -> Self {
+        let raw = MEMORY_DEVICE_TYPES
+            .iter()
+            .find(|(_, defined)| *defined == value)
+            .map_or(0x02, |(raw, _)| *raw);
+        Self(self.0.byte(0x12, raw))
+    }
+
+    /// Type detail (see [MemoryTypeDetails])
+    pub fn type_detail(self, value: u16) -> Self {
+        Self(self.0.word(0x13, value))
+    }
+
+    /// Maximum capable speed in megatransfers per second (MT/s)
+    pub fn speed(self, value: u16) -> Self {
+        Self(self.0.word(0x15, value))
+    }
+
+    /// Manufacturer
+    pub fn manufacturer(self, value: &str) -> Self {
+        Self(self.0.string(0x17, value))
+    }
+
+    /// Serial number
+    pub fn serial_number(self, value: &str) -> Self {
+        Self(self.0.string(0x18, value))
+    }
+
+    /// Asset tag
+    pub fn asset_tag(self, value: &str) -> Self {
+        Self(self.0.string(0x19, value))
+    }
+
+    /// Part number
+    pub fn part_number(self, value: &str) -> Self {
+        Self(self.0.string(0x1A, value))
+    }
+
+    /// Attributes
+    ///
+    /// Bits 3:0 are the rank, 0 if unknown.
+    pub fn attributes(self, value: u8) -> Self {
+        Self(self.0.byte(0x1B, value))
+    }
+
+    /// Configured memory speed in megatransfers per second (MT/s)
+    pub fn configured_memory_speed(self, value: u16) -> Self {
+        Self(self.0.word(0x20, value))
+    }
+
+    /// Minimum voltage in millivolts
+    pub fn minimum_voltage(self, value: u16) -> Self {
+        Self(self.0.word(0x22, value))
+    }
+
+    /// Maximum voltage in millivolts
+    pub fn maximum_voltage(self, value: u16) -> Self {
+        Self(self.0.word(0x24, value))
+    }
+
+    /// Configured voltage in millivolts
+    pub fn configured_voltage(self, value: u16) -> Self {
+        Self(self.0.word(0x26, value))
+    }
+
+    /// Memory technology (SMBIOS 3.2+)
+    ///
+    /// [MemoryDeviceTechnology::None] is written as Unknown.
+    pub fn memory_technology(self, value: MemoryDeviceTechnology) -> Self {
+        let raw = MEMORY_DEVICE_TECHNOLOGIES
+            .iter()
+            .find(|(_, defined)| *defined == value)
+            .map_or(0x02, |(raw, _)| *raw);
+        Self(self.0.byte(0x28, raw))
+    }
+
+    /// Memory operating mode capability (SMBIOS 3.2+)
+    pub fn memory_operating_mode_capability(self, value: MemoryOperatingModeCapabilities) -> Self {
+        Self(self.0.word(0x29, value.raw))
+    }
+
+    /// Firmware version (SMBIOS 3.2+)
+    pub fn firmware_version(self, value: &str) -> Self {
+        Self(self.0.string(0x2B, value))
+    }
+
+    /// Size of the non-volatile portion in bytes (SMBIOS 3.2+)
+    pub fn non_volatile_size(self, value: u64) -> Self {
+        Self(self.0.qword(0x34, value))
+    }
+
+    /// Size of the volatile portion in bytes (SMBIOS 3.2+)
+    pub fn volatile_size(self, value: u64) -> Self {
+        Self(self.0.qword(0x3C, value))
+    }
+
+    /// Size of the cache portion in bytes (SMBIOS 3.2+)
+    pub fn cache_size(self, value: u64) -> Self {
+        Self(self.0.qword(0x44, value))
+    }
+
+    /// Size of the logical memory device in bytes (SMBIOS 3.2+)
+    pub fn logical_size(self, value: u64) -> Self {
+        Self(self.0.qword(0x4C, value))
+    }
+}
+
+impl Default for MemoryDeviceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MemoryDeviceBuilder> for StructBuilder {
+    fn from(builder: MemoryDeviceBuilder) -> Self {
+        builder.0
+    }
+}
+
 /// # Memory Device - Type Data
 #[derive(PartialEq, Eq)]
 pub struct MemoryDeviceTypeData {
@@ -559,7 +1054,7 @@ impl Deref for MemoryDeviceTypeData {
 }
 
 /// # Memory Device -Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryDeviceType {
     /// Other
     Other,
@@ -631,45 +1126,50 @@ pub enum MemoryDeviceType {
     None,
 }
 
+/// Every memory type defined by the specification with its raw value
+const MEMORY_DEVICE_TYPES: &[(u8, MemoryDeviceType)] = &[
+    (0x01, MemoryDeviceType::Other),
+    (0x02, MemoryDeviceType::Unknown),
+    (0x03, MemoryDeviceType::Dram),
+    (0x04, MemoryDeviceType::Edram),
+    (0x05, MemoryDeviceType::Vram),
+    (0x06, MemoryDeviceType::Sram),
+    (0x07, MemoryDeviceType::Ram),
+    (0x08, MemoryDeviceType::Rom),
+    (0x09, MemoryDeviceType::Flash),
+    (0x0A, MemoryDeviceType::Eeprom),
+    (0x0B, MemoryDeviceType::Feprom),
+    (0x0C, MemoryDeviceType::Eprom),
+    (0x0D, MemoryDeviceType::Cdram),
+    (0x0E, MemoryDeviceType::ThreeDram),
+    (0x0F, MemoryDeviceType::Sdram),
+    (0x10, MemoryDeviceType::Sgram),
+    (0x11, MemoryDeviceType::Rdram),
+    (0x12, MemoryDeviceType::Ddr),
+    (0x13, MemoryDeviceType::Ddr2),
+    (0x14, MemoryDeviceType::Ddr2Fbdimm),
+    (0x18, MemoryDeviceType::Ddr3),
+    (0x19, MemoryDeviceType::Fbd2),
+    (0x1A, MemoryDeviceType::Ddr4),
+    (0x1B, MemoryDeviceType::Lpddr),
+    (0x1C, MemoryDeviceType::Lpddr2),
+    (0x1D, MemoryDeviceType::Lpddr3),
+    (0x1E, MemoryDeviceType::Lpddr4),
+    (0x1F, MemoryDeviceType::LogicalNonVolatileDevice),
+    (0x20, MemoryDeviceType::Hbm),
+    (0x21, MemoryDeviceType::Hbm2),
+    (0x22, MemoryDeviceType::Ddr5),
+    (0x23, MemoryDeviceType::Lpddr5),
+    (0x24, MemoryDeviceType::Hbm3),
+];
+
 impl From<u8> for MemoryDeviceTypeData {
     fn from(raw: u8) -> Self {
         MemoryDeviceTypeData {
-            value: match raw {
-                0x01 => MemoryDeviceType::Other,
-                0x02 => MemoryDeviceType::Unknown,
-                0x03 => MemoryDeviceType::Dram,
-                0x04 => MemoryDeviceType::Edram,
-                0x05 => MemoryDeviceType::Vram,
-                0x06 => MemoryDeviceType::Sram,
-                0x07 => MemoryDeviceType::Ram,
-                0x08 => MemoryDeviceType::Rom,
-                0x09 => MemoryDeviceType::Flash,
-                0x0A => MemoryDeviceType::Eeprom,
-                0x0B => MemoryDeviceType::Feprom,
-                0x0C => MemoryDeviceType::Eprom,
-                0x0D => MemoryDeviceType::Cdram,
-                0x0E => MemoryDeviceType::ThreeDram,
-                0x0F => MemoryDeviceType::Sdram,
-                0x10 => MemoryDeviceType::Sgram,
-                0x11 => MemoryDeviceType::Rdram,
-                0x12 => MemoryDeviceType::Ddr,
-                0x13 => MemoryDeviceType::Ddr2,
-                0x14 => MemoryDeviceType::Ddr2Fbdimm,
-                0x18 => MemoryDeviceType::Ddr3,
-                0x19 => MemoryDeviceType::Fbd2,
-                0x1A => MemoryDeviceType::Ddr4,
-                0x1B => MemoryDeviceType::Lpddr,
-                0x1C => MemoryDeviceType::Lpddr2,
-                0x1D => MemoryDeviceType::Lpddr3,
-                0x1E => MemoryDeviceType::Lpddr4,
-                0x1F => MemoryDeviceType::LogicalNonVolatileDevice,
-                0x20 => MemoryDeviceType::Hbm,
-                0x21 => MemoryDeviceType::Hbm2,
-                0x22 => MemoryDeviceType::Ddr5,
-                0x23 => MemoryDeviceType::Lpddr5,
-                0x24 => MemoryDeviceType::Hbm3,
-                _ => MemoryDeviceType::None,
-            },
+            value: MEMORY_DEVICE_TYPES
+                .iter()
+                .find(|(defined, _)| *defined == raw)
+                .map_or(MemoryDeviceType::None, |(_, value)| *value),
             raw,
         }
     }
@@ -709,6 +1209,15 @@ impl Serialize for MemoryFormFactorData {
     }
 }
 
+impl fmt::Display for MemoryFormFactorData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryFormFactor::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryFormFactorData {
     type Target = MemoryFormFactor;
 
@@ -718,7 +1227,7 @@ impl Deref for MemoryFormFactorData {
 }
 
 /// # Memory Device — Form Factor
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryFormFactor {
     /// Other
     Other,
@@ -752,39 +1261,57 @@ pub enum MemoryFormFactor {
     Fbdimm,
     /// Die
     Die,
+    /// CAMM (Compression Attached Memory Module)
+    Camm,
     /// A value unknown to this standard, check the raw value
     None,
 }
 
+/// Every form factor defined by the specification with its raw value
+const MEMORY_FORM_FACTORS: &[(u8, MemoryFormFactor)] = &[
+    (0x01, MemoryFormFactor::Other),
+    (0x02, MemoryFormFactor::Unknown),
+    (0x03, MemoryFormFactor::Simm),
+    (0x04, MemoryFormFactor::Sip),
+    (0x05, MemoryFormFactor::Chip),
+    (0x06, MemoryFormFactor::Dip),
+    (0x07, MemoryFormFactor::Zip),
+    (0x08, MemoryFormFactor::ProprietaryCard),
+    (0x09, MemoryFormFactor::Dimm),
+    (0x0A, MemoryFormFactor::Tsop),
+    (0x0B, MemoryFormFactor::RowOfChips),
+    (0x0C, MemoryFormFactor::Rimm),
+    (0x0D, MemoryFormFactor::Sodimm),
+    (0x0E, MemoryFormFactor::Srimm),
+    (0x0F, MemoryFormFactor::Fbdimm),
+    (0x10, MemoryFormFactor::Die),
+    (0x11, MemoryFormFactor::Camm),
+];
+
 impl From<u8> for MemoryFormFactorData {
     fn from(raw: u8) -> Self {
         MemoryFormFactorData {
-            value: match raw {
-                0x01 => MemoryFormFactor::Other,
-                0x02 => MemoryFormFactor::Unknown,
-                0x03 => MemoryFormFactor::Simm,
-                0x04 => MemoryFormFactor::Sip,
-                0x05 => MemoryFormFactor::Chip,
-                0x06 => MemoryFormFactor::Dip,
-                0x07 => MemoryFormFactor::Zip,
-                0x08 => MemoryFormFactor::ProprietaryCard,
-                0x09 => MemoryFormFactor::Dimm,
-                0x0A => MemoryFormFactor::Tsop,
-                0x0B => MemoryFormFactor::RowOfChips,
-                0x0C => MemoryFormFactor::Rimm,
-                0x0D => MemoryFormFactor::Sodimm,
-                0x0E => MemoryFormFactor::Srimm,
-                0x0F => MemoryFormFactor::Fbdimm,
-                0x10 => MemoryFormFactor::Die,
-                _ => MemoryFormFactor::None,
-            },
+            value: MEMORY_FORM_FACTORS
+                .iter()
+                .find(|(defined, _)| *defined == raw)
+                .map_or(MemoryFormFactor::None, |(_, value)| *value),
             raw,
         }
     }
 }
 
 /// # Memory Device — Type Detail
-#[derive(PartialEq, Eq)]
+///
+/// Each defined bit is available as a named constant which can be combined
+/// and tested as flags:
+///
+/// ```
+/// # use smbioslib::*;
+/// let type_detail = MemoryTypeDetails::from(0x2080);
+/// assert!(type_detail.contains(MemoryTypeDetails::SYNCHRONOUS | MemoryTypeDetails::REGISTERED));
+/// assert!(!type_detail.intersects(MemoryTypeDetails::UNBUFFERED | MemoryTypeDetails::LRDIMM));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct MemoryTypeDetails {
     /// Raw value
     pub raw: u16,
@@ -805,82 +1332,148 @@ impl From<u16> for MemoryTypeDetails {
 }
 
 impl MemoryTypeDetails {
+    /// Bit 1 Other
+    pub const OTHER: Self = Self { raw: 0x0002 };
+
+    /// Bit 2 Unknown
+    pub const UNKNOWN: Self = Self { raw: 0x0004 };
+
+    /// Bit 3 Fast-paged
+    pub const FAST_PAGED: Self = Self { raw: 0x0008 };
+
+    /// Bit 4 Static column
+    pub const STATIC_COLUMN: Self = Self { raw: 0x0010 };
+
+    /// Bit 5 Pseudo-static
+    pub const PSEUDO_STATIC: Self = Self { raw: 0x0020 };
+
+    /// Bit 6 RAMBUS
+    pub const RAMBUS: Self = Self { raw: 0x0040 };
+
+    /// Bit 7 Synchronous
+    pub const SYNCHRONOUS: Self = Self { raw: 0x0080 };
+
+    /// Bit 8 CMOS
+    pub const CMOS: Self = Self { raw: 0x0100 };
+
+    /// Bit 9 EDO
+    pub const EDO: Self = Self { raw: 0x0200 };
+
+    /// Bit 10 Window DRAM
+    pub const WINDOW_DRAM: Self = Self { raw: 0x0400 };
+
+    /// Bit 11 Cache DRAM
+    pub const CACHE_DRAM: Self = Self { raw: 0x0800 };
+
+    /// Bit 12 Non-volatile
+    pub const NON_VOLATILE: Self = Self { raw: 0x1000 };
+
+    /// Bit 13 Registered (Buffered)
+    pub const REGISTERED: Self = Self { raw: 0x2000 };
+
+    /// Bit 14 Unbuffered (Unregistered)
+    pub const UNBUFFERED: Self = Self { raw: 0x4000 };
+
+    /// Bit 15 LRDIMM
+    pub const LRDIMM: Self = Self { raw: 0x8000 };
+
     /// Bit 1 Other
     pub fn other(&self) -> bool {
-        self.raw & 0x0002 == 0x0002
+        self.contains(Self::OTHER)
     }
 
     /// Bit 2 Unknown
     pub fn unknown(&self) -> bool {
-        self.raw & 0x0004 == 0x0004
+        self.contains(Self::UNKNOWN)
     }
 
     /// Bit 3 Fast-paged
     pub fn fast_paged(&self) -> bool {
-        self.raw & 0x0008 == 0x0008
+        self.contains(Self::FAST_PAGED)
     }
 
     /// Bit 4 Static column
     pub fn static_column(&self) -> bool {
-        self.raw & 0x0010 == 0x0010
+        self.contains(Self::STATIC_COLUMN)
     }
 
     /// Bit 5 Pseudo-static
     pub fn pseudo_static(&self) -> bool {
-        self.raw & 0x0020 == 0x0020
+        self.contains(Self::PSEUDO_STATIC)
     }
 
     /// Bit 6 RAMBUS
     pub fn ram_bus(&self) -> bool {
-        self.raw & 0x0040 == 0x0040
+        self.contains(Self::RAMBUS)
     }
 
     /// Bit 7 Synchronous
     pub fn synchronous(&self) -> bool {
-        self.raw & 0x0080 == 0x0080
+        self.contains(Self::SYNCHRONOUS)
     }
 
     /// Bit 8 CMOS
     pub fn cmos(&self) -> bool {
-        self.raw & 0x0100 == 0x0100
+        self.contains(Self::CMOS)
     }
 
     /// Bit 9 EDO
     pub fn edo(&self) -> bool {
-        self.raw & 0x0200 == 0x0200
+        self.contains(Self::EDO)
     }
 
     /// Bit 10 Window DRAM
     pub fn window_dram(&self) -> bool {
-        self.raw & 0x0400 == 0x0400
+        self.contains(Self::WINDOW_DRAM)
     }
 
     /// Bit 11 Cache DRAM
     pub fn cache_dram(&self) -> bool {
-        self.raw & 0x0800 == 0x0800
+        self.contains(Self::CACHE_DRAM)
     }
 
     /// Bit 12 Non-volatile
     pub fn non_volatile(&self) -> bool {
-        self.raw & 0x1000 == 0x1000
+        self.contains(Self::NON_VOLATILE)
     }
 
     /// Bit 13 Registered (Buffered)
     pub fn registered(&self) -> bool {
-        self.raw & 0x2000 == 0x2000
+        self.contains(Self::REGISTERED)
     }
 
     /// Bit 14 Unbuffered (Unregistered)
     pub fn unbuffered(&self) -> bool {
-        self.raw & 0x4000 == 0x4000
+        self.contains(Self::UNBUFFERED)
     }
 
     /// Bit 15 LRDIMM
     pub fn lrdimm(&self) -> bool {
-        self.raw & 0x8000 == 0x8000
+        self.contains(Self::LRDIMM)
     }
 }
 
+flag_set!(
+    MemoryTypeDetails,
+    [
+        ("Other", OTHER),
+        ("Unknown", UNKNOWN),
+        ("Fast-paged", FAST_PAGED),
+        ("Static column", STATIC_COLUMN),
+        ("Pseudo-static", PSEUDO_STATIC),
+        ("RAMBUS", RAMBUS),
+        ("Synchronous", SYNCHRONOUS),
+        ("CMOS", CMOS),
+        ("EDO", EDO),
+        ("Window DRAM", WINDOW_DRAM),
+        ("Cache DRAM", CACHE_DRAM),
+        ("Non-volatile", NON_VOLATILE),
+        ("Registered (Buffered)", REGISTERED),
+        ("Unbuffered (Unregistered)", UNBUFFERED),
+        ("LRDIMM", LRDIMM),
+    ]
+);
+
 impl fmt::Debug for MemoryTypeDetails {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<MemoryTypeDetails>())
@@ -964,6 +1557,15 @@ impl Serialize for MemoryDeviceTechnologyData {
     }
 }
 
+impl fmt::Display for MemoryDeviceTechnologyData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryDeviceTechnology::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryDeviceTechnologyData {
     type Target = MemoryDeviceTechnology;
 
@@ -973,7 +1575,7 @@ impl Deref for MemoryDeviceTechnologyData {
 }
 
 /// # Memory Device — Memory Technology
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryDeviceTechnology {
     /// Other
     Other,
@@ -993,26 +1595,42 @@ pub enum MemoryDeviceTechnology {
     None,
 }
 
+/// Every memory technology defined by the specification with its raw value
+const MEMORY_DEVICE_TECHNOLOGIES: &[(u8, MemoryDeviceTechnology)] = &[
+    (0x01, MemoryDeviceTechnology::Other),
+    (0x02, MemoryDeviceTechnology::Unknown),
+    (0x03, MemoryDeviceTechnology::Dram),
+    (0x04, MemoryDeviceTechnology::NvdimmN),
+    (0x05, MemoryDeviceTechnology::NvdimmF),
+    (0x06, MemoryDeviceTechnology::NvdimmP),
+    (0x07, MemoryDeviceTechnology::IntelOptaneDcPersistentMemory),
+];
+
 impl From<u8> for MemoryDeviceTechnologyData {
     fn from(raw: u8) -> Self {
         MemoryDeviceTechnologyData {
-            value: match raw {
-                0x01 => MemoryDeviceTechnology::Other,
-                0x02 => MemoryDeviceTechnology::Unknown,
-                0x03 => MemoryDeviceTechnology::Dram,
-                0x04 => MemoryDeviceTechnology::NvdimmN,
-                0x05 => MemoryDeviceTechnology::NvdimmF,
-                0x06 => MemoryDeviceTechnology::NvdimmP,
-                0x07 => MemoryDeviceTechnology::IntelOptaneDcPersistentMemory,
-                _ => MemoryDeviceTechnology::None,
-            },
+            value: MEMORY_DEVICE_TECHNOLOGIES
+                .iter()
+                .find(|(defined, _)| *defined == raw)
+                .map_or(MemoryDeviceTechnology::None, |(_, value)| *value),
             raw,
         }
     }
 }
 
 /// # Memory Device — Memory Operating Mode Capability
-#[derive(PartialEq, Eq)]
+///
+/// Each defined bit is available as a named constant which can be combined
+/// and tested as flags:
+///
+/// ```
+/// # use smbioslib::*;
+/// let capabilities = MemoryOperatingModeCapabilities::from(0x0018);
+/// assert!(capabilities.contains(MemoryOperatingModeCapabilities::VOLATILE_MEMORY));
+/// assert!(capabilities.byte_accessible_persistent_memory());
+/// assert!(!capabilities.block_accessible_persistent_memory());
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct MemoryOperatingModeCapabilities {
     /// Raw value
     pub raw: u16,
@@ -1033,32 +1651,64 @@ impl From<u16> for MemoryOperatingModeCapabilities {
 }
 
 impl MemoryOperatingModeCapabilities {
+    /// Other
+    pub const OTHER: Self = Self { raw: 0x0002 };
+
+    /// Unknown
+    pub const UNKNOWN: Self = Self { raw: 0x0004 };
+
+    /// Volatile memory
+    pub const VOLATILE_MEMORY: Self = Self { raw: 0x0008 };
+
+    /// Byte-accessible persistent memory
+    pub const BYTE_ACCESSIBLE_PERSISTENT_MEMORY: Self = Self { raw: 0x0010 };
+
+    /// Block-accessible persistent memory
+    pub const BLOCK_ACCESSIBLE_PERSISTENT_MEMORY: Self = Self { raw: 0x0020 };
+
     /// Other
     pub fn other(&self) -> bool {
-        self.raw & 0x0002 == 0x0002
+        self.contains(Self::OTHER)
     }
 
     /// Unknown
     pub fn unknown(&self) -> bool {
-        self.raw & 0x0004 == 0x0004
+        self.contains(Self::UNKNOWN)
     }
 
     /// Volatile memory
     pub fn volatile_memory(&self) -> bool {
-        self.raw & 0x0008 == 0x0008
+        self.contains(Self::VOLATILE_MEMORY)
     }
 
     /// Byte-accessible persistent memory
     pub fn byte_accessible_persistent_memory(&self) -> bool {
-        self.raw & 0x0010 == 0x0010
+        self.contains(Self::BYTE_ACCESSIBLE_PERSISTENT_MEMORY)
     }
 
     /// Block-accessible persistent memory
     pub fn block_accessible_persistent_memory(&self) -> bool {
-        self.raw & 0x0020 == 0x0020
+        self.contains(Self::BLOCK_ACCESSIBLE_PERSISTENT_MEMORY)
     }
 }
 
+flag_set!(
+    MemoryOperatingModeCapabilities,
+    [
+        ("Other", OTHER),
+        ("Unknown", UNKNOWN),
+        ("Volatile memory", VOLATILE_MEMORY),
+        (
+            "Byte-accessible persistent memory",
+            BYTE_ACCESSIBLE_PERSISTENT_MEMORY
+        ),
+        (
+            "Block-accessible persistent memory",
+            BLOCK_ACCESSIBLE_PERSISTENT_MEMORY
+        ),
+    ]
+);
+
 impl fmt::Debug for MemoryOperatingModeCapabilities {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<MemoryOperatingModeCapabilities>())
@@ -1146,6 +1796,56 @@ impl From<u32> for MemorySpeedExtended {
     }
 }
 
+/// # Speed of a Memory Device
+///
+/// The combined [MemorySpeed] and [MemorySpeedExtended] of a [SMBiosMemoryDevice].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryDeviceSpeed {
+    /// Memory Speed is Unknown
+    Unknown,
+    /// Speed of memory in megatransfers per second (MT/s)
+    MTs(u32),
+}
+
+impl MemoryDeviceSpeed {
+    fn new(speed: MemorySpeed, extended_speed: Option<MemorySpeedExtended>) -> Self {
+        match speed {
+            MemorySpeed::Unknown => MemoryDeviceSpeed::Unknown,
+            MemorySpeed::MTs(mts) => MemoryDeviceSpeed::MTs(mts as u32),
+            MemorySpeed::SeeExtendedSpeed => match extended_speed {
+                Some(MemorySpeedExtended::MTs(mts)) => MemoryDeviceSpeed::MTs(mts),
+                _ => MemoryDeviceSpeed::Unknown,
+            },
+        }
+    }
+
+    /// The speed in megatransfers per second (MT/s), `None` when unknown
+    pub fn mts(&self) -> Option<u32> {
+        match self {
+            MemoryDeviceSpeed::Unknown => None,
+            MemoryDeviceSpeed::MTs(mts) => Some(*mts),
+        }
+    }
+}
+
+/// # Voltage of a Memory Device
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryDeviceVoltage {
+    /// Voltage in millivolts
+    MilliVolts(u16),
+    /// Voltage is unknown
+    Unknown,
+}
+
+impl From<u16> for MemoryDeviceVoltage {
+    fn from(raw: u16) -> Self {
+        match raw {
+            0 => MemoryDeviceVoltage::Unknown,
+            _ => MemoryDeviceVoltage::MilliVolts(raw),
+        }
+    }
+}
+
 /// # Size of Memory
 #[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum MemorySize {
@@ -1211,8 +1911,126 @@ impl From<u32> for MemorySizeExtended {
     }
 }
 
+/// # Size of a Memory Device
+///
+/// The combined [MemorySize] and [MemorySizeExtended] of a [SMBiosMemoryDevice].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryDeviceSize {
+    /// No Memory Device Installed in the Socket
+    NotInstalled,
+    /// Memory Size is Unknown
+    Unknown,
+    /// Size of Memory (bytes)
+    Bytes(u64),
+}
+
+impl MemoryDeviceSize {
+    /// The size in bytes, 0 when not installed and `None` when unknown
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            MemoryDeviceSize::NotInstalled => Some(0),
+            MemoryDeviceSize::Unknown => None,
+            MemoryDeviceSize::Bytes(bytes) => Some(*bytes),
+        }
+    }
+}
+
+/// # JEDEC JEP-106 Manufacturer ID
+///
+/// A two-byte manufacturer ID as stored in the SPD and copied into the
+/// Memory Device structure.  The low byte holds the number of JEP-106
+/// continuation codes (with odd parity in bit 7) and the high byte holds the
+/// manufacturer's identification code within that bank.  A raw value of 0
+/// means the manufacturer is unknown.
+///
+/// ```
+/// # use smbioslib::*;
+/// let micron = JedecManufacturerId::from(0x2C80);
+/// assert_eq!(micron.bank(), 1);
+/// assert_eq!(micron.id(), 0x2C);
+/// assert_eq!(micron.to_string(), "Bank 1, Hex 0x2C");
+/// assert_eq!(JedecManufacturerId::from(0).to_string(), "Unknown");
+/// ```
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct JedecManufacturerId {
+    /// Raw value
+    pub raw: u16,
+}
+
+impl Deref for JedecManufacturerId {
+    type Target = u16;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u16> for JedecManufacturerId {
+    fn from(raw: u16) -> Self {
+        JedecManufacturerId { raw }
+    }
+}
+
+impl JedecManufacturerId {
+    /// Returns true when the manufacturer is unknown (raw value 0)
+    pub fn is_unknown(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// JEP-106 bank number, starting at 1 (number of continuation codes + 1)
+    pub fn bank(&self) -> u8 {
+        (self.raw & 0x7F) as u8 + 1
+    }
+
+    /// Identification code within the bank, including its parity bit
+    pub fn id(&self) -> u8 {
+        (self.raw >> 8) as u8
+    }
+
+    /// Name of the manufacturer for a selection of common memory module,
+    /// DRAM and buffer vendors, None if the ID is not in the table
+    #[cfg(feature = "jedec-names")]
+    pub fn name(&self) -> Option<&'static str> {
+        if self.is_unknown() {
+            return None;
+        }
+
+        let name = match (self.bank(), self.id() & 0x7F) {
+            (1, 0x01) => "AMD",
+            (1, 0x04) => "Fujitsu",
+            (1, 0x09) => "Intel",
+            (1, 0x14) => "Smart Modular",
+            (1, 0x17) => "Texas Instruments",
+            (1, 0x18) => "Kioxia",
+            (1, 0x2C) => "Micron Technology",
+            (1, 0x2D) => "SK Hynix",
+            (1, 0x33) => "IDT",
+            (1, 0x41) => "Infineon",
+            (1, 0x4E) => "Samsung",
+            (2, 0x18) => "Kingston",
+            (3, 0x1E) => "Corsair",
+            (4, 0x0B) => "Nanya Technology",
+            (5, 0x4B) => "A-DATA Technology",
+            (5, 0x4D) => "G.Skill",
+            (6, 0x1B) => "Crucial Technology",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Display for JedecManufacturerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_unknown() {
+            write!(f, "Unknown")
+        } else {
+            write!(f, "Bank {}, Hex {:#04X}", self.bank(), self.id())
+        }
+    }
+}
+
 /// # Size of Memory in Bytes
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryIndicatedSize {
     /// Memory Size is Unknown
     Unknown,
@@ -1391,4 +2209,424 @@ mod tests {
             Some(MemorySpeedExtended::MTs(0x05060708))
         );
     }
+
+    #[test]
+    fn test_builder() {
+        let builder = MemoryDeviceBuilder::new()
+            .physical_memory_array_handle(Handle(0x0040))
+            .total_width(72)
+            .data_width(64)
+            .size_mb(16384)
+            .form_factor(MemoryFormFactor::Dimm)
+            .device_locator("DIMM_A1")
+            .bank_locator("NODE 1")
+            .memory_type(MemoryDeviceType::Ddr4)
+            .speed(2666)
+            .manufacturer("Hynix")
+            .serial_number("72091003")
+            .part_number("HMA81GR7AFR8N-VK")
+            .attributes(0x01)
+            .configured_memory_speed(2400)
+            .configured_voltage(1200);
+        let raw = StructBuilder::from(builder).build(Handle(0x0041));
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(parts.header.length(), 0x28);
+        assert_eq!(
+            test_struct.physical_memory_array_handle(),
+            Some(Handle(0x0040))
+        );
+        assert_eq!(
+            test_struct.memory_error_information_handle(),
+            Some(Handle::NOT_PROVIDED)
+        );
+        assert_eq!(test_struct.size(), Some(MemorySize::Megabytes(16384)));
+        assert_eq!(
+            test_struct.extended_size(),
+            Some(MemorySizeExtended::SeeSize)
+        );
+        assert_eq!(*test_struct.form_factor().unwrap(), MemoryFormFactor::Dimm);
+        assert_eq!(test_struct.device_locator().to_string(), "DIMM_A1");
+        assert_eq!(test_struct.bank_locator().to_string(), "NODE 1");
+        assert_eq!(*test_struct.memory_type().unwrap(), MemoryDeviceType::Ddr4);
+        assert!(test_struct.type_detail().unwrap().unknown());
+        assert_eq!(test_struct.manufacturer().to_string(), "Hynix");
+        assert_eq!(test_struct.asset_tag().to_string(), "");
+        assert_eq!(test_struct.part_number().to_string(), "HMA81GR7AFR8N-VK");
+        assert_eq!(test_struct.configured_voltage(), Some(1200));
+        assert!(test_struct.memory_technology().is_none());
+
+        let raw =
+            StructBuilder::from(MemoryDeviceBuilder::new().size_mb(64 * 1024)).build(Handle(1));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.size(), Some(MemorySize::SeeExtendedSize));
+        assert_eq!(
+            test_struct.extended_size(),
+            Some(MemorySizeExtended::Megabytes(64 * 1024))
+        );
+
+        // Sizes beyond the Extended Size field saturate rather than wrap
+        let raw =
+            StructBuilder::from(MemoryDeviceBuilder::new().size_mb(0x8000_0001)).build(Handle(1));
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(
+            SMBiosMemoryDevice::new(&parts).extended_size(),
+            Some(MemorySizeExtended::Megabytes(0x7FFF_FFFF))
+        );
+    }
+
+    #[test]
+    fn test_version_gating() {
+        use crate::core::{SMBiosVersion, TableBuilder};
+
+        // A 2.8 length structure with 64 GB in the Extended Size field
+        let table = |version| {
+            let mut builder = TableBuilder::new();
+            builder.add(MemoryDeviceBuilder::new().size_mb(64 * 1024));
+            builder.build_smbios_data(version)
+        };
+
+        // Fields added in 2.7 and 2.8 are padding in a 2.6 table
+        let data = table(Some(SMBiosVersion::new(2, 6, 0)));
+        let memory_device = data.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert_eq!(memory_device.size(), Some(MemorySize::SeeExtendedSize));
+        assert!(memory_device.extended_size().is_none());
+        assert!(memory_device.configured_memory_speed().is_none());
+        assert!(memory_device.configured_voltage().is_none());
+
+        let data = table(Some(SMBiosVersion::new(2, 8, 0)));
+        let memory_device = data.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert_eq!(
+            memory_device.extended_size(),
+            Some(MemorySizeExtended::Megabytes(64 * 1024))
+        );
+        assert!(memory_device.configured_voltage().is_some());
+        assert!(memory_device.memory_technology().is_none());
+
+        let data = table(None);
+        let memory_device = data.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert!(memory_device.extended_size().is_some());
+    }
+
+    #[test]
+    fn test_size_bytes() {
+        let size_bytes = |size: u16, extended_size: Option<u32>| {
+            let builder =
+                StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15).word(0x0C, size);
+            let builder = match extended_size {
+                Some(extended_size) => builder.dword(0x1C, extended_size),
+                None => builder,
+            };
+            let raw = builder.build(Handle(0x1100));
+            let parts = UndefinedStruct::new(&raw);
+            SMBiosMemoryDevice::new(&parts).size_bytes()
+        };
+
+        assert_eq!(size_bytes(0, None), Some(MemoryDeviceSize::NotInstalled));
+        assert_eq!(size_bytes(0xFFFF, None), Some(MemoryDeviceSize::Unknown));
+        assert_eq!(
+            size_bytes(0x8100, None),
+            Some(MemoryDeviceSize::Bytes(256 * 1024))
+        );
+        assert_eq!(
+            size_bytes(0x0100, None),
+            Some(MemoryDeviceSize::Bytes(256 * 1024 * 1024))
+        );
+        assert_eq!(
+            size_bytes(0x7FFF, Some(64 * 1024)),
+            Some(MemoryDeviceSize::Bytes(64 << 30))
+        );
+        // Bit 31 of the Extended Size is reserved
+        assert_eq!(
+            size_bytes(0x7FFF, Some(0x8000_0000 | (32 * 1024))),
+            Some(MemoryDeviceSize::Bytes(32 << 30))
+        );
+        assert_eq!(size_bytes(0x7FFF, Some(0)), Some(MemoryDeviceSize::Unknown));
+        assert_eq!(size_bytes(0x7FFF, None), Some(MemoryDeviceSize::Unknown));
+
+        assert_eq!(MemoryDeviceSize::NotInstalled.bytes(), Some(0));
+        assert_eq!(MemoryDeviceSize::Unknown.bytes(), None);
+        assert_eq!(MemoryDeviceSize::Bytes(1024).bytes(), Some(1024));
+    }
+
+    #[test]
+    fn test_type_and_form_factor() {
+        let cases = [
+            (0x18, MemoryDeviceType::Ddr3),
+            (0x1A, MemoryDeviceType::Ddr4),
+            (0x22, MemoryDeviceType::Ddr5),
+            (0x1E, MemoryDeviceType::Lpddr4),
+            (0x23, MemoryDeviceType::Lpddr5),
+            (0x21, MemoryDeviceType::Hbm2),
+            (0x24, MemoryDeviceType::Hbm3),
+            (0x1F, MemoryDeviceType::LogicalNonVolatileDevice),
+            (0x15, MemoryDeviceType::None),
+        ];
+        for (raw, memory_type) in cases.iter() {
+            assert_eq!(MemoryDeviceTypeData::from(*raw).value, *memory_type);
+        }
+
+        let cases = [
+            (0x09, MemoryFormFactor::Dimm),
+            (0x0D, MemoryFormFactor::Sodimm),
+            (0x10, MemoryFormFactor::Die),
+            (0x11, MemoryFormFactor::Camm),
+            (0x12, MemoryFormFactor::None),
+        ];
+        for (raw, form_factor) in cases.iter() {
+            assert_eq!(MemoryFormFactorData::from(*raw).value, *form_factor);
+        }
+        assert_eq!(MemoryFormFactorData::from(0x11).to_string(), "Camm");
+        assert_eq!(MemoryFormFactorData::from(0x12).to_string(), "18");
+
+        let raw = StructBuilder::from(
+            MemoryDeviceBuilder::new()
+                .form_factor(MemoryFormFactor::Camm)
+                .memory_type(MemoryDeviceType::Lpddr5),
+        )
+        .build(Handle(0x1100));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(*test_struct.form_factor().unwrap(), MemoryFormFactor::Camm);
+        assert_eq!(
+            *test_struct.memory_type().unwrap(),
+            MemoryDeviceType::Lpddr5
+        );
+    }
+
+    #[test]
+    fn test_persistent_memory_fields() {
+        let builder = MemoryDeviceBuilder::new()
+            .memory_type(MemoryDeviceType::LogicalNonVolatileDevice)
+            .memory_technology(MemoryDeviceTechnology::IntelOptaneDcPersistentMemory)
+            .memory_operating_mode_capability(
+                MemoryOperatingModeCapabilities::VOLATILE_MEMORY
+                    | MemoryOperatingModeCapabilities::BYTE_ACCESSIBLE_PERSISTENT_MEMORY,
+            )
+            .firmware_version("01.02.00.5446")
+            .non_volatile_size(0x3F_0000_0000)
+            .volatile_size(0)
+            .cache_size(0)
+            .logical_size(0);
+        let raw = StructBuilder::from(builder).build(Handle(0x1100));
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(parts.header.length(), 0x54);
+        let technology = test_struct.memory_technology().unwrap();
+        assert_eq!(
+            *technology,
+            MemoryDeviceTechnology::IntelOptaneDcPersistentMemory
+        );
+        assert_eq!(technology.to_string(), "IntelOptaneDcPersistentMemory");
+        let capabilities = test_struct.memory_operating_mode_capability().unwrap();
+        assert!(capabilities.volatile_memory());
+        assert!(capabilities.byte_accessible_persistent_memory());
+        assert!(!capabilities.block_accessible_persistent_memory());
+        assert_eq!(
+            capabilities
+                .iter()
+                .map(|(desc, _)| desc)
+                .collect::<Vec<_>>(),
+            vec!["Volatile memory", "Byte-accessible persistent memory"]
+        );
+        assert_eq!(test_struct.firmware_version().to_string(), "01.02.00.5446");
+        assert_eq!(
+            test_struct.non_volatile_size(),
+            Some(MemoryIndicatedSize::Bytes(0x3F_0000_0000))
+        );
+        assert_eq!(
+            test_struct.volatile_size(),
+            Some(MemoryIndicatedSize::Bytes(0))
+        );
+        assert_eq!(
+            test_struct.logical_size(),
+            Some(MemoryIndicatedSize::Bytes(0))
+        );
+        assert!(test_struct.extended_speed().is_none());
+
+        assert_eq!(MemoryDeviceTechnologyData::from(0x42).to_string(), "66");
+    }
+
+    #[test]
+    fn test_jedec_manufacturer_id() {
+        let raw = StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x64)
+            .word(0x2C, 0xCE80)
+            .word(0x30, 0x0000)
+            .word(0x5C, 0x0B83)
+            .word(0x60, 0x9801)
+            .build(Handle(0x1100));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+
+        let module_manufacturer = test_struct.module_manufacturer().unwrap();
+        assert_eq!(module_manufacturer.bank(), 1);
+        assert_eq!(module_manufacturer.id(), 0xCE);
+        assert!(test_struct
+            .memory_subsystem_controller_manufacturer()
+            .unwrap()
+            .is_unknown());
+        let pmic0_manufacturer = test_struct.pmic0_manufacturer().unwrap();
+        assert_eq!(pmic0_manufacturer.to_string(), "Bank 4, Hex 0x0B");
+        let rcd_manufacturer = test_struct.rcd_manufacturer().unwrap();
+        assert_eq!(rcd_manufacturer.bank(), 2);
+
+        #[cfg(feature = "jedec-names")]
+        {
+            assert_eq!(module_manufacturer.name(), Some("Samsung"));
+            assert_eq!(pmic0_manufacturer.name(), Some("Nanya Technology"));
+            assert_eq!(rcd_manufacturer.name(), Some("Kingston"));
+            assert_eq!(JedecManufacturerId::from(0x7F80).name(), None);
+        }
+    }
+
+    #[test]
+    fn test_speed_mts() {
+        let raw = StructBuilder::from(
+            MemoryDeviceBuilder::new()
+                .speed(4800)
+                .configured_memory_speed(0),
+        )
+        .build(Handle(0x1100));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts(), Some(MemoryDeviceSpeed::MTs(4800)));
+        assert_eq!(
+            test_struct.configured_memory_speed_mts(),
+            Some(MemoryDeviceSpeed::Unknown)
+        );
+
+        // FFFFh defers to the extended fields, which are absent before 3.3
+        let raw = StructBuilder::from(MemoryDeviceBuilder::new().speed(0xFFFF)).build(Handle(1));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts(), Some(MemoryDeviceSpeed::Unknown));
+
+        let raw = StructBuilder::from(
+            MemoryDeviceBuilder::new()
+                .speed(0xFFFF)
+                .configured_memory_speed(0xFFFF),
+        )
+        .dword(0x54, 70_000)
+        .dword(0x58, 68_000)
+        .build(Handle(2));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts().unwrap().mts(), Some(70_000));
+        assert_eq!(
+            test_struct.configured_memory_speed_mts(),
+            Some(MemoryDeviceSpeed::MTs(68_000))
+        );
+
+        let raw =
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15).build(Handle(3));
+        let parts = UndefinedStruct::new(&raw);
+        assert!(SMBiosMemoryDevice::new(&parts).speed_mts().is_none());
+    }
+
+    #[test]
+    fn test_voltage_and_rank() {
+        let raw = StructBuilder::from(
+            MemoryDeviceBuilder::new()
+                .attributes(0x02)
+                .minimum_voltage(1100)
+                .maximum_voltage(0),
+        )
+        .build(Handle(0x1100));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.rank(), Some(2));
+        assert_eq!(
+            test_struct.minimum_voltage_decoded(),
+            Some(MemoryDeviceVoltage::MilliVolts(1100))
+        );
+        assert_eq!(
+            test_struct.maximum_voltage_decoded(),
+            Some(MemoryDeviceVoltage::Unknown)
+        );
+
+        let raw = StructBuilder::from(MemoryDeviceBuilder::new().attributes(0xF0)).build(Handle(1));
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(SMBiosMemoryDevice::new(&parts).rank(), None);
+
+        // Voltages were added in SMBIOS 2.8
+        let raw = StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x22)
+            .byte(0x1B, 0x04)
+            .build(Handle(2));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.rank(), Some(4));
+        assert!(test_struct.configured_voltage_decoded().is_none());
+    }
+
+    #[test]
+    fn test_physical_memory_array() {
+        use crate::core::TableBuilder;
+
+        let mut builder = TableBuilder::new();
+        let array = builder.add(
+            StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x17)
+                .byte(0x04, 0x03)
+                .handle(0x0B, Handle::NOT_PROVIDED),
+        );
+        builder.add(MemoryDeviceBuilder::new().physical_memory_array_handle(array));
+        builder.add(MemoryDeviceBuilder::new().physical_memory_array_handle(Handle(0x4242)));
+        let data = builder.build_smbios_data(None);
+
+        let devices = data.collect::<SMBiosMemoryDevice<'_>>();
+        let physical_memory_array = devices[0].physical_memory_array(&data).unwrap();
+        assert_eq!(physical_memory_array.parts().header.handle(), array);
+        assert!(devices[1].physical_memory_array(&data).is_none());
+    }
+
+    #[test]
+    fn test_installed_memory() {
+        use crate::core::TableBuilder;
+
+        let mut builder = TableBuilder::new();
+        for (array, size_mb, memory_type) in [
+            (Handle(0x10), 8192, MemoryDeviceType::Ddr5),
+            (Handle(0x10), 0, MemoryDeviceType::Unknown),
+            (
+                Handle(0x20),
+                64 * 1024,
+                MemoryDeviceType::LogicalNonVolatileDevice,
+            ),
+            (Handle(0x10), 8192, MemoryDeviceType::Ddr5),
+            (Handle(0x30), 4, MemoryDeviceType::Flash),
+        ] {
+            builder.add(
+                MemoryDeviceBuilder::new()
+                    .physical_memory_array_handle(array)
+                    .size_mb(size_mb)
+                    .memory_type(memory_type),
+            );
+        }
+        builder.add(
+            StructBuilder::from(MemoryDeviceBuilder::new().memory_type(MemoryDeviceType::Ddr5))
+                .word(0x0C, 0xFFFF),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let installed_memory = data.installed_memory();
+        assert_eq!(installed_memory.total_bytes, 80 * 1024 * 1024 * 1024);
+        assert_eq!(installed_memory.unknown_size_devices, 1);
+        assert_eq!(
+            installed_memory.arrays,
+            vec![
+                ArrayInstalledMemory {
+                    handle: Handle(0x10),
+                    bytes: 16 * 1024 * 1024 * 1024,
+                    devices: 2,
+                },
+                ArrayInstalledMemory {
+                    handle: Handle(0x20),
+                    bytes: 64 * 1024 * 1024 * 1024,
+                    devices: 1,
+                },
+            ]
+        );
+    }
 }
@@ -1,7 +1,9 @@
-use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use super::memory_array_mapped_address::MappedAddress;
+use crate::core::{Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::{SMBiosMemoryArrayMappedAddress, SMBiosMemoryDevice, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
+use std::ops::Range;
 
 /// # Memory Device Mapped Address (Type 20)
 ///
@@ -115,8 +117,12 @@ impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
     /// contains zeros. When this field contains a valid
     /// address, Extended Ending Address must also
     /// contain a valid address.
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn extended_starting_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x13)
+        self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
+            .get_field_qword(0x13)
     }
 
     /// Physical ending address, in bytes, of the last of a
@@ -128,8 +134,80 @@ impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
     /// FFFFh, this field contains zeros. When this field
     /// contains a valid address, Extended Starting
     /// Address must also contain a valid address
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn extended_ending_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x1B)
+        self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
+            .get_field_qword(0x1B)
+    }
+
+    /// Physical starting address in bytes, from
+    /// [SMBiosMemoryDeviceMappedAddress::starting_address] or, when it is
+    /// FFFF FFFFh, [SMBiosMemoryDeviceMappedAddress::extended_starting_address]
+    pub fn starting_address_bytes(&self) -> Option<u64> {
+        self.mapped_address().starting_bytes()
+    }
+
+    /// Physical address of the last byte of the range, from
+    /// [SMBiosMemoryDeviceMappedAddress::ending_address] or, when both
+    /// Starting and Ending Address are FFFF FFFFh,
+    /// [SMBiosMemoryDeviceMappedAddress::extended_ending_address]
+    pub fn ending_address_bytes(&self) -> Option<u64> {
+        self.mapped_address().ending_bytes()
+    }
+
+    /// The mapped range of physical addresses in bytes
+    ///
+    /// `None` when the addresses are absent or the ending address is below
+    /// the starting address.
+    pub fn address_range(&self) -> Option<Range<u64>> {
+        self.mapped_address().range()
+    }
+
+    /// [SMBiosMemoryDeviceMappedAddress::partition_row_position] with FFh decoded as unknown
+    pub fn partition_row_position_decoded(&self) -> Option<PartitionRowPosition> {
+        self.partition_row_position()
+            .map(PartitionRowPosition::from)
+    }
+
+    /// [SMBiosMemoryDeviceMappedAddress::interleave_position] with 0 decoded as non-interleaved and FFh as unknown
+    pub fn interleave_position_decoded(&self) -> Option<InterleavePosition> {
+        self.interleave_position().map(InterleavePosition::from)
+    }
+
+    /// [SMBiosMemoryDeviceMappedAddress::interleaved_data_depth] with 0 decoded as non-interleaved and FFh as unknown
+    pub fn interleaved_data_depth_decoded(&self) -> Option<InterleavedDataDepth> {
+        self.interleaved_data_depth()
+            .map(InterleavedDataDepth::from)
+    }
+
+    /// The [SMBiosMemoryDevice] to which this range is mapped, found in `data`
+    ///
+    /// `None` when `data` has no Memory Device structure with the handle.
+    pub fn memory_device(&self, data: &'a SMBiosData) -> Option<SMBiosMemoryDevice<'a>> {
+        data.find_by_handle_as(&self.memory_device_handle()?)
+    }
+
+    /// The [SMBiosMemoryArrayMappedAddress] to which this range is mapped,
+    /// found in `data`
+    ///
+    /// `None` when `data` has no Memory Array Mapped Address structure with
+    /// the handle.
+    pub fn memory_array_mapped_address(
+        &self,
+        data: &'a SMBiosData,
+    ) -> Option<SMBiosMemoryArrayMappedAddress<'a>> {
+        data.find_by_handle_as(&self.memory_array_mapped_address_handle()?)
+    }
+
+    fn mapped_address(&self) -> MappedAddress {
+        MappedAddress {
+            starting: self.starting_address(),
+            ending: self.ending_address(),
+            extended_starting: self.extended_starting_address(),
+            extended_ending: self.extended_ending_address(),
+        }
     }
 }
 
@@ -182,6 +260,67 @@ impl Serialize for SMBiosMemoryDeviceMappedAddress<'_> {
     }
 }
 
+/// # Memory Device Mapped Address - Partition Row Position
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PartitionRowPosition {
+    /// Position of the device in a row of the address partition
+    Position(u8),
+    /// Position is unknown
+    Unknown,
+}
+
+impl From<u8> for PartitionRowPosition {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0xFF => PartitionRowPosition::Unknown,
+            _ => PartitionRowPosition::Position(raw),
+        }
+    }
+}
+
+/// # Memory Device Mapped Address - Interleave Position
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InterleavePosition {
+    /// The device is not interleaved
+    NonInterleaved,
+    /// Position of the device in the interleave, starting at 1
+    Position(u8),
+    /// Position is unknown
+    Unknown,
+}
+
+impl From<u8> for InterleavePosition {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => InterleavePosition::NonInterleaved,
+            0xFF => InterleavePosition::Unknown,
+            _ => InterleavePosition::Position(raw),
+        }
+    }
+}
+
+/// # Memory Device Mapped Address - Interleaved Data Depth
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InterleavedDataDepth {
+    /// The device is not part of an interleave
+    NonInterleaved,
+    /// Maximum number of consecutive rows accessed in a single interleaved
+    /// transfer
+    Rows(u8),
+    /// Interleave configuration is unknown
+    Unknown,
+}
+
+impl From<u8> for InterleavedDataDepth {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => InterleavedDataDepth::NonInterleaved,
+            0xFF => InterleavedDataDepth::Unknown,
+            _ => InterleavedDataDepth::Rows(raw),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,7 +346,126 @@ mod tests {
         assert_eq!(test_struct.partition_row_position(), Some(1));
         assert_eq!(test_struct.interleave_position(), Some(1));
         assert_eq!(test_struct.interleaved_data_depth(), Some(2));
+        assert_eq!(
+            test_struct.partition_row_position_decoded(),
+            Some(PartitionRowPosition::Position(1))
+        );
+        assert_eq!(
+            test_struct.interleave_position_decoded(),
+            Some(InterleavePosition::Position(1))
+        );
+        assert_eq!(
+            test_struct.interleaved_data_depth_decoded(),
+            Some(InterleavedDataDepth::Rows(2))
+        );
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+        assert_eq!(test_struct.address_range(), Some(0..8 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_version_gating() {
+        let struct_type20 = vec![
+            0x14, 0x23, 0x41, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x00,
+            0x3F, 0x00, 0x01, 0x01, 0x02, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xBB,
+            0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0x00, 0x00,
+        ];
+        let mut parts = UndefinedStruct::new(&struct_type20);
+
+        // The extended addresses were added in 2.7
+        parts.version = Some(SMBiosVersion::new(2, 6, 0));
+        let test_struct = SMBiosMemoryDeviceMappedAddress::new(&parts);
+        assert_eq!(test_struct.extended_starting_address(), None);
+        assert_eq!(test_struct.extended_ending_address(), None);
+
+        parts.version = Some(SMBiosVersion::new(2, 7, 0));
+        let test_struct = SMBiosMemoryDeviceMappedAddress::new(&parts);
+        assert_eq!(
+            test_struct.extended_starting_address(),
+            Some(0xAAAA_AAAA_AAAA_AAAA)
+        );
+        assert_eq!(
+            test_struct.extended_ending_address(),
+            Some(0xBBBB_BBBB_BBBB_BBBB)
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        use crate::core::{StructBuilder, TableBuilder};
+        use crate::MemoryDeviceBuilder;
+
+        let mut builder = TableBuilder::new();
+        let device = builder.add(MemoryDeviceBuilder::new().size_mb(64 * 1024));
+        let array_mapped_address = builder.add(
+            StructBuilder::with_length(SMBiosMemoryArrayMappedAddress::STRUCT_TYPE, 0x1F)
+                .dword(0x04, 0xFFFF_FFFF)
+                .dword(0x08, 0xFFFF_FFFF)
+                .qword(0x0F, 0x1_0000_0000)
+                .qword(0x17, 0x10_FFFF_FFFF),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE, 0x23)
+                .dword(0x04, 0xFFFF_FFFF)
+                .dword(0x08, 0xFFFF_FFFF)
+                .handle(0x0C, device)
+                .handle(0x0E, array_mapped_address)
+                .byte(0x10, 0xFF)
+                .byte(0x11, 0xFF)
+                .byte(0x12, 0xFF)
+                .qword(0x13, 0x1_0000_0000)
+                .qword(0x1B, 0x10_FFFF_FFFF),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE, 0x13)
+                .handle(0x0C, array_mapped_address)
+                .handle(0x0E, device),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let mapped_addresses = data.collect::<SMBiosMemoryDeviceMappedAddress<'_>>();
+        let test_struct = &mapped_addresses[0];
+        assert_eq!(
+            test_struct.address_range(),
+            Some(0x1_0000_0000..0x11_0000_0000)
+        );
+        assert_eq!(
+            test_struct.partition_row_position_decoded(),
+            Some(PartitionRowPosition::Unknown)
+        );
+        assert_eq!(
+            test_struct.interleave_position_decoded(),
+            Some(InterleavePosition::Unknown)
+        );
+        assert_eq!(
+            test_struct.interleaved_data_depth_decoded(),
+            Some(InterleavedDataDepth::Unknown)
+        );
+        assert_eq!(
+            test_struct
+                .memory_device(&data)
+                .unwrap()
+                .parts()
+                .header
+                .handle(),
+            device
+        );
+        assert_eq!(
+            test_struct
+                .memory_array_mapped_address(&data)
+                .unwrap()
+                .address_range(),
+            test_struct.address_range()
+        );
+
+        // Handles of the wrong structure types do not resolve
+        let test_struct = &mapped_addresses[1];
+        assert!(test_struct.memory_device(&data).is_none());
+        assert!(test_struct.memory_array_mapped_address(&data).is_none());
+        assert!(test_struct.extended_starting_address().is_none());
+        assert_eq!(
+            test_struct.interleave_position_decoded(),
+            Some(InterleavePosition::NonInterleaved)
+        );
     }
 }
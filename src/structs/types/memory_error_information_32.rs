@@ -1,4 +1,5 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::core::{Handle, SMBiosData};
+use crate::{SMBiosMemoryErrorInformation64, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -82,6 +83,27 @@ impl<'a> SMBiosMemoryErrorInformation32<'a> {
     pub fn error_resolution(&self) -> Option<u32> {
         self.parts.get_field_dword(0x13)
     }
+
+    /// [SMBiosMemoryErrorInformation32::vendor_syndrome] with 0 decoded as unknown
+    pub fn vendor_syndrome_decoded(&self) -> Option<MemoryErrorSyndrome> {
+        self.vendor_syndrome().map(MemoryErrorSyndrome::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation32::memory_array_error_address] with the unknown value decoded
+    pub fn memory_array_error_address_decoded(&self) -> Option<MemoryErrorAddress> {
+        self.memory_array_error_address()
+            .map(MemoryErrorAddress::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation32::device_error_address] with the unknown value decoded
+    pub fn device_error_address_decoded(&self) -> Option<MemoryErrorAddress> {
+        self.device_error_address().map(MemoryErrorAddress::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation32::error_resolution] with 8000 0000h decoded as unknown
+    pub fn error_resolution_decoded(&self) -> Option<MemoryErrorResolution> {
+        self.error_resolution().map(MemoryErrorResolution::from)
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryErrorInformation32<'_> {
@@ -123,6 +145,47 @@ impl Serialize for SMBiosMemoryErrorInformation32<'_> {
     }
 }
 
+/// # Memory Error Information referenced by a memory array or device
+///
+/// The structure identified by the Memory Error Information Handle of a
+/// [super::SMBiosPhysicalMemoryArray] or [super::SMBiosMemoryDevice].
+#[derive(Serialize, Debug)]
+pub enum MemoryErrorInformation<'a> {
+    /// The system does not provide the error information structure (FFFEh)
+    NotProvided,
+    /// No error was detected (FFFFh)
+    NoError,
+    /// [SMBiosMemoryErrorInformation32] (Type 18)
+    Bit32(SMBiosMemoryErrorInformation32<'a>),
+    /// [SMBiosMemoryErrorInformation64] (Type 33)
+    Bit64(SMBiosMemoryErrorInformation64<'a>),
+}
+
+impl<'a> MemoryErrorInformation<'a> {
+    /// Resolves a Memory Error Information Handle within `data`
+    ///
+    /// Returns `None` if `handle` refers to no structure in `data`, or to a
+    /// structure other than a 32-bit or 64-bit Memory Error Information.
+    pub fn resolve(handle: Handle, data: &'a SMBiosData) -> Option<Self> {
+        match handle {
+            Handle::NOT_PROVIDED => Some(MemoryErrorInformation::NotProvided),
+            Handle::NONE => Some(MemoryErrorInformation::NoError),
+            _ => {
+                let parts = data.find_by_handle(&handle)?;
+                match parts.header.struct_type() {
+                    SMBiosMemoryErrorInformation32::STRUCT_TYPE => Some(
+                        MemoryErrorInformation::Bit32(SMBiosMemoryErrorInformation32::new(parts)),
+                    ),
+                    SMBiosMemoryErrorInformation64::STRUCT_TYPE => Some(
+                        MemoryErrorInformation::Bit64(SMBiosMemoryErrorInformation64::new(parts)),
+                    ),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
 /// # Memory Error - Error Type Data
 pub struct MemoryErrorTypeData {
     /// Raw value
@@ -175,7 +238,7 @@ impl Deref for MemoryErrorTypeData {
 }
 
 /// # Memory Error - Error Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorType {
     /// Other
     Other,
@@ -268,6 +331,15 @@ impl Serialize for MemoryErrorGranularityData {
     }
 }
 
+impl fmt::Display for MemoryErrorGranularityData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryErrorGranularity::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryErrorGranularityData {
     type Target = MemoryErrorGranularity;
 
@@ -277,7 +349,7 @@ impl Deref for MemoryErrorGranularityData {
 }
 
 /// # Memory Error - Error Granularity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorGranularity {
     /// Other
     Other,
@@ -340,6 +412,15 @@ impl Serialize for MemoryErrorOperationData {
     }
 }
 
+impl fmt::Display for MemoryErrorOperationData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryErrorOperation::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryErrorOperationData {
     type Target = MemoryErrorOperation;
 
@@ -349,7 +430,7 @@ impl Deref for MemoryErrorOperationData {
 }
 
 /// # Memory Error - Error Operation
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorOperation {
     /// Other
     Other,
@@ -381,9 +462,79 @@ impl From<u8> for MemoryErrorOperationData {
     }
 }
 
+/// # Memory Error - Error Address
+///
+/// A physical address reported by [SMBiosMemoryErrorInformation32] or
+/// [SMBiosMemoryErrorInformation64].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryErrorAddress {
+    /// Physical address of the error
+    Address(u64),
+    /// Address is unknown
+    Unknown,
+}
+
+/// 8000 0000h is unknown
+impl From<u32> for MemoryErrorAddress {
+    fn from(raw: u32) -> Self {
+        match raw {
+            0x8000_0000 => MemoryErrorAddress::Unknown,
+            _ => MemoryErrorAddress::Address(raw as u64),
+        }
+    }
+}
+
+/// 8000 0000 0000 0000h is unknown
+impl From<u64> for MemoryErrorAddress {
+    fn from(raw: u64) -> Self {
+        match raw {
+            0x8000_0000_0000_0000 => MemoryErrorAddress::Unknown,
+            _ => MemoryErrorAddress::Address(raw),
+        }
+    }
+}
+
+/// # Memory Error - Vendor Syndrome
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryErrorSyndrome {
+    /// Vendor-specific ECC syndrome or CRC data
+    Syndrome(u32),
+    /// Syndrome is unknown
+    Unknown,
+}
+
+impl From<u32> for MemoryErrorSyndrome {
+    fn from(raw: u32) -> Self {
+        match raw {
+            0 => MemoryErrorSyndrome::Unknown,
+            _ => MemoryErrorSyndrome::Syndrome(raw),
+        }
+    }
+}
+
+/// # Memory Error - Error Resolution
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryErrorResolution {
+    /// Range, in bytes, within which the error can be determined
+    Bytes(u32),
+    /// Range is unknown
+    Unknown,
+}
+
+impl From<u32> for MemoryErrorResolution {
+    fn from(raw: u32) -> Self {
+        match raw {
+            0x8000_0000 => MemoryErrorResolution::Unknown,
+            _ => MemoryErrorResolution::Bytes(raw),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
+    use crate::{SMBiosMemoryDevice, SMBiosPhysicalMemoryArray};
 
     #[test]
     fn unit_test() {
@@ -408,5 +559,92 @@ mod tests {
         assert_eq!(test_struct.memory_array_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.device_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+        assert_eq!(
+            test_struct.vendor_syndrome_decoded(),
+            Some(MemoryErrorSyndrome::Unknown)
+        );
+        assert_eq!(
+            test_struct.memory_array_error_address_decoded(),
+            Some(MemoryErrorAddress::Unknown)
+        );
+        assert_eq!(
+            test_struct.device_error_address_decoded(),
+            Some(MemoryErrorAddress::Unknown)
+        );
+        assert_eq!(
+            test_struct.error_resolution_decoded(),
+            Some(MemoryErrorResolution::Unknown)
+        );
+        assert_eq!(
+            test_struct.error_granularity().unwrap().to_string(),
+            "Unknown"
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut builder = TableBuilder::new();
+        let error32 = builder.add(
+            StructBuilder::with_length(SMBiosMemoryErrorInformation32::STRUCT_TYPE, 0x17)
+                .byte(0x04, 0x03),
+        );
+        let error64 = builder.add(
+            StructBuilder::with_length(SMBiosMemoryErrorInformation64::STRUCT_TYPE, 0x1F)
+                .byte(0x04, 0x06),
+        );
+        let array = builder.add(
+            StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x17)
+                .handle(0x0B, error64),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15)
+                .handle(0x04, array)
+                .handle(0x06, error32),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15)
+                .handle(0x04, array)
+                .handle(0x06, Handle::NONE),
+        );
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15)
+                .handle(0x04, array)
+                .handle(0x06, Handle::NOT_PROVIDED),
+        );
+        // Refers to the memory array rather than an error information structure
+        builder.add(
+            StructBuilder::with_length(SMBiosMemoryDevice::STRUCT_TYPE, 0x15)
+                .handle(0x04, array)
+                .handle(0x06, array),
+        );
+        let data = builder.build_smbios_data(None);
+
+        match data
+            .first::<SMBiosPhysicalMemoryArray<'_>>()
+            .unwrap()
+            .memory_error_information(&data)
+        {
+            Some(MemoryErrorInformation::Bit64(error)) => {
+                assert_eq!(error.parts().header.handle(), error64)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let devices: Vec<SMBiosMemoryDevice<'_>> = data.collect();
+        match devices[0].memory_error_information(&data) {
+            Some(MemoryErrorInformation::Bit32(error)) => {
+                assert_eq!(*error.error_type().unwrap(), MemoryErrorType::OK)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(
+            devices[1].memory_error_information(&data),
+            Some(MemoryErrorInformation::NoError)
+        ));
+        assert!(matches!(
+            devices[2].memory_error_information(&data),
+            Some(MemoryErrorInformation::NotProvided)
+        ));
+        assert!(devices[3].memory_error_information(&data).is_none());
     }
 }
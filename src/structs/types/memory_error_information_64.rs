@@ -1,6 +1,6 @@
 use crate::{
-    MemoryErrorGranularityData, MemoryErrorOperationData, MemoryErrorTypeData, SMBiosStruct,
-    UndefinedStruct,
+    MemoryErrorAddress, MemoryErrorGranularityData, MemoryErrorOperationData,
+    MemoryErrorResolution, MemoryErrorSyndrome, MemoryErrorTypeData, SMBiosStruct, UndefinedStruct,
 };
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
@@ -87,6 +87,27 @@ impl<'a> SMBiosMemoryErrorInformation64<'a> {
     pub fn error_resolution(&self) -> Option<u32> {
         self.parts.get_field_dword(0x1B)
     }
+
+    /// [SMBiosMemoryErrorInformation64::vendor_syndrome] with 0 decoded as unknown
+    pub fn vendor_syndrome_decoded(&self) -> Option<MemoryErrorSyndrome> {
+        self.vendor_syndrome().map(MemoryErrorSyndrome::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation64::memory_array_error_address] with the unknown value decoded
+    pub fn memory_array_error_address_decoded(&self) -> Option<MemoryErrorAddress> {
+        self.memory_array_error_address()
+            .map(MemoryErrorAddress::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation64::device_error_address] with the unknown value decoded
+    pub fn device_error_address_decoded(&self) -> Option<MemoryErrorAddress> {
+        self.device_error_address().map(MemoryErrorAddress::from)
+    }
+
+    /// [SMBiosMemoryErrorInformation64::error_resolution] with 8000 0000h decoded as unknown
+    pub fn error_resolution_decoded(&self) -> Option<MemoryErrorResolution> {
+        self.error_resolution().map(MemoryErrorResolution::from)
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryErrorInformation64<'_> {
@@ -131,6 +152,7 @@ impl Serialize for SMBiosMemoryErrorInformation64<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder};
     use crate::{
         MemoryErrorGranularity, MemoryErrorOperation, MemoryErrorType, SMBiosStruct,
         UndefinedStruct,
@@ -166,5 +188,64 @@ mod tests {
             Some(0x8000_0000_0000_0000)
         );
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+        assert_eq!(
+            test_struct.vendor_syndrome_decoded(),
+            Some(MemoryErrorSyndrome::Unknown)
+        );
+        assert_eq!(
+            test_struct.memory_array_error_address_decoded(),
+            Some(MemoryErrorAddress::Unknown)
+        );
+        assert_eq!(
+            test_struct.device_error_address_decoded(),
+            Some(MemoryErrorAddress::Unknown)
+        );
+        assert_eq!(
+            test_struct.error_resolution_decoded(),
+            Some(MemoryErrorResolution::Unknown)
+        );
+    }
+
+    #[test]
+    fn test_known_values() {
+        let struct_type33 =
+            StructBuilder::with_length(SMBiosMemoryErrorInformation64::STRUCT_TYPE, 0x1F)
+                .byte(0x04, 0x0E)
+                .byte(0x05, 0x03)
+                .byte(0x06, 0x03)
+                .dword(0x07, 0x5A)
+                .qword(0x0B, 0x2_0000_1000_0000)
+                .qword(0x13, 0x1000)
+                .dword(0x1B, 0x40)
+                .build(Handle(0x0050));
+
+        let parts = UndefinedStruct::new(&struct_type33);
+        let test_struct = SMBiosMemoryErrorInformation64::new(&parts);
+
+        assert_eq!(
+            test_struct.error_type().unwrap().to_string(),
+            "UncorrectableError"
+        );
+        assert_eq!(
+            test_struct.error_granularity().unwrap().to_string(),
+            "DeviceLevel"
+        );
+        assert_eq!(test_struct.error_operation().unwrap().to_string(), "Read");
+        assert_eq!(
+            test_struct.vendor_syndrome_decoded(),
+            Some(MemoryErrorSyndrome::Syndrome(0x5A))
+        );
+        assert_eq!(
+            test_struct.memory_array_error_address_decoded(),
+            Some(MemoryErrorAddress::Address(0x2_0000_1000_0000))
+        );
+        assert_eq!(
+            test_struct.device_error_address_decoded(),
+            Some(MemoryErrorAddress::Address(0x1000))
+        );
+        assert_eq!(
+            test_struct.error_resolution_decoded(),
+            Some(MemoryErrorResolution::Bytes(0x40))
+        );
     }
 }
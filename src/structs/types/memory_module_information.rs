@@ -2,6 +2,7 @@ use crate::core::{strings::*, UndefinedStruct};
 use crate::{MemoryTypes, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
+use std::ops::Deref;
 
 /// # Memory Module Information (Type 6, Obsolete)
 ///
@@ -73,6 +74,36 @@ impl<'a> SMBiosMemoryModuleInformation<'a> {
     pub fn error_status(&self) -> Option<u8> {
         self.parts.get_field_byte(0x0B)
     }
+
+    /// [SMBiosMemoryModuleInformation::bank_connections] decoded into its
+    /// two banks
+    pub fn bank_connections_decoded(&self) -> Option<BankConnections> {
+        self.bank_connections().map(BankConnections::from)
+    }
+
+    /// [SMBiosMemoryModuleInformation::current_speed] with 0 decoded as
+    /// unknown
+    pub fn current_speed_decoded(&self) -> Option<ModuleSpeed> {
+        self.current_speed().map(ModuleSpeed::from)
+    }
+
+    /// [SMBiosMemoryModuleInformation::installed_size] decoded into its
+    /// size and bank connection
+    pub fn installed_size_decoded(&self) -> Option<MemoryModuleSize> {
+        self.installed_size().map(MemoryModuleSize::from)
+    }
+
+    /// [SMBiosMemoryModuleInformation::enabled_size] decoded into its
+    /// size and bank connection
+    pub fn enabled_size_decoded(&self) -> Option<MemoryModuleSize> {
+        self.enabled_size().map(MemoryModuleSize::from)
+    }
+
+    /// [SMBiosMemoryModuleInformation::error_status] decoded into its
+    /// error bits
+    pub fn error_status_decoded(&self) -> Option<ModuleErrorStatus> {
+        self.error_status().map(ModuleErrorStatus::from)
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryModuleInformation<'_> {
@@ -108,6 +139,246 @@ impl Serialize for SMBiosMemoryModuleInformation<'_> {
     }
 }
 
+/// # Memory Module Bank Connections
+///
+/// Each nibble holds a bank (RAS#) connected to the socket; 0xF means no
+/// connection.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct BankConnections {
+    /// Raw value
+    pub raw: u8,
+}
+
+impl BankConnections {
+    const NO_CONNECTION: u8 = 0x0F;
+
+    /// The bank held in the upper nibble, `None` when not connected
+    pub fn first(&self) -> Option<u8> {
+        Self::bank(self.raw >> 4)
+    }
+
+    /// The bank held in the lower nibble, `None` when not connected
+    pub fn second(&self) -> Option<u8> {
+        Self::bank(self.raw & 0x0F)
+    }
+
+    /// The connected banks
+    pub fn banks(&self) -> Vec<u8> {
+        self.first().into_iter().chain(self.second()).collect()
+    }
+
+    fn bank(nibble: u8) -> Option<u8> {
+        match nibble {
+            Self::NO_CONNECTION => None,
+            _ => Some(nibble),
+        }
+    }
+}
+
+impl Deref for BankConnections {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u8> for BankConnections {
+    fn from(raw: u8) -> Self {
+        BankConnections { raw }
+    }
+}
+
+impl fmt::Debug for BankConnections {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<BankConnections>())
+            .field("raw", &self.raw)
+            .field("banks", &self.banks())
+            .finish()
+    }
+}
+
+impl Serialize for BankConnections {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BankConnections", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("banks", &self.banks())?;
+        state.end()
+    }
+}
+
+/// # Memory Module Speed
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ModuleSpeed {
+    /// The speed is unknown
+    Unknown,
+    /// Speed in nanoseconds
+    Nanoseconds(u8),
+}
+
+impl From<u8> for ModuleSpeed {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => ModuleSpeed::Unknown,
+            _ => ModuleSpeed::Nanoseconds(raw),
+        }
+    }
+}
+
+/// # Memory Module Size
+///
+/// Bits 6:0 hold the size and bit 7 is set for a double-bank connection.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct MemoryModuleSize {
+    /// Raw value
+    pub raw: u8,
+}
+
+impl MemoryModuleSize {
+    /// The size, decoded from bits 6:0
+    pub fn size(&self) -> ModuleSize {
+        match self.raw & 0x7F {
+            0x7D => ModuleSize::NotDeterminable,
+            0x7E => ModuleSize::NotEnabled,
+            0x7F => ModuleSize::NotInstalled,
+            exponent => ModuleSize::PowerOfTwoMegabytes(exponent),
+        }
+    }
+
+    /// The size in megabytes, `None` when there is no size to report
+    pub fn megabytes(&self) -> Option<u64> {
+        match self.size() {
+            ModuleSize::PowerOfTwoMegabytes(exponent) => 1u64.checked_shl(exponent as u32),
+            _ => None,
+        }
+    }
+
+    /// Double-bank connection (bit 7), otherwise single-bank
+    pub fn double_bank(&self) -> bool {
+        self.raw & 0x80 == 0x80
+    }
+}
+
+impl Deref for MemoryModuleSize {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u8> for MemoryModuleSize {
+    fn from(raw: u8) -> Self {
+        MemoryModuleSize { raw }
+    }
+}
+
+impl fmt::Debug for MemoryModuleSize {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<MemoryModuleSize>())
+            .field("raw", &self.raw)
+            .field("size", &self.size())
+            .field("double_bank", &self.double_bank())
+            .finish()
+    }
+}
+
+impl Serialize for MemoryModuleSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MemoryModuleSize", 3)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("double_bank", &self.double_bank())?;
+        state.end()
+    }
+}
+
+/// # Memory Module Size Value
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ModuleSize {
+    /// The size is 2**n MB
+    PowerOfTwoMegabytes(u8),
+    /// The size could not be determined (7Dh)
+    NotDeterminable,
+    /// A module is installed but not enabled (7Eh)
+    NotEnabled,
+    /// No module is installed (7Fh)
+    NotInstalled,
+}
+
+/// # Memory Module Error Status
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct ModuleErrorStatus {
+    /// Raw value
+    pub raw: u8,
+}
+
+impl ModuleErrorStatus {
+    /// Uncorrectable errors received for the module
+    ///
+    /// Only meaningful when [ModuleErrorStatus::see_event_log] is false.
+    pub fn uncorrectable_errors(&self) -> bool {
+        self.raw & 0x01 == 0x01
+    }
+
+    /// Correctable errors received for the module
+    ///
+    /// Only meaningful when [ModuleErrorStatus::see_event_log] is false.
+    pub fn correctable_errors(&self) -> bool {
+        self.raw & 0x02 == 0x02
+    }
+
+    /// The error status is to be obtained from the event log
+    pub fn see_event_log(&self) -> bool {
+        self.raw & 0x04 == 0x04
+    }
+}
+
+impl Deref for ModuleErrorStatus {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u8> for ModuleErrorStatus {
+    fn from(raw: u8) -> Self {
+        ModuleErrorStatus { raw }
+    }
+}
+
+impl fmt::Debug for ModuleErrorStatus {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<ModuleErrorStatus>())
+            .field("raw", &self.raw)
+            .field("uncorrectable_errors", &self.uncorrectable_errors())
+            .field("correctable_errors", &self.correctable_errors())
+            .field("see_event_log", &self.see_event_log())
+            .finish()
+    }
+}
+
+impl Serialize for ModuleErrorStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ModuleErrorStatus", 4)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("uncorrectable_errors", &self.uncorrectable_errors())?;
+        state.serialize_field("correctable_errors", &self.correctable_errors())?;
+        state.serialize_field("see_event_log", &self.see_event_log())?;
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,5 +414,53 @@ mod tests {
         assert!(memory_types.simm());
         assert_eq!(test_struct.installed_size(), Some(0x7D));
         assert_eq!(test_struct.enabled_size(), Some(0x83));
+
+        let banks = test_struct.bank_connections_decoded().unwrap();
+        assert_eq!(*banks, 0x01);
+        assert_eq!(banks.banks(), vec![0, 1]);
+        assert_eq!(
+            test_struct.current_speed_decoded(),
+            Some(ModuleSpeed::Nanoseconds(0b00000010))
+        );
+
+        let installed_size = test_struct.installed_size_decoded().unwrap();
+        assert_eq!(*installed_size, 0x7D);
+        assert_eq!(installed_size.size(), ModuleSize::NotDeterminable);
+        assert_eq!(installed_size.megabytes(), None);
+
+        let enabled_size = test_struct.enabled_size_decoded().unwrap();
+        assert_eq!(*enabled_size, 0x83);
+        assert_eq!(enabled_size.size(), ModuleSize::PowerOfTwoMegabytes(3));
+        assert_eq!(enabled_size.megabytes(), Some(8));
+        assert!(enabled_size.double_bank());
+
+        let error_status = test_struct.error_status_decoded().unwrap();
+        assert!(!error_status.uncorrectable_errors());
+        assert!(!error_status.correctable_errors());
+        assert!(!error_status.see_event_log());
+    }
+
+    #[test]
+    fn test_decoding() {
+        let banks = BankConnections::from(0x2F);
+        assert_eq!(banks.first(), Some(2));
+        assert_eq!(banks.second(), None);
+        assert_eq!(BankConnections::from(0x13).banks(), vec![1, 3]);
+        assert!(BankConnections::from(0xFF).banks().is_empty());
+
+        assert_eq!(ModuleSpeed::from(0), ModuleSpeed::Unknown);
+        assert_eq!(ModuleSpeed::from(70), ModuleSpeed::Nanoseconds(70));
+
+        assert_eq!(MemoryModuleSize::from(0x7E).size(), ModuleSize::NotEnabled);
+        let not_installed = MemoryModuleSize::from(0xFF);
+        assert_eq!(not_installed.size(), ModuleSize::NotInstalled);
+        assert!(not_installed.double_bank());
+        assert_eq!(MemoryModuleSize::from(0x07).megabytes(), Some(128));
+        assert_eq!(MemoryModuleSize::from(0x7C).megabytes(), None);
+
+        let error_status = ModuleErrorStatus::from(0x03);
+        assert!(error_status.uncorrectable_errors());
+        assert!(error_status.correctable_errors());
+        assert!(ModuleErrorStatus::from(0x04).see_event_log());
     }
 }
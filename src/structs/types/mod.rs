@@ -9,146 +9,241 @@ pub use unknown::*;
 mod bios_information;
 pub use bios_information::*;
 
+#[cfg(feature = "oem")]
 mod additional_information;
+#[cfg(feature = "oem")]
 pub use additional_information::*;
 
+#[cfg(feature = "platform")]
 mod baseboard_information;
+#[cfg(feature = "platform")]
 pub use baseboard_information::*;
 
+#[cfg(feature = "platform")]
 mod bios_language_information;
+#[cfg(feature = "platform")]
 pub use bios_language_information::*;
 
+#[cfg(feature = "platform")]
 mod bis_entry_point;
+#[cfg(feature = "platform")]
 pub use bis_entry_point::*;
 
+#[cfg(feature = "platform")]
 mod built_in_pointing_device;
+#[cfg(feature = "platform")]
 pub use built_in_pointing_device::*;
 
+#[cfg(feature = "processor")]
 mod cache_information;
+#[cfg(feature = "processor")]
 pub use cache_information::*;
 
+#[cfg(feature = "sensors")]
 mod cooling_device;
+#[cfg(feature = "sensors")]
 pub use cooling_device::*;
 
+#[cfg(feature = "sensors")]
 mod electrical_current_probe;
+#[cfg(feature = "sensors")]
 pub use electrical_current_probe::*;
 
 mod end_of_table;
 pub use end_of_table::*;
 
+#[cfg(feature = "platform")]
 mod group_associations;
+#[cfg(feature = "platform")]
 pub use group_associations::*;
 
+#[cfg(feature = "platform")]
 mod hardware_security;
+#[cfg(feature = "platform")]
 pub use hardware_security::*;
 
 mod inactive;
 pub use inactive::*;
 
+#[cfg(feature = "platform")]
 mod ipmi_device_information;
+#[cfg(feature = "platform")]
 pub use ipmi_device_information::*;
 
+#[cfg(feature = "platform")]
 mod management_controller_host_interface;
+#[cfg(feature = "platform")]
 pub use management_controller_host_interface::*;
 
+#[cfg(feature = "sensors")]
 mod management_device;
+#[cfg(feature = "sensors")]
 pub use management_device::*;
 
+#[cfg(feature = "sensors")]
 mod management_device_component;
+#[cfg(feature = "sensors")]
 pub use management_device_component::*;
 
+#[cfg(feature = "sensors")]
 mod management_device_threshold_data;
+#[cfg(feature = "sensors")]
 pub use management_device_threshold_data::*;
 
+#[cfg(feature = "memory")]
 mod memory_array_mapped_address;
+#[cfg(feature = "memory")]
 pub use memory_array_mapped_address::*;
 
+#[cfg(feature = "memory")]
 mod memory_channel;
+#[cfg(feature = "memory")]
 pub use memory_channel::*;
 
+#[cfg(feature = "memory")]
 mod memory_controller_information;
+#[cfg(feature = "memory")]
 pub use memory_controller_information::*;
 
+#[cfg(feature = "memory")]
 mod memory_device;
+#[cfg(feature = "memory")]
 pub use memory_device::*;
 
+#[cfg(feature = "memory")]
 mod memory_device_mapped_address;
+#[cfg(feature = "memory")]
 pub use memory_device_mapped_address::*;
 
+#[cfg(feature = "memory")]
 mod memory_error_information_32;
+#[cfg(feature = "memory")]
 pub use memory_error_information_32::*;
 
+#[cfg(feature = "memory")]
 mod memory_error_information_64;
+#[cfg(feature = "memory")]
 pub use memory_error_information_64::*;
 
+#[cfg(feature = "memory")]
 mod memory_module_information;
+#[cfg(feature = "memory")]
 pub use memory_module_information::*;
 
+#[cfg(feature = "oem")]
 mod oem_strings;
+#[cfg(feature = "oem")]
 pub use oem_strings::*;
 
+#[cfg(feature = "platform")]
 mod on_board_device_information;
+#[cfg(feature = "platform")]
 pub use on_board_device_information::*;
 
+#[cfg(feature = "platform")]
 mod onboard_devices_extended_information;
+#[cfg(feature = "platform")]
 pub use onboard_devices_extended_information::*;
 
+#[cfg(feature = "platform")]
 mod out_of_band_remote_access;
+#[cfg(feature = "platform")]
 pub use out_of_band_remote_access::*;
 
+#[cfg(feature = "memory")]
 mod physical_memory_array;
+#[cfg(feature = "memory")]
 pub use physical_memory_array::*;
 
+#[cfg(feature = "platform")]
 mod portable_battery;
+#[cfg(feature = "platform")]
 pub use portable_battery::*;
 
+#[cfg(feature = "platform")]
 mod port_connector_information;
+#[cfg(feature = "platform")]
 pub use port_connector_information::*;
 
+#[cfg(feature = "sensors")]
+mod probe;
+#[cfg(feature = "sensors")]
+pub use probe::*;
+
+#[cfg(feature = "processor")]
 mod processor_additional_information;
+#[cfg(feature = "processor")]
 pub use processor_additional_information::*;
 
+#[cfg(feature = "processor")]
 mod processor_information;
+#[cfg(feature = "processor")]
 pub use processor_information::*;
 
+#[cfg(feature = "platform")]
 mod system_boot_information;
+#[cfg(feature = "platform")]
 pub use system_boot_information::*;
 
+#[cfg(feature = "platform")]
 mod system_chassis_information;
+#[cfg(feature = "platform")]
 pub use system_chassis_information::*;
 
+#[cfg(feature = "oem")]
 mod system_configuration_options;
+#[cfg(feature = "oem")]
 pub use system_configuration_options::*;
 
+#[cfg(feature = "platform")]
 mod system_event_log;
+#[cfg(feature = "platform")]
 pub use system_event_log::*;
 
 mod system_information;
 pub use system_information::*;
 
+#[cfg(feature = "platform")]
 mod system_power_controls;
+#[cfg(feature = "platform")]
 pub use system_power_controls::*;
 
+#[cfg(feature = "platform")]
 mod system_power_supply;
+#[cfg(feature = "platform")]
 pub use system_power_supply::*;
 
+#[cfg(feature = "platform")]
 mod system_reset;
+#[cfg(feature = "platform")]
 pub use system_reset::*;
 
+#[cfg(feature = "platform")]
 mod system_slot;
+#[cfg(feature = "platform")]
 pub use system_slot::*;
 
+#[cfg(feature = "sensors")]
 mod temperature_probe;
+#[cfg(feature = "sensors")]
 pub use temperature_probe::*;
 
+#[cfg(feature = "platform")]
 mod tpm_device;
+#[cfg(feature = "platform")]
 pub use tpm_device::*;
 
+#[cfg(feature = "sensors")]
 mod voltage_probe;
+#[cfg(feature = "sensors")]
 pub use voltage_probe::*;
 
+#[cfg(feature = "platform")]
 mod firmware_inventory_information;
+#[cfg(feature = "platform")]
 pub use firmware_inventory_information::*;
 
+#[cfg(feature = "platform")]
 mod string_property;
+#[cfg(feature = "platform")]
 pub use string_property::*;
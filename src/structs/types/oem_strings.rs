@@ -1,4 +1,4 @@
-use crate::{SMBiosStringSet, SMBiosStruct, UndefinedStruct};
+use crate::{CountedStringIterator, SMBiosStringSet, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -36,6 +36,11 @@ impl<'a> SMBiosOemStrings<'a> {
     pub fn oem_strings(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// Iterates over the [count](Self::count) OEM strings in order
+    pub fn oem_string_iterator(&self) -> OemStringIterator<'a> {
+        CountedStringIterator::new(&self.parts.strings, self.count().unwrap_or(0))
+    }
 }
 
 impl fmt::Debug for SMBiosOemStrings<'_> {
@@ -61,9 +66,13 @@ impl Serialize for SMBiosOemStrings<'_> {
     }
 }
 
+/// # OEM String Iterator for the strings contained within [SMBiosOemStrings]
+pub type OemStringIterator<'a> = CountedStringIterator<'a>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder, TableBuilder};
 
     #[test]
     fn unit_test() {
@@ -98,4 +107,36 @@ mod tests {
             Some("BUILDID#13WWCDC8601#SABA#DABA;".to_string())
         );
     }
+
+    #[test]
+    fn test_oem_string_iterator() {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::new(SMBiosOemStrings::STRUCT_TYPE)
+                .byte(0x04, 2)
+                .push_string("Asset Tag: 1234")
+                .push_string("Provisioned"),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let strings: Vec<String> = data
+            .first::<SMBiosOemStrings<'_>>()
+            .unwrap()
+            .oem_string_iterator()
+            .map(|string| string.to_string())
+            .collect();
+        assert_eq!(strings, vec!["Asset Tag: 1234", "Provisioned"]);
+
+        // A count larger than the string-set yields the missing strings as errors
+        let raw = StructBuilder::new(SMBiosOemStrings::STRUCT_TYPE)
+            .byte(0x04, 2)
+            .push_string("Only one")
+            .build(Handle(0));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosOemStrings::new(&parts);
+        let mut iter = test_struct.oem_string_iterator();
+        assert_eq!(iter.next().unwrap().ok(), Some("Only one".to_string()));
+        assert!(iter.next().unwrap().ok().is_none());
+        assert!(iter.next().is_none());
+    }
 }
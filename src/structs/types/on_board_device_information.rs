@@ -37,11 +37,11 @@ impl<'a> SMBiosOnBoardDeviceInformation<'a> {
     pub fn number_of_devices(&self) -> usize {
         let struct_length = self.parts().header.length() as usize;
 
-        (struct_length - Header::SIZE) / OnBoardDevice::SIZE
+        struct_length.saturating_sub(Header::SIZE) / OnBoardDevice::SIZE
     }
 
     /// Iterates over the [OnBoardDevice] entries
-    pub fn onboard_device_iterator(&'a self) -> OnBoardDeviceIterator<'a> {
+    pub fn onboard_device_iterator(&self) -> OnBoardDeviceIterator<'a> {
         OnBoardDeviceIterator::new(self)
     }
 }
@@ -71,7 +71,7 @@ impl Serialize for SMBiosOnBoardDeviceInformation<'_> {
 
 /// # On Board Device entry within [SMBiosOnBoardDeviceInformation]
 pub struct OnBoardDevice<'a> {
-    onboard_device_information: &'a SMBiosOnBoardDeviceInformation<'a>,
+    parts: &'a UndefinedStruct,
     entry_offset: usize,
 }
 
@@ -84,29 +84,36 @@ impl<'a> OnBoardDevice<'a> {
     /// for a total size of two bytes.
     const SIZE: usize = 2;
 
-    fn new(
-        onboard_device_information: &'a SMBiosOnBoardDeviceInformation<'a>,
-        entry_offset: usize,
-    ) -> Self {
+    fn new(parts: &'a UndefinedStruct, entry_offset: usize) -> Self {
         Self {
-            onboard_device_information,
+            parts,
             entry_offset,
         }
     }
 
     /// Device type
     pub fn device_type(&self) -> Option<OnBoardDeviceType> {
-        self.onboard_device_information
-            .parts()
+        self.parts
             .get_field_byte(self.entry_offset)
-            .map(|raw| OnBoardDeviceType::from(raw))
+            .map(OnBoardDeviceType::from)
     }
 
     /// Device description
     pub fn description(&self) -> SMBiosString {
-        self.onboard_device_information
-            .parts()
-            .get_field_string(self.entry_offset + 1)
+        self.parts.get_field_string(self.entry_offset + 1)
+    }
+
+    /// The kind of device, decoded from bits 6:0 of the device type
+    pub fn type_of_device(&self) -> Option<TypeOfDevice> {
+        self.device_type()
+            .map(|device_type| device_type.type_of_device())
+    }
+
+    /// Whether the device is enabled (bit 7 of the device type)
+    pub fn enabled(&self) -> bool {
+        self.device_type()
+            .map(|device_type| device_type.status() == DeviceStatus::Enabled)
+            .unwrap_or(false)
     }
 }
 
@@ -132,6 +139,7 @@ impl Serialize for OnBoardDevice<'_> {
 }
 
 /// # On Board Device Type
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct OnBoardDeviceType {
     /// Raw value
     pub raw: u8,
@@ -202,7 +210,7 @@ impl Serialize for OnBoardDeviceType {
 }
 
 /// # Onboard Device Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TypeOfDevice {
     /// Other
     Other,
@@ -241,7 +249,7 @@ pub enum TypeOfDevice {
 }
 
 /// # Enabled/Disabled Device Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DeviceStatus {
     /// Device is enabled
     Enabled,
@@ -251,7 +259,7 @@ pub enum DeviceStatus {
 
 /// # On-board Device Itereator for [OnBoardDevice]s contained within [SMBiosOnBoardDeviceInformation]
 pub struct OnBoardDeviceIterator<'a> {
-    data: &'a SMBiosOnBoardDeviceInformation<'a>,
+    parts: &'a UndefinedStruct,
     current_index: usize,
     current_entry: usize,
     number_of_entries: usize,
@@ -260,9 +268,9 @@ pub struct OnBoardDeviceIterator<'a> {
 impl<'a> OnBoardDeviceIterator<'a> {
     const DEVICES_OFFSET: usize = 4usize;
 
-    fn new(data: &'a SMBiosOnBoardDeviceInformation<'a>) -> Self {
+    fn new(data: &SMBiosOnBoardDeviceInformation<'a>) -> Self {
         OnBoardDeviceIterator {
-            data: data,
+            parts: data.parts(),
             current_index: Self::DEVICES_OFFSET,
             current_entry: 0,
             number_of_entries: data.number_of_devices(),
@@ -281,10 +289,10 @@ impl<'a> IntoIterator for &'a OnBoardDeviceIterator<'a> {
 
     fn into_iter(self) -> Self::IntoIter {
         OnBoardDeviceIterator {
-            data: self.data,
+            parts: self.parts,
             current_index: OnBoardDeviceIterator::DEVICES_OFFSET,
             current_entry: 0,
-            number_of_entries: self.data.number_of_devices(),
+            number_of_entries: self.number_of_entries,
         }
     }
 }
@@ -299,15 +307,11 @@ impl<'a> Iterator for OnBoardDeviceIterator<'a> {
         }
 
         let next_index = self.current_index + OnBoardDevice::SIZE;
-        match self
-            .data
-            .parts()
-            .get_field_data(self.current_index, next_index)
-        {
+        match self.parts.get_field_data(self.current_index, next_index) {
             Some(_) => {
-                let result = OnBoardDevice::new(self.data, self.current_index);
+                let result = OnBoardDevice::new(self.parts, self.current_index);
                 self.current_index = next_index;
-                self.current_entry = self.current_entry + 1;
+                self.current_entry += 1;
                 Some(result)
             }
             None => {
@@ -341,6 +345,7 @@ impl<'a> Serialize for OnBoardDeviceIterator<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
 
     #[test]
     fn unit_test() {
@@ -372,4 +377,41 @@ mod tests {
 
         assert!(iterator.next().is_none());
     }
+
+    #[test]
+    fn test_multiple_devices() {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::new(SMBiosOnBoardDeviceInformation::STRUCT_TYPE)
+                .byte(0x04, 0x83)
+                .string(0x05, "Onboard Video")
+                .byte(0x06, 0x05)
+                .string(0x07, "Onboard LAN")
+                .byte(0x08, 0x87)
+                .string(0x09, "Onboard Audio"),
+        );
+        let data = builder.build_smbios_data(None);
+
+        // Iterate straight from a temporary structure; the entries only
+        // borrow from the table.
+        let devices: Vec<OnBoardDevice<'_>> = data
+            .first::<SMBiosOnBoardDeviceInformation<'_>>()
+            .unwrap()
+            .onboard_device_iterator()
+            .collect();
+
+        assert_eq!(devices.len(), 3);
+
+        assert_eq!(devices[0].type_of_device(), Some(TypeOfDevice::Video));
+        assert!(devices[0].enabled());
+        assert_eq!(devices[0].description().to_string(), "Onboard Video");
+
+        assert_eq!(devices[1].type_of_device(), Some(TypeOfDevice::Ethernet));
+        assert!(!devices[1].enabled());
+        assert_eq!(devices[1].description().to_string(), "Onboard LAN");
+
+        assert_eq!(devices[2].type_of_device(), Some(TypeOfDevice::Sound));
+        assert!(devices[2].enabled());
+        assert_eq!(devices[2].description().to_string(), "Onboard Audio");
+    }
 }
@@ -38,14 +38,12 @@ impl<'a> SMBiosOutOfBandRemoteAccess<'a> {
 
     /// Current remote-access connections (bit field)
     pub fn connections(&self) -> Option<Connections> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| Connections::from(raw))
+        self.parts.get_field_byte(0x05).map(Connections::from)
     }
 }
 
 /// # Connections
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct Connections {
     /// Raw value
     pub raw: u8,
@@ -168,4 +166,22 @@ mod tests {
         assert!(connections.outbound_connection_enabled());
         assert_eq!(connections.raw, 0x03);
     }
+
+    #[test]
+    fn test_connections() {
+        use crate::core::{Handle, StructBuilder};
+
+        let raw = StructBuilder::with_length(SMBiosOutOfBandRemoteAccess::STRUCT_TYPE, 0x06)
+            .string(0x04, "BMC Vendor")
+            .byte(0x05, 0x01)
+            .build(Handle(0x3B));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosOutOfBandRemoteAccess::new(&parts);
+
+        assert_eq!(test_struct.manufacturer_name().to_string(), "BMC Vendor");
+        let connections = test_struct.connections().unwrap();
+        assert!(connections.inbound_connection_enabled());
+        assert!(!connections.outbound_connection_enabled());
+        assert_eq!(connections, Connections::from(0x01));
+    }
 }
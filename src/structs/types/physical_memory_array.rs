@@ -1,5 +1,5 @@
-use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::{MemoryErrorInformation, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::{fmt, ops::Deref};
 /// # Physical Memory Array (Type 16)
@@ -73,6 +73,14 @@ impl<'a> SMBiosPhysicalMemoryArray<'a> {
         self.parts.get_field_handle(0x0B)
     }
 
+    /// [SMBiosPhysicalMemoryArray::memory_error_information_handle] resolved within `data`
+    pub fn memory_error_information(
+        &self,
+        data: &'a SMBiosData,
+    ) -> Option<MemoryErrorInformation<'a>> {
+        MemoryErrorInformation::resolve(self.memory_error_information_handle()?, data)
+    }
+
     /// Number of slots or sockets available for [super::SMBiosMemoryDevice]s in this array
     ///
     /// This value represents the number of [super::SMBiosMemoryDevice]
@@ -90,8 +98,24 @@ impl<'a> SMBiosPhysicalMemoryArray<'a> {
     /// Maximum Capacity contains a value that is not
     /// 8000 0000h, Extended Maximum Capacity must
     /// contain zeros.
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn extended_maximum_capacity(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x0F)
+        self.parts
+            .since(SMBiosVersion::new(2, 7, 0))?
+            .get_field_qword(0x0F)
+    }
+
+    /// Maximum memory capacity, in bytes, for this array
+    ///
+    /// Taken from [SMBiosPhysicalMemoryArray::extended_maximum_capacity] when
+    /// [SMBiosPhysicalMemoryArray::maximum_capacity] is
+    /// [MaximumMemoryCapacity::SeeExtendedMaximumCapacity].
+    pub fn maximum_capacity_bytes(&self) -> Option<u64> {
+        match self.maximum_capacity()? {
+            MaximumMemoryCapacity::Kilobytes(kilobytes) => Some(kilobytes as u64 * 1024),
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity => self.extended_maximum_capacity(),
+        }
     }
 }
 
@@ -174,6 +198,15 @@ impl Serialize for MemoryArrayLocationData {
     }
 }
 
+impl fmt::Display for MemoryArrayLocationData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryArrayLocation::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryArrayLocationData {
     type Target = MemoryArrayLocation;
 
@@ -183,7 +216,7 @@ impl Deref for MemoryArrayLocationData {
 }
 
 /// # Memory Array - Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayLocation {
     /// Other
     Other = 0x01,
@@ -279,6 +312,15 @@ impl Serialize for MemoryArrayUseData {
     }
 }
 
+impl fmt::Display for MemoryArrayUseData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryArrayUse::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryArrayUseData {
     type Target = MemoryArrayUse;
 
@@ -288,7 +330,7 @@ impl Deref for MemoryArrayUseData {
 }
 
 /// # Memory Array - Use
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayUse {
     /// Other
     Other,
@@ -360,6 +402,15 @@ impl Serialize for MemoryArrayErrorCorrectionData {
     }
 }
 
+impl fmt::Display for MemoryArrayErrorCorrectionData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            MemoryArrayErrorCorrection::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for MemoryArrayErrorCorrectionData {
     type Target = MemoryArrayErrorCorrection;
 
@@ -369,7 +420,7 @@ impl Deref for MemoryArrayErrorCorrectionData {
 }
 
 /// # Memory Array - Error Correction Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayErrorCorrection {
     /// Other
     Other,
@@ -408,7 +459,7 @@ impl From<u8> for MemoryArrayErrorCorrectionData {
 }
 
 /// # Maximum memory capacity, in kilobytes, for this array
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MaximumMemoryCapacity {
     /// Maximum memory capacity in Kilobytes
     Kilobytes(u32),
@@ -428,6 +479,7 @@ impl From<u32> for MaximumMemoryCapacity {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::StructBuilder;
 
     #[test]
     fn unit_test() {
@@ -459,4 +511,72 @@ mod tests {
         assert_eq!(test_struct.number_of_memory_devices(), Some(4));
         assert_eq!(test_struct.extended_maximum_capacity(), Some(0));
     }
+
+    #[test]
+    fn test_version_gating() {
+        let raw = StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x17)
+            .dword(0x07, 0x8000_0000)
+            .qword(0x0F, 0xAAAA_AAAA_AAAA_AAAA)
+            .build(Handle(16));
+        let mut parts = UndefinedStruct::new(&raw);
+
+        // Extended Maximum Capacity was added in 2.7
+        parts.version = Some(SMBiosVersion::new(2, 6, 0));
+        let array = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(array.extended_maximum_capacity(), None);
+        assert_eq!(array.maximum_capacity_bytes(), None);
+
+        parts.version = Some(SMBiosVersion::new(2, 7, 0));
+        let array = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(
+            array.extended_maximum_capacity(),
+            Some(0xAAAA_AAAA_AAAA_AAAA)
+        );
+    }
+
+    #[test]
+    fn test_maximum_capacity_bytes() {
+        let build = |maximum_capacity: u32, extended_maximum_capacity: u64| {
+            StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x17)
+                .byte(0x04, 0x03)
+                .byte(0x05, 0x03)
+                .byte(0x06, 0x06)
+                .dword(0x07, maximum_capacity)
+                .qword(0x0F, extended_maximum_capacity)
+                .build(Handle(0x1000))
+        };
+
+        let raw = build(0x0200_0000, 0);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(test_struct.maximum_capacity_bytes(), Some(32 << 30));
+        assert_eq!(
+            test_struct.location().unwrap().to_string(),
+            "SystemBoardOrMotherboard"
+        );
+        assert_eq!(test_struct.usage().unwrap().to_string(), "SystemMemory");
+        assert_eq!(
+            test_struct.memory_error_correction().unwrap().to_string(),
+            "MultiBitEcc"
+        );
+
+        let raw = build(0x8000_0000, 4 << 40);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(
+            test_struct.maximum_capacity().unwrap(),
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity
+        );
+        assert_eq!(test_struct.maximum_capacity_bytes(), Some(4 << 40));
+
+        // Extended Maximum Capacity was added in 2.7
+        let raw = StructBuilder::with_length(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x0F)
+            .byte(0x04, 0xFF)
+            .dword(0x07, 0x8000_0000)
+            .build(Handle(0x1000));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(test_struct.maximum_capacity_bytes(), None);
+        assert_eq!(test_struct.location().unwrap().to_string(), "255");
+    }
 }
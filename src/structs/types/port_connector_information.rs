@@ -160,7 +160,7 @@ impl Deref for PortInformationConnectorTypeData {
 }
 
 /// # Port Information - Connector Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortInformationConnectorType {
     /// There is No Connector
     NoConnector,
@@ -181,7 +181,7 @@ pub enum PortInformationConnectorType {
     /// DB-9 pin male
     DB9PinMale,
     /// DB-9 pin female
-    DB8PinFemale,
+    DB9PinFemale,
     /// RJ-11
     RJ11,
     /// RJ-45
@@ -223,7 +223,7 @@ pub enum PortInformationConnectorType {
     /// Mini-Centronics Type-14
     MiniCentronicsType14,
     /// Mini-Centronics Type-26
-    MiniCentronicsTyp26,
+    MiniCentronicsType26,
     /// Mini-jack (headphones)
     MiniJackHeadphones,
     /// BNC
@@ -239,7 +239,7 @@ pub enum PortInformationConnectorType {
     /// PC-98Hireso
     PC98Hireso,
     /// PC-H98
-    PCH88,
+    PCH98,
     /// PC-98Note
     PC98Note,
     /// PC-98Full
@@ -250,6 +250,80 @@ pub enum PortInformationConnectorType {
     None,
 }
 
+impl PortInformationConnectorType {
+    /// DB-9 pin female, under its former misspelled name
+    #[deprecated(note = "use PortInformationConnectorType::DB9PinFemale")]
+    #[allow(non_upper_case_globals)]
+    pub const DB8PinFemale: PortInformationConnectorType =
+        PortInformationConnectorType::DB9PinFemale;
+
+    /// Mini-Centronics Type-26, under its former misspelled name
+    #[deprecated(note = "use PortInformationConnectorType::MiniCentronicsType26")]
+    #[allow(non_upper_case_globals)]
+    pub const MiniCentronicsTyp26: PortInformationConnectorType =
+        PortInformationConnectorType::MiniCentronicsType26;
+
+    /// PC-H98, under its former misspelled name
+    #[deprecated(note = "use PortInformationConnectorType::PCH98")]
+    #[allow(non_upper_case_globals)]
+    pub const PCH88: PortInformationConnectorType = PortInformationConnectorType::PCH98;
+
+    /// The name used by the specification, such as "RJ-45"
+    ///
+    /// `None` for a value unknown to this standard.
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            PortInformationConnectorType::NoConnector => Some("None"),
+            PortInformationConnectorType::Centronics => Some("Centronics"),
+            PortInformationConnectorType::MiniCentronics => Some("Mini Centronics"),
+            PortInformationConnectorType::Proprietary => Some("Proprietary"),
+            PortInformationConnectorType::DB25PinMale => Some("DB-25 pin male"),
+            PortInformationConnectorType::DB25PinFemale => Some("DB-25 pin female"),
+            PortInformationConnectorType::DB15PinMale => Some("DB-15 pin male"),
+            PortInformationConnectorType::DB15PinFemale => Some("DB-15 pin female"),
+            PortInformationConnectorType::DB9PinMale => Some("DB-9 pin male"),
+            PortInformationConnectorType::DB9PinFemale => Some("DB-9 pin female"),
+            PortInformationConnectorType::RJ11 => Some("RJ-11"),
+            PortInformationConnectorType::RJ45 => Some("RJ-45"),
+            PortInformationConnectorType::MiniScsi50Pin => Some("50-pin MiniSCSI"),
+            PortInformationConnectorType::MiniDin => Some("Mini-DIN"),
+            PortInformationConnectorType::MicroDin => Some("Micro-DIN"),
+            PortInformationConnectorType::Ps2 => Some("PS/2"),
+            PortInformationConnectorType::Infrared => Some("Infrared"),
+            PortInformationConnectorType::HpHil => Some("HP-HIL"),
+            PortInformationConnectorType::AccessBusUsb => Some("Access Bus (USB)"),
+            PortInformationConnectorType::SsaScsi => Some("SSA SCSI"),
+            PortInformationConnectorType::CircularDin8Male => Some("Circular DIN-8 male"),
+            PortInformationConnectorType::CircularDin8Female => Some("Circular DIN-8 female"),
+            PortInformationConnectorType::OnBoardIde => Some("On Board IDE"),
+            PortInformationConnectorType::OnBoardFloppy => Some("On Board Floppy"),
+            PortInformationConnectorType::DualInline9Pin => Some("9-pin Dual Inline (pin 10 cut)"),
+            PortInformationConnectorType::DualInline25Pin => {
+                Some("25-pin Dual Inline (pin 26 cut)")
+            }
+            PortInformationConnectorType::DualInline50Pin => Some("50-pin Dual Inline"),
+            PortInformationConnectorType::DualInline68Pin => Some("68-pin Dual Inline"),
+            PortInformationConnectorType::OnBoardSoundInputCDRom => {
+                Some("On Board Sound Input from CD-ROM")
+            }
+            PortInformationConnectorType::MiniCentronicsType14 => Some("Mini-Centronics Type-14"),
+            PortInformationConnectorType::MiniCentronicsType26 => Some("Mini-Centronics Type-26"),
+            PortInformationConnectorType::MiniJackHeadphones => Some("Mini-jack (headphones)"),
+            PortInformationConnectorType::Bnc => Some("BNC"),
+            PortInformationConnectorType::Port1394 => Some("1394"),
+            PortInformationConnectorType::SasSataPlugReceptacle => Some("SAS/SATA Plug Receptacle"),
+            PortInformationConnectorType::UsbTypeCReceptacle => Some("USB Type-C Receptacle"),
+            PortInformationConnectorType::PC98 => Some("PC-98"),
+            PortInformationConnectorType::PC98Hireso => Some("PC-98Hireso"),
+            PortInformationConnectorType::PCH98 => Some("PC-H98"),
+            PortInformationConnectorType::PC98Note => Some("PC-98Note"),
+            PortInformationConnectorType::PC98Full => Some("PC-98Full"),
+            PortInformationConnectorType::Other => Some("Other"),
+            PortInformationConnectorType::None => None,
+        }
+    }
+}
+
 impl From<u8> for PortInformationConnectorTypeData {
     fn from(raw: u8) -> Self {
         PortInformationConnectorTypeData {
@@ -263,7 +337,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x06 => PortInformationConnectorType::DB15PinMale,
                 0x07 => PortInformationConnectorType::DB15PinFemale,
                 0x08 => PortInformationConnectorType::DB9PinMale,
-                0x09 => PortInformationConnectorType::DB8PinFemale,
+                0x09 => PortInformationConnectorType::DB9PinFemale,
                 0x0A => PortInformationConnectorType::RJ11,
                 0x0B => PortInformationConnectorType::RJ45,
                 0x0C => PortInformationConnectorType::MiniScsi50Pin,
@@ -284,7 +358,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x1B => PortInformationConnectorType::DualInline68Pin,
                 0x1C => PortInformationConnectorType::OnBoardSoundInputCDRom,
                 0x1D => PortInformationConnectorType::MiniCentronicsType14,
-                0x1E => PortInformationConnectorType::MiniCentronicsTyp26,
+                0x1E => PortInformationConnectorType::MiniCentronicsType26,
                 0x1F => PortInformationConnectorType::MiniJackHeadphones,
                 0x20 => PortInformationConnectorType::Bnc,
                 0x21 => PortInformationConnectorType::Port1394,
@@ -292,7 +366,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x23 => PortInformationConnectorType::UsbTypeCReceptacle,
                 0xA0 => PortInformationConnectorType::PC98,
                 0xA1 => PortInformationConnectorType::PC98Hireso,
-                0xA2 => PortInformationConnectorType::PCH88,
+                0xA2 => PortInformationConnectorType::PCH98,
                 0xA3 => PortInformationConnectorType::PC98Note,
                 0xA4 => PortInformationConnectorType::PC98Full,
                 0xFF => PortInformationConnectorType::Other,
@@ -355,7 +429,7 @@ impl Deref for PortInformationPortTypeData {
 }
 
 /// # Port Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortInformationPortType {
     /// No Port
     NoPort,
@@ -393,10 +467,10 @@ pub enum PortInformationPortType {
     Usb,
     /// FireWire (IEEE P1394)
     Firewire,
-    /// PCMCIA Type I2
+    /// PCMCIA Type I
     PcmciaTypeI,
     /// PCMCIA Type II
-    PcmcialTypeII,
+    PcmciaTypeII,
     /// PCMCIA Type III
     PcmciaTypeIii,
     /// Cardbus
@@ -439,6 +513,71 @@ pub enum PortInformationPortType {
     None,
 }
 
+impl PortInformationPortType {
+    /// PCMCIA Type II, under its former misspelled name
+    #[deprecated(note = "use PortInformationPortType::PcmciaTypeII")]
+    #[allow(non_upper_case_globals)]
+    pub const PcmcialTypeII: PortInformationPortType = PortInformationPortType::PcmciaTypeII;
+
+    /// The name used by the specification, such as "USB"
+    ///
+    /// `None` for a value unknown to this standard.
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            PortInformationPortType::NoPort => Some("None"),
+            PortInformationPortType::ParallelPortXTATCompatible => {
+                Some("Parallel Port XT/AT Compatible")
+            }
+            PortInformationPortType::ParallelPortPS2 => Some("Parallel Port PS/2"),
+            PortInformationPortType::ParallelPortEcp => Some("Parallel Port ECP"),
+            PortInformationPortType::ParallelPortEpp => Some("Parallel Port EPP"),
+            PortInformationPortType::ParallelPortEcpEpp => Some("Parallel Port ECP/EPP"),
+            PortInformationPortType::SerialPortXTATCompatible => {
+                Some("Serial Port XT/AT Compatible")
+            }
+            PortInformationPortType::SerialPort16450Compatible => {
+                Some("Serial Port 16450 Compatible")
+            }
+            PortInformationPortType::SerialPort16550Compatible => {
+                Some("Serial Port 16550 Compatible")
+            }
+            PortInformationPortType::SerialPort16550ACompatible => {
+                Some("Serial Port 16550A Compatible")
+            }
+            PortInformationPortType::ScsiPort => Some("SCSI Port"),
+            PortInformationPortType::MidiPort => Some("MIDI Port"),
+            PortInformationPortType::JoyStickPort => Some("Joy Stick Port"),
+            PortInformationPortType::KeyboardPort => Some("Keyboard Port"),
+            PortInformationPortType::MousePort => Some("Mouse Port"),
+            PortInformationPortType::SsaScsi => Some("SSA SCSI"),
+            PortInformationPortType::Usb => Some("USB"),
+            PortInformationPortType::Firewire => Some("FireWire (IEEE P1394)"),
+            PortInformationPortType::PcmciaTypeI => Some("PCMCIA Type I"),
+            PortInformationPortType::PcmciaTypeII => Some("PCMCIA Type II"),
+            PortInformationPortType::PcmciaTypeIii => Some("PCMCIA Type III"),
+            PortInformationPortType::Cardbus => Some("Cardbus"),
+            PortInformationPortType::AccessBusPort => Some("Access Bus Port"),
+            PortInformationPortType::ScsiII => Some("SCSI II"),
+            PortInformationPortType::ScsiWide => Some("SCSI Wide"),
+            PortInformationPortType::PC98 => Some("PC-98"),
+            PortInformationPortType::PC98Hireso => Some("PC-98-Hireso"),
+            PortInformationPortType::PCH98 => Some("PC-H98"),
+            PortInformationPortType::VideoPort => Some("Video Port"),
+            PortInformationPortType::AudioPort => Some("Audio Port"),
+            PortInformationPortType::ModemPort => Some("Modem Port"),
+            PortInformationPortType::NetworkPort => Some("Network Port"),
+            PortInformationPortType::Sata => Some("SATA"),
+            PortInformationPortType::Sas => Some("SAS"),
+            PortInformationPortType::Mfdp => Some("MFDP (Multi-Function Display Port)"),
+            PortInformationPortType::Thunderbolt => Some("Thunderbolt"),
+            PortInformationPortType::Port8251Compatible => Some("8251 Compatible"),
+            PortInformationPortType::Port8251FifoCompatible => Some("8251 FIFO Compatible"),
+            PortInformationPortType::Other => Some("Other"),
+            PortInformationPortType::None => None,
+        }
+    }
+}
+
 impl From<u8> for PortInformationPortTypeData {
     fn from(raw: u8) -> Self {
         PortInformationPortTypeData {
@@ -462,7 +601,7 @@ impl From<u8> for PortInformationPortTypeData {
                 0x10 => PortInformationPortType::Usb,
                 0x11 => PortInformationPortType::Firewire,
                 0x12 => PortInformationPortType::PcmciaTypeI,
-                0x13 => PortInformationPortType::PcmcialTypeII,
+                0x13 => PortInformationPortType::PcmciaTypeII,
                 0x14 => PortInformationPortType::PcmciaTypeIii,
                 0x15 => PortInformationPortType::Cardbus,
                 0x16 => PortInformationPortType::AccessBusPort,
@@ -492,6 +631,7 @@ impl From<u8> for PortInformationPortTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder};
 
     #[test]
     fn unit_test() {
@@ -524,4 +664,64 @@ mod tests {
             PortInformationPortType::MousePort
         );
     }
+
+    #[test]
+    fn test_descriptions() {
+        let raw = StructBuilder::new(SMBiosPortConnectorInformation::STRUCT_TYPE)
+            .string(0x04, "JUSBC1")
+            .byte(0x05, 0x23)
+            .string(0x06, "USB-C")
+            .byte(0x07, 0x23)
+            .byte(0x08, 0x10)
+            .build(Handle(8));
+        let parts = UndefinedStruct::new(&raw);
+        let port = SMBiosPortConnectorInformation::new(&parts);
+
+        let connector = port.external_connector_type().unwrap();
+        assert_eq!(*connector, PortInformationConnectorType::UsbTypeCReceptacle);
+        assert_eq!(connector.description(), Some("USB Type-C Receptacle"));
+        assert_eq!(port.port_type().unwrap().description(), Some("USB"));
+
+        assert_eq!(
+            PortInformationConnectorTypeData::from(0x09).description(),
+            Some("DB-9 pin female")
+        );
+        assert_eq!(
+            PortInformationConnectorTypeData::from(0xFF).description(),
+            Some("Other")
+        );
+        assert_eq!(
+            PortInformationConnectorTypeData::from(0x80).description(),
+            None
+        );
+        assert_eq!(
+            PortInformationPortTypeData::from(0x0B).description(),
+            Some("MIDI Port")
+        );
+        assert_eq!(
+            PortInformationPortTypeData::from(0x1B).description(),
+            Some("PC-H98")
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_names() {
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0x09),
+            PortInformationConnectorType::DB8PinFemale
+        );
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0x1E),
+            PortInformationConnectorType::MiniCentronicsTyp26
+        );
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0xA2),
+            PortInformationConnectorType::PCH88
+        );
+        assert_eq!(
+            *PortInformationPortTypeData::from(0x13),
+            PortInformationPortType::PcmcialTypeII
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{strings::*, SMBiosVersion, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
@@ -128,22 +128,46 @@ impl<'a> SMBiosPortableBattery<'a> {
     /// Date, uniquely identifies the battery. The Serial
     /// Number field must be set to 0 (no string) for this
     /// field to be valid.
+    ///
+    /// Added in SMBIOS 2.2; absent from tables of earlier versions.
     pub fn sbds_serial_number(&self) -> Option<u16> {
-        self.parts.get_field_word(0x10)
+        self.parts
+            .since(SMBiosVersion::new(2, 2, 0))?
+            .get_field_word(0x10)
     }
 
     /// Date the cell pack was manufactured, in packed
     /// format
+    ///
+    /// Added in SMBIOS 2.2; absent from tables of earlier versions.
     pub fn sbds_manufacture_date(&self) -> Option<u16> {
-        self.parts.get_field_word(0x12)
+        self.parts
+            .since(SMBiosVersion::new(2, 2, 0))?
+            .get_field_word(0x12)
+    }
+
+    /// [SMBiosPortableBattery::sbds_manufacture_date] unpacked into a
+    /// year, month and day
+    ///
+    /// `None` when the field is absent or 0.
+    pub fn sbds_manufacture_date_decoded(&self) -> Option<SbdsManufactureDate> {
+        match self.sbds_manufacture_date()? {
+            0 => None,
+            raw => Some(SbdsManufactureDate::from(raw)),
+        }
     }
 
     /// Number of the string that identifies the battery
     /// chemistry (for example, “PbAc”)
     /// The Device Chemistry field must be set to 02h
     /// (Unknown) for this field to be valid.
+    ///
+    /// Added in SMBIOS 2.2; absent from tables of earlier versions.
     pub fn sbds_device_chemistry(&self) -> SMBiosString {
-        self.parts.get_field_string(0x14)
+        match self.parts.supports(SMBiosVersion::new(2, 2, 0)) {
+            true => self.parts.get_field_string(0x14),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// Multiplication factor of the Design Capacity
@@ -154,14 +178,41 @@ impl<'a> SMBiosPortableBattery<'a> {
     /// implementations use the value 10 to correspond
     /// to the data as returned from the SBDS Function
     /// 18h.
+    ///
+    /// Added in SMBIOS 2.2; absent from tables of earlier versions.
     pub fn design_capacity_multiplier(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
+        self.parts
+            .since(SMBiosVersion::new(2, 2, 0))?
+            .get_field_byte(0x15)
+    }
+
+    /// Design capacity of the battery in mWatt-hours, with the
+    /// 'design_capacity_multiplier' applied
+    ///
+    /// Structures older than version 2.2, which lack the multiplier, and a
+    /// multiplier of 0 use the default of 1.  `None` when the design
+    /// capacity is absent or unknown.
+    pub fn design_capacity_mwh(&self) -> Option<u32> {
+        match self.design_capacity()? {
+            PortableBatteryDesignCapacity::MilliWattHours(mwh) => {
+                let multiplier = match self.design_capacity_multiplier() {
+                    Some(0) | None => 1,
+                    Some(multiplier) => multiplier,
+                };
+                Some(mwh as u32 * multiplier as u32)
+            }
+            PortableBatteryDesignCapacity::Unknown => None,
+        }
     }
 
     /// Contains OEM- or BIOS vendor-specific
     /// information
+    ///
+    /// Added in SMBIOS 2.2; absent from tables of earlier versions.
     pub fn oem_specific(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x16)
+        self.parts
+            .since(SMBiosVersion::new(2, 2, 0))?
+            .get_field_dword(0x16)
     }
 }
 
@@ -336,6 +387,43 @@ impl From<u16> for PortableBatteryDesignCapacity {
     }
 }
 
+/// # Portable Battery - SBDS Manufacture Date
+///
+/// The date the cell pack was manufactured, packed by the Smart Battery Data
+/// Specification as bits 15:9 year - 1980, bits 8:5 month and bits 4:0 day.
+///
+/// ```
+/// # use smbioslib::*;
+/// let date = SbdsManufactureDate::from(0x427A);
+/// assert_eq!((date.year, date.month, date.day), (2013, 3, 26));
+/// assert_eq!(date.to_string(), "2013-03-26");
+/// ```
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SbdsManufactureDate {
+    /// Year (1980-2107)
+    pub year: u16,
+    /// Month (1-12)
+    pub month: u8,
+    /// Day of the month (1-31)
+    pub day: u8,
+}
+
+impl From<u16> for SbdsManufactureDate {
+    fn from(raw: u16) -> Self {
+        SbdsManufactureDate {
+            year: 1980 + (raw >> 9),
+            month: ((raw >> 5) & 0x0F) as u8,
+            day: (raw & 0x1F) as u8,
+        }
+    }
+}
+
+impl fmt::Display for SbdsManufactureDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
 /// # Portable Battery - Design Voltage
 #[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum PortableBatteryDesignVoltage {
@@ -357,6 +445,7 @@ impl From<u16> for PortableBatteryDesignVoltage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder};
 
     #[test]
     fn unit_test() {
@@ -394,11 +483,46 @@ mod tests {
         assert_eq!(test_struct.maximum_error_in_battery_data(), Some(255));
         assert_eq!(test_struct.sbds_serial_number(), Some(711));
         assert_eq!(test_struct.sbds_manufacture_date(), Some(17018));
+        assert_eq!(
+            test_struct.sbds_manufacture_date_decoded(),
+            Some(SbdsManufactureDate {
+                year: 2013,
+                month: 3,
+                day: 26
+            })
+        );
         assert_eq!(
             test_struct.sbds_device_chemistry().to_string(),
             "LiP".to_string()
         );
         assert_eq!(test_struct.design_capacity_multiplier(), Some(10));
         assert_eq!(test_struct.oem_specific(), Some(0));
+        assert_eq!(test_struct.design_capacity_mwh(), Some(46030));
+    }
+
+    #[test]
+    fn test_design_capacity_mwh() {
+        let design_capacity_mwh = |capacity: u16, multiplier: u8| {
+            let raw = StructBuilder::with_length(SMBiosPortableBattery::STRUCT_TYPE, 0x1A)
+                .word(0x0A, capacity)
+                .byte(0x15, multiplier)
+                .build(Handle(0x2E));
+            let parts = UndefinedStruct::new(&raw);
+            SMBiosPortableBattery::new(&parts).design_capacity_mwh()
+        };
+
+        assert_eq!(design_capacity_mwh(5000, 1), Some(5000));
+        assert_eq!(design_capacity_mwh(5000, 0), Some(5000));
+        assert_eq!(design_capacity_mwh(0xFFFF, 255), Some(0xFFFF * 255));
+        assert_eq!(design_capacity_mwh(0, 10), None);
+
+        // Version 2.1 structures have no multiplier
+        let raw = StructBuilder::with_length(SMBiosPortableBattery::STRUCT_TYPE, 0x10)
+            .word(0x0A, 5000)
+            .build(Handle(0x2E));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosPortableBattery::new(&parts);
+        assert!(test_struct.design_capacity_multiplier().is_none());
+        assert_eq!(test_struct.design_capacity_mwh(), Some(5000));
     }
 }
@@ -0,0 +1,97 @@
+use serde::Serialize;
+use std::fmt;
+
+/// # Probe Status
+///
+/// Status of a Voltage Probe (Type 26), Temperature Probe (Type 28) or
+/// Electrical Current Probe (Type 29), held in bits 7:5 of the probe's
+/// Location and Status field.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProbeStatus {
+    /// Other
+    Other,
+    /// Unknown
+    Unknown,
+    /// OK
+    OK,
+    /// Non-critical
+    NonCritical,
+    /// Critical
+    Critical,
+    /// Non-recoverable
+    NonRecoverable,
+    /// A value unknown to this standard, check the raw value
+    None,
+}
+
+impl From<u8> for ProbeStatus {
+    fn from(raw: u8) -> Self {
+        match raw & 0b1110_0000 {
+            0b0010_0000 => ProbeStatus::Other,
+            0b0100_0000 => ProbeStatus::Unknown,
+            0b0110_0000 => ProbeStatus::OK,
+            0b1000_0000 => ProbeStatus::NonCritical,
+            0b1010_0000 => ProbeStatus::Critical,
+            0b1100_0000 => ProbeStatus::NonRecoverable,
+            _ => ProbeStatus::None,
+        }
+    }
+}
+
+/// # Probe Accuracy
+///
+/// Accuracy of a Voltage Probe (Type 26) or Electrical Current Probe
+/// (Type 29) reading, in plus/minus 1/100th of a percent.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProbeAccuracy {
+    /// Accuracy for the probe's reading in 1/100th of a percent
+    OneOneHundredthPercent(u16),
+    /// Accuracy is unknown
+    Unknown,
+}
+
+impl From<u16> for ProbeAccuracy {
+    fn from(raw: u16) -> Self {
+        match raw {
+            0x8000 => ProbeAccuracy::Unknown,
+            _ => ProbeAccuracy::OneOneHundredthPercent(raw),
+        }
+    }
+}
+
+impl ProbeAccuracy {
+    /// The accuracy in percent, `None` when unknown
+    pub fn percent(&self) -> Option<f32> {
+        match self {
+            ProbeAccuracy::OneOneHundredthPercent(hundredths) => Some(*hundredths as f32 / 100.0),
+            ProbeAccuracy::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for ProbeAccuracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.percent() {
+            Some(percent) => write!(f, "{:.2}%", percent),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test() {
+        assert_eq!(ProbeStatus::from(0b0110_0111), ProbeStatus::OK);
+        assert_eq!(ProbeStatus::from(0b1100_0011), ProbeStatus::NonRecoverable);
+        assert_eq!(ProbeStatus::from(0b0000_0011), ProbeStatus::None);
+        assert_eq!(ProbeStatus::from(0b1110_0011), ProbeStatus::None);
+
+        assert_eq!(ProbeAccuracy::from(150).percent(), Some(1.5));
+        assert_eq!(ProbeAccuracy::from(150).to_string(), "1.50%");
+        assert_eq!(ProbeAccuracy::from(0x8000), ProbeAccuracy::Unknown);
+        assert_eq!(ProbeAccuracy::from(0x8000).to_string(), "Unknown");
+    }
+}
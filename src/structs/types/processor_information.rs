@@ -1,5 +1,5 @@
-use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{flag_set, strings::*, Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::{SMBiosCacheInformation, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::convert::TryInto;
 use std::fmt;
@@ -64,17 +64,23 @@ impl<'a> SMBiosProcessorInformation<'a> {
     }
 
     /// Raw processor identification data
+    ///
+    /// See [SMBiosProcessorInformation::processor_id_decoded] for the data
+    /// decoded according to the class of processor.
     pub fn processor_id(&self) -> Option<&[u8; 8]> {
-        // Note: There are two more levels to the design of ProcessorId to consider.
-        // 1. These 8 bytes can represent one of 4 classes of processor (see 7.5.3)
-        //    perhaps return an enum with 4 variants.
-        // 2. Per variant the data represents specific Id information which can be
-        //    accomodated in specific representative structures.
         self.parts
             .get_field_data(0x08, 0x10)
             .map(|raw| raw.try_into().expect("incorrect length"))
     }
 
+    /// Processor identification data decoded according to the
+    /// [SMBiosProcessorInformation::effective_processor_family]
+    pub fn processor_id_decoded(&self) -> Option<ProcessorId> {
+        let raw = self.processor_id()?;
+        let family = self.effective_processor_family()?;
+        Some(ProcessorId::new(family.raw, raw))
+    }
+
     /// Processor version
     pub fn processor_version(&self) -> SMBiosString {
         self.parts.get_field_string(0x10)
@@ -129,6 +135,16 @@ impl<'a> SMBiosProcessorInformation<'a> {
             .map(|raw| ProcessorStatus::from(raw))
     }
 
+    /// CPU socket populated (bit 6 of the status)
+    pub fn socket_populated(&self) -> Option<bool> {
+        self.status().map(|status| status.socket_populated())
+    }
+
+    /// CPU status (bits 2:0 of the status)
+    pub fn cpu_status(&self) -> Option<CpuStatus> {
+        self.status().map(|status| status.cpu_status())
+    }
+
     /// Processor upgrade
     pub fn processor_upgrade(&self) -> Option<ProcessorUpgradeData> {
         self.parts
@@ -145,8 +161,12 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// processor has no L1 cache. For version 2.3 and
     /// later implementations, the value is 0FFFFh if
     /// the Cache Information structure is not provided.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn l1cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1A)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_handle(0x1A)
     }
 
     /// Handle of a [super::SMBiosCacheInformation] structure that
@@ -158,8 +178,12 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// processor has no L2 cache. For version 2.3 and
     /// later implementations, the value is 0FFFFh if
     /// the Cache Information structure is not provided.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn l2cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1C)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_handle(0x1C)
     }
 
     /// Handle of a [super::SMBiosCacheInformation] structure that
@@ -171,29 +195,82 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// processor has no L3 cache. For version 2.3 and
     /// later implementations, the value is 0FFFFh if
     /// the Cache Information structure is not provided.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn l3cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1E)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_handle(0x1E)
+    }
+
+    /// The [SMBiosCacheInformation] of the Level 1 cache for this processor,
+    /// found in `data`
+    ///
+    /// `None` when the handle is 0FFFFh or `data` has no Cache Information
+    /// structure with the handle.
+    pub fn l1cache(&self, data: &'a SMBiosData) -> Option<SMBiosCacheInformation<'a>> {
+        Self::cache(self.l1cache_handle()?, data)
+    }
+
+    /// The [SMBiosCacheInformation] of the Level 2 cache for this processor,
+    /// found in `data`
+    ///
+    /// `None` when the handle is 0FFFFh or `data` has no Cache Information
+    /// structure with the handle.
+    pub fn l2cache(&self, data: &'a SMBiosData) -> Option<SMBiosCacheInformation<'a>> {
+        Self::cache(self.l2cache_handle()?, data)
+    }
+
+    /// The [SMBiosCacheInformation] of the Level 3 cache for this processor,
+    /// found in `data`
+    ///
+    /// `None` when the handle is 0FFFFh or `data` has no Cache Information
+    /// structure with the handle.
+    pub fn l3cache(&self, data: &'a SMBiosData) -> Option<SMBiosCacheInformation<'a>> {
+        Self::cache(self.l3cache_handle()?, data)
+    }
+
+    fn cache(handle: Handle, data: &'a SMBiosData) -> Option<SMBiosCacheInformation<'a>> {
+        if handle == Handle::NONE {
+            return None;
+        }
+        data.find_by_handle_as(&handle)
     }
 
     /// The serial number of this processor
     ///
     /// This value is set by the manufacturer and
     /// normally not changeable.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn serial_number(&self) -> SMBiosString {
-        self.parts.get_field_string(0x20)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x20),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// The asset tag of this processor
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn asset_tag(&self) -> SMBiosString {
-        self.parts.get_field_string(0x21)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x21),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// The part number of this processor
     ///
     /// This value is set by the manufacturer and
     /// normally not changeable.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn part_number(&self) -> SMBiosString {
-        self.parts.get_field_string(0x22)
+        match self.parts.supports(SMBiosVersion::new(2, 3, 0)) {
+            true => self.parts.get_field_string(0x22),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// Number of cores per processor socket
@@ -202,6 +279,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// 'core_count_2' field is set to the number of cores.
     pub fn core_count(&self) -> Option<CoreCount> {
         self.parts
+            .since(SMBiosVersion::new(2, 5, 0))?
             .get_field_byte(0x23)
             .map(|raw| CoreCount::from(raw))
     }
@@ -213,6 +291,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// cores.
     pub fn cores_enabled(&self) -> Option<CoresEnabled> {
         self.parts
+            .since(SMBiosVersion::new(2, 5, 0))?
             .get_field_byte(0x24)
             .map(|raw| CoresEnabled::from(raw))
     }
@@ -224,6 +303,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// threads.
     pub fn thread_count(&self) -> Option<ThreadCount> {
         self.parts
+            .since(SMBiosVersion::new(2, 5, 0))?
             .get_field_byte(0x25)
             .map(|raw| ThreadCount::from(raw))
     }
@@ -231,6 +311,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// Defines which functions the processor supports
     pub fn processor_characteristics(&self) -> Option<ProcessorCharacteristics> {
         self.parts
+            .since(SMBiosVersion::new(2, 5, 0))?
             .get_field_word(0x26)
             .map(|raw| ProcessorCharacteristics::from(raw))
     }
@@ -238,10 +319,26 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// Processor family 2
     pub fn processor_family_2(&self) -> Option<ProcessorFamilyData2> {
         self.parts
+            .since(SMBiosVersion::new(2, 6, 0))?
             .get_field_word(0x28)
             .map(|raw| ProcessorFamilyData2::from(raw))
     }
 
+    /// Processor family, taken from [SMBiosProcessorInformation::processor_family_2]
+    /// when [SMBiosProcessorInformation::processor_family] is FEh
+    ///
+    /// When the family 2 field is absent (before version 2.6) the FEh value is
+    /// returned as [ProcessorFamily::SeeProcessorFamily2].
+    pub fn effective_processor_family(&self) -> Option<ProcessorFamilyData2> {
+        let family = self.processor_family()?;
+        match family.value {
+            ProcessorFamily::SeeProcessorFamily2 => self
+                .processor_family_2()
+                .or_else(|| Some(ProcessorFamilyData2::from(family.raw as u16))),
+            _ => Some(ProcessorFamilyData2::from(family.raw as u16)),
+        }
+    }
+
     /// Number of Cores per processor socket.
     ///
     /// Supports core counts >255. If this field is
@@ -252,6 +349,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// to 'CoreCount::SeeCoreCount2' and 'core_count_2' will hold the count.
     pub fn core_count_2(&self) -> Option<CoreCount2> {
         self.parts
+            .since(SMBiosVersion::new(3, 0, 0))?
             .get_field_word(0x2A)
             .map(|raw| CoreCount2::from(raw))
     }
@@ -267,6 +365,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// and 'cores_enabled_2' will hold the count.
     pub fn cores_enabled_2(&self) -> Option<CoresEnabled2> {
         self.parts
+            .since(SMBiosVersion::new(3, 0, 0))?
             .get_field_word(0x2C)
             .map(|raw| CoresEnabled2::from(raw))
     }
@@ -282,6 +381,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// and 'thread_count_2' will hold the count.
     pub fn thread_count_2(&self) -> Option<ThreadCount2> {
         self.parts
+            .since(SMBiosVersion::new(3, 0, 0))?
             .get_field_word(0x2E)
             .map(|raw| ThreadCount2::from(raw))
     }
@@ -295,9 +395,50 @@ impl<'a> SMBiosProcessorInformation<'a> {
     ///     • And it disables multi-threading support, it reports a value of 2.
     pub fn thread_enabled(&self) -> Option<ThreadEnabled> {
         self.parts
+            .since(SMBiosVersion::new(3, 6, 0))?
             .get_field_word(0x30)
             .map(|raw| ThreadEnabled::from(raw))
     }
+
+    /// Number of cores per processor socket, taken from
+    /// [SMBiosProcessorInformation::core_count_2] when
+    /// [SMBiosProcessorInformation::core_count] is FFh
+    ///
+    /// Returns `None` when the count is unknown.
+    pub fn total_core_count(&self) -> Option<u16> {
+        self.count_with_fallback(0x23, 0x2A)
+    }
+
+    /// Number of enabled cores per processor socket, taken from
+    /// [SMBiosProcessorInformation::cores_enabled_2] when
+    /// [SMBiosProcessorInformation::cores_enabled] is FFh
+    ///
+    /// Returns `None` when the count is unknown.
+    pub fn total_cores_enabled(&self) -> Option<u16> {
+        self.count_with_fallback(0x24, 0x2C)
+    }
+
+    /// Number of threads per processor socket, taken from
+    /// [SMBiosProcessorInformation::thread_count_2] when
+    /// [SMBiosProcessorInformation::thread_count] is FFh
+    ///
+    /// Returns `None` when the count is unknown.
+    pub fn total_thread_count(&self) -> Option<u16> {
+        self.count_with_fallback(0x25, 0x2E)
+    }
+
+    /// Reads a count byte, falling back to the 3.0+ count word at
+    /// `word_offset` when the byte is FFh
+    fn count_with_fallback(&self, byte_offset: usize, word_offset: usize) -> Option<u16> {
+        match self.parts.get_field_byte(byte_offset)? {
+            0 => None,
+            0xFF => match self.parts.get_field_word(word_offset)? {
+                0 | 0xFFFF => None,
+                count => Some(count),
+            },
+            count => Some(count as u16),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosProcessorInformation<'_> {
@@ -343,7 +484,7 @@ impl Serialize for SMBiosProcessorInformation<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosProcessorInformation", 27)?;
+        let mut state = serializer.serialize_struct("SMBiosProcessorInformation", 28)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("socket_designation", &self.socket_designation())?;
         state.serialize_field("processor_type", &self.processor_type())?;
@@ -431,7 +572,7 @@ impl Deref for ProcessorTypeData {
 }
 
 /// # Processor Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorType {
     /// Other
     Other,
@@ -588,7 +729,7 @@ impl From<u16> for ProcessorFamilyData2 {
     }
 }
 /// # Processor Family
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorFamily {
     /// Other
     Other,
@@ -1056,6 +1197,22 @@ pub enum ProcessorFamily {
     MultiCoreLoongson3CProcessor5xxxSeries,
     /// Multi-Core Loongson™ 3D Processor 5xxx Series
     MultiCoreLoongson3DProcessor5xxxSeries,
+    /// Intel® Core™ 3
+    IntelCore3,
+    /// Intel® Core™ 5
+    IntelCore5,
+    /// Intel® Core™ 7
+    IntelCore7,
+    /// Intel® Core™ 9
+    IntelCore9,
+    /// Intel® Core™ Ultra 3
+    IntelCoreUltra3,
+    /// Intel® Core™ Ultra 5
+    IntelCoreUltra5,
+    /// Intel® Core™ Ultra 7
+    IntelCoreUltra7,
+    /// Intel® Core™ Ultra 9
+    IntelCoreUltra9,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -1296,11 +1453,429 @@ impl From<u16> for ProcessorFamily {
             0x26F => ProcessorFamily::MultiCoreLoongson3BProcessor5xxxSeries,
             0x270 => ProcessorFamily::MultiCoreLoongson3CProcessor5xxxSeries,
             0x271 => ProcessorFamily::MultiCoreLoongson3DProcessor5xxxSeries,
+            0x300 => ProcessorFamily::IntelCore3,
+            0x301 => ProcessorFamily::IntelCore5,
+            0x302 => ProcessorFamily::IntelCore7,
+            0x303 => ProcessorFamily::IntelCore9,
+            0x304 => ProcessorFamily::IntelCoreUltra3,
+            0x305 => ProcessorFamily::IntelCoreUltra5,
+            0x306 => ProcessorFamily::IntelCoreUltra7,
+            0x307 => ProcessorFamily::IntelCoreUltra9,
             _ => ProcessorFamily::None,
         }
     }
 }
 
+/// # Processor ID
+///
+/// The Processor ID field decoded according to the class of the processor
+/// family.
+///
+/// ```
+/// # use smbioslib::*;
+/// let id = ProcessorId::x86(&[0x54, 0x06, 0x05, 0x00, 0xFF, 0xFB, 0xEB, 0xBF]);
+/// if let ProcessorId::X86(id) = id {
+///     assert_eq!(id.signature.family(), 6);
+///     assert_eq!(id.signature.model(), 85);
+///     assert_eq!(id.signature.stepping(), 4);
+///     assert!(id.features.contains(X86FeatureFlags::SSE2));
+/// }
+/// ```
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessorId {
+    /// CPUID signature and feature flags of an x86 class processor
+    X86(X86ProcessorId),
+    /// Main ID Register and SoC ID of an ARM class processor
+    Arm(ArmProcessorId),
+    /// Processor ID of a family whose format is not decoded
+    Other([u8; 8]),
+}
+
+impl ProcessorId {
+    /// Decodes the Processor ID for the given processor family
+    ///
+    /// `family` is the effective family, see
+    /// [SMBiosProcessorInformation::effective_processor_family].
+    pub fn new(family: u16, raw: &[u8; 8]) -> Self {
+        match family {
+            0x0B..=0x15
+            | 0x18..=0x1D
+            | 0x1F
+            | 0x28..=0x2F
+            | 0x38..=0x3F
+            | 0x46..=0x4F
+            | 0x66..=0x6B
+            | 0x83..=0x8F
+            | 0xA1..=0xB3
+            | 0xB5..=0xB7
+            | 0xB9..=0xC7
+            | 0xCD..=0xCF
+            | 0xD2..=0xDB
+            | 0xDD..=0xE0
+            | 0xE4..=0xEF
+            | 0x300..=0x307 => Self::x86(raw),
+            // ARM and StrongARM (118h-119h) predate the MIDR encoding
+            0x100..=0x102 => Self::arm(raw),
+            _ => ProcessorId::Other(*raw),
+        }
+    }
+
+    /// Decodes the Processor ID as the CPUID signature and feature flags
+    /// of an x86 class processor
+    pub fn x86(raw: &[u8; 8]) -> Self {
+        ProcessorId::X86(X86ProcessorId {
+            signature: X86CpuSignature::from(u32::from_le_bytes(raw[0..4].try_into().unwrap())),
+            features: X86FeatureFlags::from(u32::from_le_bytes(raw[4..8].try_into().unwrap())),
+        })
+    }
+
+    /// Decodes the Processor ID as the Main ID Register and SoC ID of an
+    /// ARM class processor
+    pub fn arm(raw: &[u8; 8]) -> Self {
+        ProcessorId::Arm(ArmProcessorId {
+            midr: ArmMainId::from(u32::from_le_bytes(raw[0..4].try_into().unwrap())),
+            soc_id: u32::from_le_bytes(raw[4..8].try_into().unwrap()),
+        })
+    }
+}
+
+/// # x86 Processor ID
+///
+/// The EAX and EDX values reported by the CPUID instruction with EAX set to 1.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct X86ProcessorId {
+    /// Processor signature (CPUID EAX)
+    pub signature: X86CpuSignature,
+    /// Feature flags (CPUID EDX)
+    pub features: X86FeatureFlags,
+}
+
+/// # x86 CPUID Signature
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct X86CpuSignature {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl X86CpuSignature {
+    /// Stepping ID (bits 3:0)
+    pub fn stepping(&self) -> u8 {
+        (self.raw & 0x0F) as u8
+    }
+
+    /// Model number, including the extended model for families 6 and 15
+    pub fn model(&self) -> u8 {
+        match self.base_family() {
+            0x06 | 0x0F => (self.extended_model() << 4) | self.base_model(),
+            _ => self.base_model(),
+        }
+    }
+
+    /// Family number, including the extended family for family 15
+    pub fn family(&self) -> u16 {
+        match self.base_family() {
+            0x0F => self.base_family() as u16 + self.extended_family() as u16,
+            family => family as u16,
+        }
+    }
+
+    /// Processor type (bits 13:12)
+    pub fn processor_type(&self) -> u8 {
+        ((self.raw >> 12) & 0x03) as u8
+    }
+
+    /// Model (bits 7:4)
+    pub fn base_model(&self) -> u8 {
+        ((self.raw >> 4) & 0x0F) as u8
+    }
+
+    /// Family (bits 11:8)
+    pub fn base_family(&self) -> u8 {
+        ((self.raw >> 8) & 0x0F) as u8
+    }
+
+    /// Extended model (bits 19:16)
+    pub fn extended_model(&self) -> u8 {
+        ((self.raw >> 16) & 0x0F) as u8
+    }
+
+    /// Extended family (bits 27:20)
+    pub fn extended_family(&self) -> u8 {
+        ((self.raw >> 20) & 0xFF) as u8
+    }
+}
+
+impl From<u32> for X86CpuSignature {
+    fn from(raw: u32) -> Self {
+        X86CpuSignature { raw }
+    }
+}
+
+impl fmt::Debug for X86CpuSignature {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<X86CpuSignature>())
+            .field("raw", &self.raw)
+            .field("processor_type", &self.processor_type())
+            .field("family", &self.family())
+            .field("model", &self.model())
+            .field("stepping", &self.stepping())
+            .finish()
+    }
+}
+
+impl Serialize for X86CpuSignature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("X86CpuSignature", 5)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("processor_type", &self.processor_type())?;
+        state.serialize_field("family", &self.family())?;
+        state.serialize_field("model", &self.model())?;
+        state.serialize_field("stepping", &self.stepping())?;
+        state.end()
+    }
+}
+
+impl fmt::Display for X86CpuSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Type {}, Family {}, Model {}, Stepping {}",
+            self.processor_type(),
+            self.family(),
+            self.model(),
+            self.stepping()
+        )
+    }
+}
+
+/// # x86 Feature Flags
+///
+/// The feature flags reported in EDX by the CPUID instruction.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct X86FeatureFlags {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl X86FeatureFlags {
+    /// FPU (Floating-point unit on-chip)
+    pub const FPU: Self = Self { raw: 1 << 0 };
+    /// VME (Virtual mode extension)
+    pub const VME: Self = Self { raw: 1 << 1 };
+    /// DE (Debugging extension)
+    pub const DE: Self = Self { raw: 1 << 2 };
+    /// PSE (Page size extension)
+    pub const PSE: Self = Self { raw: 1 << 3 };
+    /// TSC (Time stamp counter)
+    pub const TSC: Self = Self { raw: 1 << 4 };
+    /// MSR (Model specific registers)
+    pub const MSR: Self = Self { raw: 1 << 5 };
+    /// PAE (Physical address extension)
+    pub const PAE: Self = Self { raw: 1 << 6 };
+    /// MCE (Machine check exception)
+    pub const MCE: Self = Self { raw: 1 << 7 };
+    /// CX8 (CMPXCHG8 instruction supported)
+    pub const CX8: Self = Self { raw: 1 << 8 };
+    /// APIC (On-chip APIC hardware supported)
+    pub const APIC: Self = Self { raw: 1 << 9 };
+    /// SEP (Fast system call)
+    pub const SEP: Self = Self { raw: 1 << 11 };
+    /// MTRR (Memory type range registers)
+    pub const MTRR: Self = Self { raw: 1 << 12 };
+    /// PGE (Page global enable)
+    pub const PGE: Self = Self { raw: 1 << 13 };
+    /// MCA (Machine check architecture)
+    pub const MCA: Self = Self { raw: 1 << 14 };
+    /// CMOV (Conditional move instruction supported)
+    pub const CMOV: Self = Self { raw: 1 << 15 };
+    /// PAT (Page attribute table)
+    pub const PAT: Self = Self { raw: 1 << 16 };
+    /// PSE-36 (36-bit page size extension)
+    pub const PSE_36: Self = Self { raw: 1 << 17 };
+    /// PSN (Processor serial number present and enabled)
+    pub const PSN: Self = Self { raw: 1 << 18 };
+    /// CLFSH (CLFLUSH instruction supported)
+    pub const CLFSH: Self = Self { raw: 1 << 19 };
+    /// DS (Debug store)
+    pub const DS: Self = Self { raw: 1 << 21 };
+    /// ACPI (ACPI supported)
+    pub const ACPI: Self = Self { raw: 1 << 22 };
+    /// MMX (MMX technology supported)
+    pub const MMX: Self = Self { raw: 1 << 23 };
+    /// FXSR (FXSAVE and FXSTOR instructions supported)
+    pub const FXSR: Self = Self { raw: 1 << 24 };
+    /// SSE (Streaming SIMD extensions)
+    pub const SSE: Self = Self { raw: 1 << 25 };
+    /// SSE2 (Streaming SIMD extensions 2)
+    pub const SSE2: Self = Self { raw: 1 << 26 };
+    /// SS (Self-snoop)
+    pub const SS: Self = Self { raw: 1 << 27 };
+    /// HTT (Multi-threading)
+    pub const HTT: Self = Self { raw: 1 << 28 };
+    /// TM (Thermal monitor supported)
+    pub const TM: Self = Self { raw: 1 << 29 };
+    /// PBE (Pending break enabled)
+    pub const PBE: Self = Self { raw: 1 << 31 };
+}
+
+flag_set!(
+    X86FeatureFlags,
+    [
+        ("FPU (Floating-point unit on-chip)", FPU),
+        ("VME (Virtual mode extension)", VME),
+        ("DE (Debugging extension)", DE),
+        ("PSE (Page size extension)", PSE),
+        ("TSC (Time stamp counter)", TSC),
+        ("MSR (Model specific registers)", MSR),
+        ("PAE (Physical address extension)", PAE),
+        ("MCE (Machine check exception)", MCE),
+        ("CX8 (CMPXCHG8 instruction supported)", CX8),
+        ("APIC (On-chip APIC hardware supported)", APIC),
+        ("SEP (Fast system call)", SEP),
+        ("MTRR (Memory type range registers)", MTRR),
+        ("PGE (Page global enable)", PGE),
+        ("MCA (Machine check architecture)", MCA),
+        ("CMOV (Conditional move instruction supported)", CMOV),
+        ("PAT (Page attribute table)", PAT),
+        ("PSE-36 (36-bit page size extension)", PSE_36),
+        ("PSN (Processor serial number present and enabled)", PSN),
+        ("CLFSH (CLFLUSH instruction supported)", CLFSH),
+        ("DS (Debug store)", DS),
+        ("ACPI (ACPI supported)", ACPI),
+        ("MMX (MMX technology supported)", MMX),
+        ("FXSR (FXSAVE and FXSTOR instructions supported)", FXSR),
+        ("SSE (Streaming SIMD extensions)", SSE),
+        ("SSE2 (Streaming SIMD extensions 2)", SSE2),
+        ("SS (Self-snoop)", SS),
+        ("HTT (Multi-threading)", HTT),
+        ("TM (Thermal monitor supported)", TM),
+        ("PBE (Pending break enabled)", PBE),
+    ]
+);
+
+impl From<u32> for X86FeatureFlags {
+    fn from(raw: u32) -> Self {
+        X86FeatureFlags { raw }
+    }
+}
+
+impl Deref for X86FeatureFlags {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl fmt::Debug for X86FeatureFlags {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<X86FeatureFlags>())
+            .field("raw", &self.raw)
+            .field(
+                "flags",
+                &self.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Serialize for X86FeatureFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("X86FeatureFlags", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field(
+            "flags",
+            &self.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
+
+/// # ARM Processor ID
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmProcessorId {
+    /// Main ID Register (MIDR_EL1 or MIDR)
+    pub midr: ArmMainId,
+    /// SoC ID (SMCCC_ARCH_SOC_ID) when
+    /// [ProcessorCharacteristics::ARM64_SOC_ID] is set, otherwise 0
+    pub soc_id: u32,
+}
+
+/// # ARM Main ID Register
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct ArmMainId {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl ArmMainId {
+    /// Implementer code (bits 31:24), such as 41h for Arm Limited
+    pub fn implementer(&self) -> u8 {
+        (self.raw >> 24) as u8
+    }
+
+    /// Variant number (bits 23:20)
+    pub fn variant(&self) -> u8 {
+        ((self.raw >> 20) & 0x0F) as u8
+    }
+
+    /// Architecture code (bits 19:16)
+    pub fn architecture(&self) -> u8 {
+        ((self.raw >> 16) & 0x0F) as u8
+    }
+
+    /// Primary part number (bits 15:4)
+    pub fn part_number(&self) -> u16 {
+        ((self.raw >> 4) & 0x0FFF) as u16
+    }
+
+    /// Revision number (bits 3:0)
+    pub fn revision(&self) -> u8 {
+        (self.raw & 0x0F) as u8
+    }
+}
+
+impl From<u32> for ArmMainId {
+    fn from(raw: u32) -> Self {
+        ArmMainId { raw }
+    }
+}
+
+impl fmt::Debug for ArmMainId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<ArmMainId>())
+            .field("raw", &self.raw)
+            .field("implementer", &self.implementer())
+            .field("variant", &self.variant())
+            .field("architecture", &self.architecture())
+            .field("part_number", &self.part_number())
+            .field("revision", &self.revision())
+            .finish()
+    }
+}
+
+impl Serialize for ArmMainId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ArmMainId", 6)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("implementer", &self.implementer())?;
+        state.serialize_field("variant", &self.variant())?;
+        state.serialize_field("architecture", &self.architecture())?;
+        state.serialize_field("part_number", &self.part_number())?;
+        state.serialize_field("revision", &self.revision())?;
+        state.end()
+    }
+}
+
 /// #
 pub struct ProcessorUpgradeData {
     /// Raw value
@@ -1335,6 +1910,15 @@ impl Serialize for ProcessorUpgradeData {
     }
 }
 
+impl fmt::Display for ProcessorUpgradeData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            ProcessorUpgrade::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for ProcessorUpgradeData {
     type Target = ProcessorUpgrade;
 
@@ -1343,8 +1927,10 @@ impl Deref for ProcessorUpgradeData {
     }
 }
 
-/// #
-#[derive(Serialize, Debug, PartialEq, Eq)]
+/// # Processor Upgrade
+///
+/// The socket or upgrade method of the processor.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorUpgrade {
     /// Other
     Other,
@@ -1602,7 +2188,19 @@ impl From<u8> for ProcessorUpgradeData {
 }
 
 /// # Processor Characteristics
-#[derive(PartialEq, Eq)]
+///
+/// Each defined bit is available as a named constant which can be combined
+/// and tested as flags:
+///
+/// ```
+/// # use smbioslib::*;
+/// let characteristics = ProcessorCharacteristics::from(0x00FC);
+/// assert!(characteristics.contains(
+///     ProcessorCharacteristics::CAPABLE_64_BIT | ProcessorCharacteristics::ENHANCED_VIRTUALIZATION
+/// ));
+/// assert!(!characteristics.contains(ProcessorCharacteristics::ARM64_SOC_ID));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ProcessorCharacteristics {
     /// Raw value
     pub raw: u16,
@@ -1623,52 +2221,94 @@ impl From<u16> for ProcessorCharacteristics {
 }
 
 impl ProcessorCharacteristics {
+    /// Bit 1 Unknown
+    pub const UNKNOWN: Self = Self { raw: 0x0002 };
+
+    /// Bit 2 64-bit Capable
+    pub const CAPABLE_64_BIT: Self = Self { raw: 0x0004 };
+
+    /// Bit 3 Multi-Core
+    pub const MULTI_CORE: Self = Self { raw: 0x0008 };
+
+    /// Bit 4 Hardware Thread
+    pub const HARDWARE_THREAD: Self = Self { raw: 0x0010 };
+
+    /// Bit 5 Execute Protection
+    pub const EXECUTE_PROTECTION: Self = Self { raw: 0x0020 };
+
+    /// Bit 6 Enhanced Virtualization
+    pub const ENHANCED_VIRTUALIZATION: Self = Self { raw: 0x0040 };
+
+    /// Bit 7 Power/Performance Control
+    pub const POWER_PERFORMANCE_CONTROL: Self = Self { raw: 0x0080 };
+
+    /// Bit 8 128-bit Capable
+    pub const CAPABLE_128_BIT: Self = Self { raw: 0x0100 };
+
+    /// Bit 9 Arm64 SoC ID
+    pub const ARM64_SOC_ID: Self = Self { raw: 0x0200 };
+
     /// Bit 1 Unknown
     pub fn unknown(&self) -> bool {
-        self.raw & 0x0002 == 0x0002
+        self.contains(Self::UNKNOWN)
     }
 
     /// Bit 2 64-bit Capable
     pub fn bit_64capable(&self) -> bool {
-        self.raw & 0x0004 == 0x0004
+        self.contains(Self::CAPABLE_64_BIT)
     }
 
     /// Bit 3 Multi-Core
     pub fn multi_core(&self) -> bool {
-        self.raw & 0x0008 == 0x0008
+        self.contains(Self::MULTI_CORE)
     }
 
     /// Bit 4 Hardware Thread
     pub fn hardware_thread(&self) -> bool {
-        self.raw & 0x0010 == 0x0010
+        self.contains(Self::HARDWARE_THREAD)
     }
 
     /// Bit 5 Execute Protection
     pub fn execute_protection(&self) -> bool {
-        self.raw & 0x0020 == 0x0020
+        self.contains(Self::EXECUTE_PROTECTION)
     }
 
     /// Bit 6 Enhanced Virtualization
     pub fn enhanced_virtualization(&self) -> bool {
-        self.raw & 0x0040 == 0x0040
+        self.contains(Self::ENHANCED_VIRTUALIZATION)
     }
 
     /// Bit 7 Power/Performance Control
     pub fn power_performance_control(&self) -> bool {
-        self.raw & 0x0080 == 0x0080
+        self.contains(Self::POWER_PERFORMANCE_CONTROL)
     }
 
     /// Bit 8 128-bit Capable
     pub fn bit_128capable(&self) -> bool {
-        self.raw & 0x0100 == 0x0100
+        self.contains(Self::CAPABLE_128_BIT)
     }
 
     /// Bit 9 Arm64 SoC ID
     pub fn arm_64soc_id(&self) -> bool {
-        self.raw & 0x200 == 0x200
+        self.contains(Self::ARM64_SOC_ID)
     }
 }
 
+flag_set!(
+    ProcessorCharacteristics,
+    [
+        ("Unknown", UNKNOWN),
+        ("64-bit capable", CAPABLE_64_BIT),
+        ("Multi-Core", MULTI_CORE),
+        ("Hardware Thread", HARDWARE_THREAD),
+        ("Execute Protection", EXECUTE_PROTECTION),
+        ("Enhanced Virtualization", ENHANCED_VIRTUALIZATION),
+        ("Power/Performance Control", POWER_PERFORMANCE_CONTROL),
+        ("128-bit Capable", CAPABLE_128_BIT),
+        ("Arm64 SoC ID", ARM64_SOC_ID),
+    ]
+);
+
 impl fmt::Debug for ProcessorCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<ProcessorCharacteristics>())
@@ -1713,7 +2353,10 @@ impl Serialize for ProcessorCharacteristics {
 }
 
 /// # Processor Voltage
-#[derive(Serialize, Debug)]
+///
+/// When bit 7 of the field is set bits 6:0 are the current voltage times 10;
+/// otherwise bits 2:0 are the legacy set of supported voltages.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
 pub enum ProcessorVoltage {
     /// Current Processor Voltage
     CurrentVolts(f32),
@@ -1721,6 +2364,25 @@ pub enum ProcessorVoltage {
     SupportedVolts(ProcessorSupportedVoltages),
 }
 
+impl ProcessorVoltage {
+    /// The current voltage in tenths of a volt, without floating point
+    /// rounding; `None` for the legacy encoding
+    pub fn current_tenths_of_volts(&self) -> Option<u8> {
+        match self {
+            ProcessorVoltage::CurrentVolts(volts) => Some((volts * 10.0).round() as u8),
+            ProcessorVoltage::SupportedVolts(_) => None,
+        }
+    }
+
+    /// The supported voltages; `None` when the field holds the current voltage
+    pub fn supported_voltages(&self) -> Option<&ProcessorSupportedVoltages> {
+        match self {
+            ProcessorVoltage::CurrentVolts(_) => None,
+            ProcessorVoltage::SupportedVolts(supported) => Some(supported),
+        }
+    }
+}
+
 impl From<u8> for ProcessorVoltage {
     fn from(raw: u8) -> Self {
         if raw & 0b1000_0000 == 0b1000_0000 {
@@ -1732,7 +2394,7 @@ impl From<u8> for ProcessorVoltage {
 }
 
 /// # Processor Supported Voltages
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ProcessorSupportedVoltages {
     /// Raw value
     pub raw: u8,
@@ -1811,7 +2473,7 @@ impl Serialize for ProcessorSupportedVoltages {
 }
 
 /// External Clock Frequency in MHz
-#[derive(Serialize)]
+#[derive(Serialize, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorExternalClock {
     /// The value is unknown
     Unknown,
@@ -1819,6 +2481,16 @@ pub enum ProcessorExternalClock {
     MHz(u16),
 }
 
+impl ProcessorExternalClock {
+    /// The external clock frequency in MHz, or `None` when the value is unknown
+    pub fn mhz(&self) -> Option<u16> {
+        match self {
+            ProcessorExternalClock::Unknown => None,
+            ProcessorExternalClock::MHz(mhz) => Some(*mhz),
+        }
+    }
+}
+
 impl From<u16> for ProcessorExternalClock {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1838,8 +2510,14 @@ impl fmt::Debug for ProcessorExternalClock {
     }
 }
 
+impl fmt::Display for ProcessorExternalClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
 /// Processor Speed in MHz
-#[derive(Serialize)]
+#[derive(Serialize, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorSpeed {
     /// The value is unknown
     Unknown,
@@ -1847,6 +2525,16 @@ pub enum ProcessorSpeed {
     MHz(u16),
 }
 
+impl ProcessorSpeed {
+    /// The speed in MHz, or `None` when the value is unknown
+    pub fn mhz(&self) -> Option<u16> {
+        match self {
+            ProcessorSpeed::Unknown => None,
+            ProcessorSpeed::MHz(mhz) => Some(*mhz),
+        }
+    }
+}
+
 impl From<u16> for ProcessorSpeed {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1866,8 +2554,14 @@ impl fmt::Debug for ProcessorSpeed {
     }
 }
 
+impl fmt::Display for ProcessorSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
 /// # Processor Socket and CPU Status
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ProcessorStatus {
     /// Raw value
     pub raw: u8,
@@ -1923,7 +2617,7 @@ impl Serialize for ProcessorStatus {
 }
 
 /// CPU Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CpuStatus {
     /// 0h – Unknown
     Unknown,
@@ -2108,6 +2802,7 @@ impl From<u16> for ThreadEnabled {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{StructBuilder, TableBuilder};
 
     #[test]
     fn unit_test() {
@@ -2219,4 +2914,320 @@ mod tests {
             ThreadCount2::Reserved => panic!("expected number"),
         }
     }
+
+    #[test]
+    fn test_effective_processor_family() {
+        let build = |family: u8, family_2: Option<u16>| {
+            let mut builder = StructBuilder::new(4).byte(0x06, family);
+            if let Some(family_2) = family_2 {
+                builder = builder.word(0x28, family_2);
+            }
+            builder.build(Handle(0))
+        };
+
+        // The family 2 word is only consulted for FEh
+        let raw = build(0xB3, Some(0x0101));
+        let parts = UndefinedStruct::new(&raw);
+        let family = SMBiosProcessorInformation::new(&parts)
+            .effective_processor_family()
+            .unwrap();
+        assert_eq!(*family, ProcessorFamily::IntelXeonProcessor);
+        assert_eq!(family.raw, 0xB3);
+
+        for (family_2, expected) in [
+            (0x0101, ProcessorFamily::ARMv8),
+            (0x0102, ProcessorFamily::ARMv9),
+            (0x0201, ProcessorFamily::RISCVRV64),
+            (0x0305, ProcessorFamily::IntelCoreUltra5),
+            (0x006B, ProcessorFamily::AMDZenProcessorFamily),
+        ] {
+            let raw = build(0xFE, Some(family_2));
+            let parts = UndefinedStruct::new(&raw);
+            let family = SMBiosProcessorInformation::new(&parts)
+                .effective_processor_family()
+                .unwrap();
+            assert_eq!(*family, expected);
+            assert_eq!(family.raw, family_2);
+        }
+
+        // Unknown values keep the raw value
+        let raw = build(0xFE, Some(0x0400));
+        let parts = UndefinedStruct::new(&raw);
+        let family = SMBiosProcessorInformation::new(&parts)
+            .effective_processor_family()
+            .unwrap();
+        assert_eq!(*family, ProcessorFamily::None);
+        assert_eq!(family.to_string(), "0x400");
+
+        // No family 2 word
+        let raw = build(0xFE, None);
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(
+            *SMBiosProcessorInformation::new(&parts)
+                .effective_processor_family()
+                .unwrap(),
+            ProcessorFamily::SeeProcessorFamily2
+        );
+    }
+
+    #[test]
+    fn test_processor_type_and_upgrade() {
+        for (raw, expected) in [
+            (0x03, ProcessorType::CentralProcessor),
+            (0x04, ProcessorType::MathProcessor),
+            (0x05, ProcessorType::DspProcessor),
+            (0x06, ProcessorType::VideoProcessor),
+            (0x07, ProcessorType::None),
+        ] {
+            let processor_type = ProcessorTypeData::from(raw);
+            assert_eq!(*processor_type, expected);
+            assert_eq!(processor_type.raw, raw);
+        }
+
+        for (raw, expected) in [
+            (0x06, ProcessorUpgrade::NoUpgrade),
+            (0x31, ProcessorUpgrade::SocketAM4),
+            (0x40, ProcessorUpgrade::SocketLGA1700),
+            (0x49, ProcessorUpgrade::SocketAM5),
+            (0x4A, ProcessorUpgrade::SocketSP5),
+            (0x50, ProcessorUpgrade::SocketLGA7529),
+        ] {
+            let upgrade = ProcessorUpgradeData::from(raw);
+            assert_eq!(*upgrade, expected);
+            assert_eq!(upgrade.raw, raw);
+        }
+        assert_eq!(
+            ProcessorUpgradeData::from(0x40).to_string(),
+            "SocketLGA1700"
+        );
+
+        let unknown = ProcessorUpgradeData::from(0xA0);
+        assert_eq!(*unknown, ProcessorUpgrade::None);
+        assert_eq!(unknown.to_string(), "160");
+    }
+
+    #[test]
+    fn test_voltage() {
+        // Bit 7 set: the current voltage
+        let voltage = ProcessorVoltage::from(0x8C);
+        assert_eq!(voltage, ProcessorVoltage::CurrentVolts(1.2));
+        assert_eq!(voltage.current_tenths_of_volts(), Some(12));
+        assert!(voltage.supported_voltages().is_none());
+        assert_eq!(
+            ProcessorVoltage::from(0xFF).current_tenths_of_volts(),
+            Some(127)
+        );
+
+        // Bit 7 clear: the legacy supported voltages
+        let voltage = ProcessorVoltage::from(0x06);
+        assert!(voltage.current_tenths_of_volts().is_none());
+        let supported = voltage.supported_voltages().unwrap();
+        assert!(!supported.volts_5_0());
+        assert!(supported.volts_3_3());
+        assert!(supported.volts_2_9());
+        assert_eq!(supported.voltages(), vec![2.9, 3.3]);
+        assert!(ProcessorVoltage::from(0x00)
+            .supported_voltages()
+            .unwrap()
+            .voltages()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_status() {
+        for (raw, populated, expected) in [
+            (0x41, true, CpuStatus::Enabled),
+            (0x42, true, CpuStatus::UserDisabled),
+            (0x43, true, CpuStatus::BiosDisabled),
+            (0x44, true, CpuStatus::Idle),
+            (0x47, true, CpuStatus::Other),
+            (0x45, true, CpuStatus::None),
+            (0x00, false, CpuStatus::Unknown),
+            // Reserved bits 7 and 5:3 are ignored
+            (0xB9, false, CpuStatus::Enabled),
+        ] {
+            let raw_struct = StructBuilder::new(4).byte(0x18, raw).build(Handle(0));
+            let parts = UndefinedStruct::new(&raw_struct);
+            let processor = SMBiosProcessorInformation::new(&parts);
+            assert_eq!(processor.socket_populated(), Some(populated));
+            assert_eq!(processor.cpu_status(), Some(expected));
+        }
+
+        let parts = UndefinedStruct::new(&StructBuilder::new(4).build(Handle(0)));
+        assert!(SMBiosProcessorInformation::new(&parts)
+            .socket_populated()
+            .is_none());
+    }
+
+    #[test]
+    fn test_total_counts() {
+        // 288 cores (0x0120), 240 enabled and 576 threads (0x0240)
+        let raw = StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+            .byte(0x23, 0xFF)
+            .byte(0x24, 0xF0)
+            .byte(0x25, 0xFF)
+            .word(0x2A, 0x0120)
+            .word(0x2C, 0x00F0)
+            .word(0x2E, 0x0240)
+            .build(Handle(4));
+        let parts = UndefinedStruct::new(&raw);
+        let processor = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(processor.total_core_count(), Some(288));
+        assert_eq!(processor.total_cores_enabled(), Some(240));
+        assert_eq!(processor.total_thread_count(), Some(576));
+
+        // Before 3.0 the count words are absent
+        let raw = StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+            .byte(0x23, 0x08)
+            .byte(0x24, 0x00)
+            .byte(0x25, 0xFF)
+            .build(Handle(4));
+        let parts = UndefinedStruct::new(&raw);
+        let processor = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(processor.total_core_count(), Some(8));
+        assert_eq!(processor.total_cores_enabled(), None);
+        assert_eq!(processor.total_thread_count(), None);
+    }
+
+    #[test]
+    fn test_processor_id_decoded() {
+        // Intel Xeon (B3h) with a Skylake-SP signature
+        let raw = StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+            .byte(0x06, 0xB3)
+            .data(0x08, &[0x54, 0x06, 0x05, 0x00, 0xFF, 0xFB, 0xEB, 0xBF])
+            .build(Handle(4));
+        let parts = UndefinedStruct::new(&raw);
+        let processor = SMBiosProcessorInformation::new(&parts);
+        let id = match processor.processor_id_decoded() {
+            Some(ProcessorId::X86(id)) => id,
+            other => panic!("expected an x86 processor id: {:?}", other),
+        };
+        assert_eq!(id.signature.family(), 6);
+        assert_eq!(id.signature.model(), 0x55);
+        assert_eq!(id.signature.stepping(), 4);
+        assert_eq!(id.signature.processor_type(), 0);
+        assert_eq!(
+            id.signature.to_string(),
+            "Type 0, Family 6, Model 85, Stepping 4"
+        );
+        assert!(id
+            .features
+            .contains(X86FeatureFlags::FPU | X86FeatureFlags::SSE2 | X86FeatureFlags::PBE));
+        assert!(!id.features.contains(X86FeatureFlags::PSN));
+        assert_eq!(id.features.iter().count(), 28);
+
+        // AMD family 17h uses the extended family
+        let signature = X86CpuSignature::from(0x00800F12);
+        assert_eq!(signature.family(), 0x17);
+        assert_eq!(signature.model(), 0x01);
+        assert_eq!(signature.stepping(), 2);
+
+        // ARMv8 (101h, through family 2) with a Neoverse N1 MIDR and SoC ID
+        let raw = StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+            .byte(0x06, 0xFE)
+            .data(0x08, &[0xC1, 0xD0, 0x3F, 0x41, 0x01, 0x00, 0x3B, 0x03])
+            .word(0x28, 0x0101)
+            .build(Handle(4));
+        let parts = UndefinedStruct::new(&raw);
+        let processor = SMBiosProcessorInformation::new(&parts);
+        let id = match processor.processor_id_decoded() {
+            Some(ProcessorId::Arm(id)) => id,
+            other => panic!("expected an ARM processor id: {:?}", other),
+        };
+        assert_eq!(id.midr.implementer(), 0x41);
+        assert_eq!(id.midr.variant(), 3);
+        assert_eq!(id.midr.architecture(), 0x0F);
+        assert_eq!(id.midr.part_number(), 0xD0C);
+        assert_eq!(id.midr.revision(), 1);
+        assert_eq!(id.soc_id, 0x033B_0001);
+
+        // Intel Itanium 2 is not x86
+        assert_eq!(ProcessorId::new(0xB8, &[0; 8]), ProcessorId::Other([0; 8]));
+        // StrongARM predates the ARMv7 MIDR encoding
+        assert_eq!(
+            ProcessorId::new(0x0119, &[1, 2, 3, 4, 5, 6, 7, 8]),
+            ProcessorId::Other([1, 2, 3, 4, 5, 6, 7, 8])
+        );
+        // The RISC-V Processor ID format is not decoded
+        assert_eq!(
+            ProcessorId::new(0x0201, &[1, 2, 3, 4, 5, 6, 7, 8]),
+            ProcessorId::Other([1, 2, 3, 4, 5, 6, 7, 8])
+        );
+    }
+
+    #[test]
+    fn test_cache_resolution() {
+        let mut builder = TableBuilder::new();
+        let l1 = builder.add(
+            StructBuilder::with_length(SMBiosCacheInformation::STRUCT_TYPE, 0x13)
+                .string(0x04, "L1 Cache"),
+        );
+        let l2 = builder.add(
+            StructBuilder::with_length(SMBiosCacheInformation::STRUCT_TYPE, 0x13)
+                .string(0x04, "L2 Cache"),
+        );
+        // Not a Cache Information structure
+        let oem = builder.add(StructBuilder::new(0x80));
+        builder.add(
+            StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+                .handle(0x1A, l1)
+                .handle(0x1C, l2)
+                .handle(0x1E, Handle::NONE),
+        );
+        builder.add(
+            StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+                .handle(0x1A, oem)
+                .handle(0x1C, Handle(0x0100)),
+        );
+        let data = builder.build_smbios_data(None);
+        let processors = data.collect::<SMBiosProcessorInformation<'_>>();
+
+        assert_eq!(
+            processors[0]
+                .l1cache(&data)
+                .unwrap()
+                .socket_designation()
+                .to_string(),
+            "L1 Cache"
+        );
+        assert_eq!(
+            processors[0]
+                .l2cache(&data)
+                .unwrap()
+                .socket_designation()
+                .to_string(),
+            "L2 Cache"
+        );
+        assert!(processors[0].l3cache(&data).is_none());
+
+        assert!(processors[1].l1cache(&data).is_none());
+        assert!(processors[1].l2cache(&data).is_none());
+        // The field is absent
+        assert!(processors[1].l3cache(&data).is_none());
+    }
+
+    #[test]
+    fn test_speeds() {
+        let raw = StructBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE)
+            .word(0x12, 100)
+            .word(0x14, 0)
+            .word(0x16, 3600)
+            .build(Handle(4));
+        let parts = UndefinedStruct::new(&raw);
+        let processor = SMBiosProcessorInformation::new(&parts);
+
+        let external_clock = processor.external_clock().unwrap();
+        assert_eq!(external_clock, ProcessorExternalClock::MHz(100));
+        assert_eq!(external_clock.mhz(), Some(100));
+        assert_eq!(external_clock.to_string(), "100 MHz");
+
+        let max_speed = processor.max_speed().unwrap();
+        assert_eq!(max_speed, ProcessorSpeed::Unknown);
+        assert_eq!(max_speed.mhz(), None);
+        assert_eq!(max_speed.to_string(), "Unknown");
+
+        let current_speed = processor.current_speed().unwrap();
+        assert_eq!(current_speed.mhz(), Some(3600));
+        assert_eq!(current_speed.to_string(), "3600 MHz");
+    }
 }
@@ -68,7 +68,7 @@ impl Serialize for SMBiosStringProperty<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosStringProperty", 3)?;
+        let mut state = serializer.serialize_struct("SMBiosStringProperty", 4)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("string_property_id", &self.string_property_id())?;
         state.serialize_field("string_property_value", &self.string_property_value())?;
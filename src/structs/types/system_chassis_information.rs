@@ -1,5 +1,5 @@
-use crate::core::{strings::*, UndefinedStruct};
-use crate::{BoardTypeData, SMBiosStruct, SMBiosType};
+use crate::core::{strings::*, SMBiosVersion, UndefinedStruct};
+use crate::{BoardTypeData, SMBiosBaseboardInformation, SMBiosStruct, SMBiosType};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -51,6 +51,15 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
             .map(|raw| ChassisTypeData::from(raw))
     }
 
+    /// Chassis lock is present
+    ///
+    /// Bit 7 of the chassis type.  When `false` either a lock is not present
+    /// or it is unknown if the enclosure has a lock.
+    pub fn has_lock(&self) -> Option<bool> {
+        self.chassis_type()
+            .map(|chassis_type| chassis_type.has_lock())
+    }
+
     /// Version
     pub fn version(&self) -> SMBiosString {
         self.parts.get_field_string(0x06)
@@ -69,8 +78,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// Boot-up State
     ///
     /// State of the enclosure when it was last booted.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn bootup_state(&self) -> Option<ChassisStateData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x09)
             .map(|raw| ChassisStateData::from(raw))
     }
@@ -79,8 +91,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     ///
     /// State of the enclosure’s power supply (or
     /// supplies) when last booted
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn power_supply_state(&self) -> Option<ChassisStateData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x0A)
             .map(|raw| ChassisStateData::from(raw))
     }
@@ -89,8 +104,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     ///
     /// Thermal state of the enclosure when last
     /// booted.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn thermal_state(&self) -> Option<ChassisStateData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x0B)
             .map(|raw| ChassisStateData::from(raw))
     }
@@ -99,8 +117,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     ///
     /// Physical security status of the enclosure when
     /// last booted.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn security_status(&self) -> Option<ChassisSecurityStatusData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x0C)
             .map(|raw| ChassisSecurityStatusData::from(raw))
     }
@@ -108,8 +129,12 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// OEM-defined
     ///
     /// OEM- or BIOS vendor-specific information
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0D)
+        self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
+            .get_field_dword(0x0D)
     }
 
     /// Height
@@ -119,8 +144,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// A U is a standard unit of measure for the
     /// height of a rack or rack-mountable component
     /// and is equal to 1.75 inches or 4.445 cm.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn height(&self) -> Option<ChassisHeight> {
         self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
             .get_field_byte(0x11)
             .map(|raw| ChassisHeight::from(raw))
     }
@@ -129,8 +157,11 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     ///
     /// Number of power cords associated with the
     /// enclosure or chassis
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn number_of_power_cords(&self) -> Option<PowerCords> {
         self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
             .get_field_byte(0x12)
             .map(|raw| PowerCords::from(raw))
     }
@@ -144,8 +175,12 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// Record Length field that follows. If no
     /// Contained Elements are included, this field is
     /// set to 0.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn contained_element_count(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x13)
+        self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
+            .get_field_byte(0x13)
     }
 
     /// Contained element record length (m)
@@ -156,8 +191,12 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// field is set to 0. For version 2.3.2 and later of
     /// this specification, this field is set to at least 03h
     /// when Contained Elements are specified.
+    ///
+    /// Added in SMBIOS 2.3; absent from tables of earlier versions.
     pub fn contained_element_record_length(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x14)
+        self.parts
+            .since(SMBiosVersion::new(2, 3, 0))?
+            .get_field_byte(0x14)
     }
 
     fn contained_elements_size(&self) -> Option<usize> {
@@ -168,19 +207,24 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     }
 
     /// Contained Elements
-    pub fn contained_elements(&self) -> Option<ContainedElements<'_>> {
+    ///
+    /// `None` when the structure is too short to hold the
+    /// [SMBiosSystemChassisInformation::contained_element_count] records.
+    pub fn contained_elements(&self) -> Option<ContainedElements<'a>> {
         ContainedElements::new(self)
     }
 
     /// SKU number
     ///
     /// Chassis or enclosure SKU number
+    ///
+    /// Added in SMBIOS 2.7; absent from tables of earlier versions.
     pub fn sku_number(&self) -> SMBiosString {
         match self.contained_elements_size() {
-            Some(size) => self
+            Some(size) if self.parts.supports(SMBiosVersion::new(2, 7, 0)) => self
                 .parts
                 .get_field_string(Self::CONTAINED_ELEMENTS_OFFSET + size),
-            None => Err(SMBiosStringError::FieldOutOfBounds).into(),
+            _ => Err(SMBiosStringError::FieldOutOfBounds).into(),
         }
     }
 }
@@ -283,7 +327,7 @@ impl From<u8> for PowerCords {
 }
 
 /// # Chassis Lock Presence
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisLockPresence {
     /// The enclosure has a lock.
     Present,
@@ -321,7 +365,7 @@ impl Serialize for ChassisTypeData {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("ChassisTypeData", 2)?;
+        let mut state = serializer.serialize_struct("ChassisTypeData", 3)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field("value", &self.value)?;
         state.serialize_field("lock_presence", &self.lock_presence)?;
@@ -338,6 +382,13 @@ impl fmt::Display for ChassisTypeData {
     }
 }
 
+impl ChassisTypeData {
+    /// Chassis lock is present (bit 7)
+    pub fn has_lock(&self) -> bool {
+        self.lock_presence == ChassisLockPresence::Present
+    }
+}
+
 impl Deref for ChassisTypeData {
     type Target = ChassisType;
 
@@ -347,7 +398,7 @@ impl Deref for ChassisTypeData {
 }
 
 /// # Chassis Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisType {
     /// Other
     Other,
@@ -511,6 +562,15 @@ impl Serialize for ChassisStateData {
     }
 }
 
+impl fmt::Display for ChassisStateData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            ChassisState::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for ChassisStateData {
     type Target = ChassisState;
 
@@ -519,8 +579,10 @@ impl Deref for ChassisStateData {
     }
 }
 
-/// # Chassis Statue
-#[derive(Serialize, Debug, PartialEq, Eq)]
+/// # Chassis State
+///
+/// Used by the boot-up, power supply and thermal states.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisState {
     /// Other
     Other,
@@ -589,6 +651,15 @@ impl Serialize for ChassisSecurityStatusData {
     }
 }
 
+impl fmt::Display for ChassisSecurityStatusData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            ChassisSecurityStatus::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for ChassisSecurityStatusData {
     type Target = ChassisSecurityStatus;
 
@@ -598,7 +669,7 @@ impl Deref for ChassisSecurityStatusData {
 }
 
 /// # Chassis Security Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisSecurityStatus {
     /// Other
     Other,
@@ -638,7 +709,7 @@ pub struct ContainedElements<'a> {
 }
 
 impl<'a> ContainedElements<'a> {
-    fn new(chassis_information: &'a SMBiosSystemChassisInformation<'a>) -> Option<Self> {
+    fn new(chassis_information: &SMBiosSystemChassisInformation<'a>) -> Option<Self> {
         chassis_information
             .contained_element_record_length()
             .and_then(|record_length| {
@@ -662,6 +733,21 @@ impl<'a> ContainedElements<'a> {
                     })
             })
     }
+
+    /// Iterates the contained element records
+    pub fn iter(&self) -> ContainedElementsIterator<'_> {
+        self.into_iter()
+    }
+
+    /// Number of contained element records
+    pub fn len(&self) -> usize {
+        self.record_count
+    }
+
+    /// Returns true when there are no contained element records
+    pub fn is_empty(&self) -> bool {
+        self.record_count == 0
+    }
 }
 
 impl<'a> fmt::Debug for ContainedElements<'a> {
@@ -756,6 +842,37 @@ pub enum ElementType {
     SMBiosType(SMBiosType),
 }
 
+impl ElementType {
+    /// Returns true when `undefined_struct` is an element of this type
+    ///
+    /// A structure type matches structures of that type and a board type
+    /// matches Baseboard (Type 2) structures with that board type, so the
+    /// elements present can be found in the table:
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn count(data: &SMBiosData, element: &ChassisElement<'_>) -> usize {
+    /// data.iter()
+    ///     .filter(|undefined_struct| element.element_type().matches(undefined_struct))
+    ///     .count()
+    /// # }
+    /// ```
+    pub fn matches(&self, undefined_struct: &UndefinedStruct) -> bool {
+        match self {
+            ElementType::SMBiosType(struct_type) => {
+                undefined_struct.header.struct_type() == **struct_type
+            }
+            ElementType::BaseboardType(board_type) => {
+                undefined_struct.header.struct_type() == SMBiosBaseboardInformation::STRUCT_TYPE
+                    && SMBiosBaseboardInformation::new(undefined_struct)
+                        .board_type()
+                        .map(|found| found.raw)
+                        == Some(board_type.raw)
+            }
+        }
+    }
+}
+
 impl From<u8> for ElementType {
     fn from(raw: u8) -> Self {
         if raw & 0b1000_0000 == 0b1000_0000 {
@@ -910,6 +1027,7 @@ mod tests {
 
         assert_eq!(test_struct.manufacturer().to_string(), "LENOVO".to_string());
         assert_eq!(*test_struct.chassis_type().unwrap(), ChassisType::Desktop);
+        assert_eq!(test_struct.has_lock(), Some(false));
         assert_eq!(test_struct.version().to_string(), "None".to_string());
         assert_eq!(
             test_struct.serial_number().to_string(),
@@ -961,4 +1079,110 @@ mod tests {
             "Default string".to_string()
         );
     }
+
+    #[test]
+    fn test_chassis_type_lock() {
+        let locked = ChassisTypeData::from(0x9F);
+        assert_eq!(*locked, ChassisType::Convertible);
+        assert!(locked.has_lock());
+        assert_eq!(locked.lock_presence, ChassisLockPresence::Present);
+
+        let unlocked = ChassisTypeData::from(0x17);
+        assert_eq!(*unlocked, ChassisType::RackMountChassis);
+        assert!(!unlocked.has_lock());
+
+        // The lock bit does not hide an unknown enumeration value
+        let unknown = ChassisTypeData::from(0xFF);
+        assert_eq!(*unknown, ChassisType::None);
+        assert!(unknown.has_lock());
+    }
+
+    #[test]
+    fn test_contained_elements() {
+        use crate::core::{Handle, StructBuilder, TableBuilder};
+
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::new(3)
+                .byte(0x05, 0x03)
+                .byte(0x13, 2)
+                // Records longer than the three defined bytes are stepped over
+                .byte(0x14, 4)
+                .data(0x15, &[0x85, 0x01, 0x04, 0xAA, 0x0A, 0x01, 0x01, 0xAA])
+                .string(0x15 + 8, "SKU"),
+        );
+        builder.add(StructBuilder::new(5));
+        builder.add(StructBuilder::new(2).byte(0x0D, 0x0A));
+        builder.add(StructBuilder::new(2).byte(0x0D, 0x09));
+        let data = builder.build_smbios_data(None);
+
+        // Usable on a temporary structure
+        let contained_elements = data
+            .first::<SMBiosSystemChassisInformation<'_>>()
+            .unwrap()
+            .contained_elements()
+            .unwrap();
+        assert_eq!(contained_elements.len(), 2);
+        assert!(!contained_elements.is_empty());
+
+        let mut elements = contained_elements.iter();
+        let memory_controllers = elements.next().unwrap();
+        assert!(matches!(
+            memory_controllers.element_minimum(),
+            ElementMinimum::Count(1)
+        ));
+        assert!(matches!(
+            memory_controllers.element_maximum(),
+            ElementMaximum::Count(4)
+        ));
+        let motherboards = elements.next().unwrap();
+        assert!(elements.next().is_none());
+
+        let found = |element: &ChassisElement<'_>| -> Vec<Handle> {
+            data.iter()
+                .filter(|undefined_struct| element.element_type().matches(undefined_struct))
+                .map(|undefined_struct| undefined_struct.header.handle())
+                .collect()
+        };
+        assert_eq!(found(&memory_controllers), vec![Handle(1)]);
+        assert_eq!(found(&motherboards), vec![Handle(2)]);
+
+        let chassis = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert_eq!(chassis.sku_number().to_string(), "SKU");
+    }
+
+    #[test]
+    fn test_states_and_security_status() {
+        let states = [
+            (0x01, ChassisState::Other),
+            (0x02, ChassisState::Unknown),
+            (0x03, ChassisState::Safe),
+            (0x04, ChassisState::Warning),
+            (0x05, ChassisState::Critical),
+            (0x06, ChassisState::NonRecoverable),
+            (0x07, ChassisState::None),
+        ];
+        for (raw, state) in states {
+            assert_eq!(*ChassisStateData::from(raw), state);
+        }
+        assert_eq!(ChassisStateData::from(0x04).to_string(), "Warning");
+        assert_eq!(ChassisStateData::from(0x00).to_string(), "0");
+
+        let statuses = [
+            (0x01, ChassisSecurityStatus::Other),
+            (0x02, ChassisSecurityStatus::Unknown),
+            (0x03, ChassisSecurityStatus::StatusNone),
+            (0x04, ChassisSecurityStatus::ExternalInterfaceLockedOut),
+            (0x05, ChassisSecurityStatus::ExternalInterfaceEnabled),
+            (0x06, ChassisSecurityStatus::None),
+        ];
+        for (raw, status) in statuses {
+            assert_eq!(*ChassisSecurityStatusData::from(raw), status);
+        }
+        assert_eq!(
+            ChassisSecurityStatusData::from(0x04).to_string(),
+            "ExternalInterfaceLockedOut"
+        );
+        assert_eq!(ChassisSecurityStatusData::from(0x06).to_string(), "6");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{SMBiosStringSet, SMBiosStruct, UndefinedStruct};
+use crate::{CountedStringIterator, SMBiosStringSet, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -39,6 +39,11 @@ impl<'a> SMBiosSystemConfigurationOptions<'a> {
     pub fn configuration_strings(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// Iterates over the [count](Self::count) configuration strings in order
+    pub fn configuration_string_iterator(&self) -> ConfigurationStringIterator<'a> {
+        CountedStringIterator::new(&self.parts.strings, self.count().unwrap_or(0))
+    }
 }
 
 impl fmt::Debug for SMBiosSystemConfigurationOptions<'_> {
@@ -64,9 +69,13 @@ impl Serialize for SMBiosSystemConfigurationOptions<'_> {
     }
 }
 
+/// # Configuration String Iterator for the strings contained within [SMBiosSystemConfigurationOptions]
+pub type ConfigurationStringIterator<'a> = CountedStringIterator<'a>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder, TableBuilder};
 
     #[test]
     fn unit_test() {
@@ -88,4 +97,42 @@ mod tests {
             Some("scre++".to_string())
         );
     }
+
+    #[test]
+    fn test_configuration_string_iterator() {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::new(SMBiosSystemConfigurationOptions::STRUCT_TYPE)
+                .byte(0x04, 2)
+                .push_string("JP2: 1-2 Cache Size is 256K, 2-3 Cache Size is 512K")
+                .push_string("SW1-1: Close to Disable On Board Video"),
+        );
+        let data = builder.build_smbios_data(None);
+
+        let strings: Vec<String> = data
+            .first::<SMBiosSystemConfigurationOptions<'_>>()
+            .unwrap()
+            .configuration_string_iterator()
+            .map(|string| string.to_string())
+            .collect();
+        assert_eq!(
+            strings,
+            vec![
+                "JP2: 1-2 Cache Size is 256K, 2-3 Cache Size is 512K",
+                "SW1-1: Close to Disable On Board Video"
+            ]
+        );
+
+        // Strings beyond the count are not yielded
+        let raw = StructBuilder::new(SMBiosSystemConfigurationOptions::STRUCT_TYPE)
+            .byte(0x04, 1)
+            .push_string("JP1")
+            .push_string("unreferenced")
+            .build(Handle(0));
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemConfigurationOptions::new(&parts);
+        let mut iter = test_struct.configuration_string_iterator();
+        assert_eq!(iter.next().unwrap().ok(), Some("JP1".to_string()));
+        assert!(iter.next().is_none());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, SMBiosVersion, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -96,9 +96,30 @@ impl<'a> SMBiosSystemEventLog<'a> {
         self.parts.get_field_dword(0x10)
     }
 
+    /// [access_method_address](Self::access_method_address) interpreted according
+    /// to the [access_method](Self::access_method)
+    pub fn access_method_address_decoded(&self) -> Option<AccessMethodAddress> {
+        let access_method = self.access_method()?;
+        let address = self.access_method_address()?;
+        Some(AccessMethodAddress::new(&access_method.value, address))
+    }
+
+    /// Length, in bytes, of the log header
+    ///
+    /// Computed as [log_data_start_offset](Self::log_data_start_offset) minus
+    /// [log_header_start_offset](Self::log_header_start_offset), since the data
+    /// directly follows the header.
+    pub fn log_header_length(&self) -> Option<u16> {
+        let header_start = self.log_header_start_offset()?;
+        self.log_data_start_offset()?.checked_sub(header_start)
+    }
+
     /// Format of the log header area
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn log_header_format(&self) -> Option<HeaderFormatData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x14)
             .map(|raw| HeaderFormatData::from(raw))
     }
@@ -108,8 +129,12 @@ impl<'a> SMBiosSystemEventLog<'a> {
     ///
     /// If the value is 0, the list that starts at offset
     /// 17h is not present.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn number_of_supported_log_type_descriptors(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_byte(0x15)
     }
 
     /// Number of bytes associated with each type
@@ -120,12 +145,16 @@ impl<'a> SMBiosSystemEventLog<'a> {
     /// to the type list. Software that interprets the
     /// following list should not assume a list entry’s
     /// length.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn length_of_each_log_type_descriptor(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x16)
+        self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
+            .get_field_byte(0x16)
     }
 
     /// Type Descriptors
-    pub fn type_descriptors(&self) -> Option<TypeDescriptors<'_>> {
+    pub fn type_descriptors(&self) -> Option<TypeDescriptors<'a>> {
         TypeDescriptors::new(self)
     }
 }
@@ -283,6 +312,8 @@ pub enum LogType {
     LogAreaReset,
     /// System boot. If implemented, this log entry is guaranteed to be the first one written on any system boot.
     SystemBoot,
+    /// OEM-specific log type (80h-FEh)
+    OemAssigned,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -314,6 +345,7 @@ impl From<u8> for LogTypeData {
                 0x15 => LogType::UncorrectableCpuComplexError,
                 0x16 => LogType::LogAreaReset,
                 0x17 => LogType::SystemBoot,
+                0x80..=0xFE => LogType::OemAssigned,
                 _ => LogType::None,
             },
             raw,
@@ -389,6 +421,8 @@ pub enum VariableDataFormatType {
     SystemManagementType,
     /// The first DWORD of the variable data contains a value that identifies a system-management condition. (See 7.16.6.5 for the enumerated values.) This DWORD is directly followed by a DWORD that contains a multiple- event counter (see 7.16.6.3 for details).
     MultipleEventSystemManagementType,
+    /// OEM-specific variable data format (80h-FFh)
+    OemAssigned,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -404,6 +438,7 @@ impl From<u8> for VariableDataFormatTypeData {
                 0x04 => VariableDataFormatType::PostResultsBitmap,
                 0x05 => VariableDataFormatType::SystemManagementType,
                 0x06 => VariableDataFormatType::MultipleEventSystemManagementType,
+                0x80..=0xFF => VariableDataFormatType::OemAssigned,
                 _ => VariableDataFormatType::None,
             },
             raw,
@@ -464,7 +499,7 @@ impl From<u8> for AccessMethodData {
 /// # System Event Log - Access Method
 ///
 /// Defines the Location and Method used by higher-level software to access the log area.
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AccessMethod {
     /// 00h Indexed I/O
     ///
@@ -506,6 +541,59 @@ impl From<u8> for AccessMethod {
     }
 }
 
+/// # System Event Log - Access Method Address
+///
+/// The Access Method Address field of [SMBiosSystemEventLog] interpreted according to
+/// its [AccessMethod].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AccessMethodAddress {
+    /// I/O addresses of the index and data ports, used by the indexed I/O access methods
+    IndexedIO {
+        /// Index port (low WORD of the address)
+        index: u16,
+        /// Data port (high WORD of the address)
+        data: u16,
+    },
+    /// Starting physical address of the log area
+    MemoryMapped32Bit(u32),
+    /// GPNV handle passed to the General-Purpose NonVolatile Data functions
+    GeneralPurposeNonVolatile(u16),
+    /// The access method is unknown to this standard, check the raw address
+    None(u32),
+}
+
+impl AccessMethodAddress {
+    /// Interprets the raw `address` for the given `access_method`
+    pub fn new(access_method: &AccessMethod, address: u32) -> Self {
+        match access_method {
+            AccessMethod::IndexedIO18Bit
+            | AccessMethod::IndexedIO28Bit
+            | AccessMethod::IndexedIO116Bit => AccessMethodAddress::IndexedIO {
+                index: address as u16,
+                data: (address >> 16) as u16,
+            },
+            AccessMethod::MemoryMapped32Bit => AccessMethodAddress::MemoryMapped32Bit(address),
+            AccessMethod::GeneralPurposeNonVolatile => {
+                AccessMethodAddress::GeneralPurposeNonVolatile(address as u16)
+            }
+            AccessMethod::None => AccessMethodAddress::None(address),
+        }
+    }
+}
+
+impl fmt::Display for AccessMethodAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessMethodAddress::IndexedIO { index, data } => {
+                write!(f, "Index 0x{:04X}, Data 0x{:04X}", index, data)
+            }
+            AccessMethodAddress::MemoryMapped32Bit(address) => write!(f, "0x{:08X}", address),
+            AccessMethodAddress::GeneralPurposeNonVolatile(handle) => write!(f, "0x{:04X}", handle),
+            AccessMethodAddress::None(address) => write!(f, "Unknown (0x{:08X})", address),
+        }
+    }
+}
+
 /// System Event Log Type Descriptor
 ///
 /// Each entry consists of a 1-byte type field and a 1-byte data-format descriptor, as shown in Table 61. The
@@ -575,6 +663,7 @@ impl<'a> Serialize for EventLogTypeDescriptor<'a> {
 }
 
 /// # System Event Log Type Descriptors within [SMBiosSystemEventLog]
+#[derive(Clone, Copy)]
 pub struct TypeDescriptors<'a> {
     raw: &'a [u8],
     record_count: usize,
@@ -582,7 +671,7 @@ pub struct TypeDescriptors<'a> {
 }
 
 impl<'a> TypeDescriptors<'a> {
-    fn new(system_event_log: &'a SMBiosSystemEventLog<'a>) -> Option<Self> {
+    fn new(system_event_log: &SMBiosSystemEventLog<'a>) -> Option<Self> {
         system_event_log
             .length_of_each_log_type_descriptor()
             .and_then(|record_length| {
@@ -631,7 +720,7 @@ impl<'a> Serialize for TypeDescriptors<'a> {
 
 /// # Iterates over the [EventLogTypeDescriptor] entries within [TypeDescriptors]
 pub struct TypeDescriptorsIterator<'a> {
-    descriptors: &'a TypeDescriptors<'a>,
+    descriptors: TypeDescriptors<'a>,
     current_index: usize,
     current_entry: usize,
 }
@@ -643,7 +732,7 @@ impl<'a> TypeDescriptorsIterator<'a> {
     }
 }
 
-impl<'a> IntoIterator for &'a TypeDescriptors<'a> {
+impl<'a> IntoIterator for TypeDescriptors<'a> {
     type Item = EventLogTypeDescriptor<'a>;
     type IntoIter = TypeDescriptorsIterator<'a>;
 
@@ -656,6 +745,15 @@ impl<'a> IntoIterator for &'a TypeDescriptors<'a> {
     }
 }
 
+impl<'a> IntoIterator for &TypeDescriptors<'a> {
+    type Item = EventLogTypeDescriptor<'a>;
+    type IntoIter = TypeDescriptorsIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
 impl<'a> Iterator for TypeDescriptorsIterator<'a> {
     type Item = EventLogTypeDescriptor<'a>;
 
@@ -801,7 +899,7 @@ impl Deref for HeaderFormatData {
 }
 
 /// # System Event Log - Header Format
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HeaderFormat {
     /// No header (for example, the header is 0 bytes in length)
     NoHeader,
@@ -827,6 +925,7 @@ impl From<u8> for HeaderFormatData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder, TableBuilder};
 
     #[test]
     fn unit_test() {
@@ -871,4 +970,110 @@ mod tests {
         let first = iterator.next().unwrap();
         assert_eq!(*first.log_type(), LogType::SingleBitEccMemoryError);
     }
+
+    #[test]
+    fn test_access_method_address() {
+        let build = |access_method: u8, address: u32| {
+            StructBuilder::with_length(SMBiosSystemEventLog::STRUCT_TYPE, 0x17)
+                .word(0x04, 0x1000)
+                .word(0x06, 0x0000)
+                .word(0x08, 0x0010)
+                .byte(0x0A, access_method)
+                .dword(0x10, address)
+                .byte(0x14, 0x01)
+                .build(Handle(0x0F00))
+        };
+
+        let raw = build(0x01, 0x0C01_0C00);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            test_struct.access_method_address_decoded(),
+            Some(AccessMethodAddress::IndexedIO {
+                index: 0x0C00,
+                data: 0x0C01
+            })
+        );
+        assert_eq!(
+            test_struct
+                .access_method_address_decoded()
+                .unwrap()
+                .to_string(),
+            "Index 0x0C00, Data 0x0C01"
+        );
+        assert_eq!(test_struct.log_header_length(), Some(16));
+        assert_eq!(
+            *test_struct.log_header_format().unwrap(),
+            HeaderFormat::Type1LogHeader
+        );
+
+        let raw = build(0x03, 0xFFE0_0000);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            test_struct.access_method_address_decoded(),
+            Some(AccessMethodAddress::MemoryMapped32Bit(0xFFE0_0000))
+        );
+
+        let raw = build(0x04, 0x0000_0003);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            test_struct.access_method_address_decoded(),
+            Some(AccessMethodAddress::GeneralPurposeNonVolatile(3))
+        );
+
+        let raw = build(0x80, 0x1234_5678);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            test_struct.access_method_address_decoded(),
+            Some(AccessMethodAddress::None(0x1234_5678))
+        );
+    }
+
+    #[test]
+    fn test_type_descriptors() {
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::with_length(SMBiosSystemEventLog::STRUCT_TYPE, 0x17)
+                .byte(0x15, 3)
+                .byte(0x16, 2)
+                .data(0x17, &[0x01, 0x00, 0x17, 0x00, 0x81, 0x80]),
+        );
+        let data = builder.build_smbios_data(None);
+
+        // Descriptors are reachable from a temporary structure
+        let descriptors: Vec<EventLogTypeDescriptor<'_>> = data
+            .first::<SMBiosSystemEventLog<'_>>()
+            .unwrap()
+            .type_descriptors()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(descriptors.len(), 3);
+
+        assert_eq!(*descriptors[0].log_type(), LogType::SingleBitEccMemoryError);
+        assert_eq!(
+            *descriptors[0].variable_data_format_type(),
+            VariableDataFormatType::NoStandardFormat
+        );
+        assert_eq!(*descriptors[1].log_type(), LogType::SystemBoot);
+        assert_eq!(*descriptors[2].log_type(), LogType::OemAssigned);
+        assert_eq!(descriptors[2].log_type().raw, 0x81);
+        assert_eq!(
+            *descriptors[2].variable_data_format_type(),
+            VariableDataFormatType::OemAssigned
+        );
+
+        // Descriptor count beyond the structure length yields no descriptors
+        let raw = StructBuilder::with_length(SMBiosSystemEventLog::STRUCT_TYPE, 0x17)
+            .byte(0x15, 3)
+            .byte(0x16, 2)
+            .build(Handle(0x0F00));
+        let parts = UndefinedStruct::new(&raw);
+        assert!(SMBiosSystemEventLog::new(&parts)
+            .type_descriptors()
+            .is_none());
+    }
 }
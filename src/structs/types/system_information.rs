@@ -1,4 +1,4 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{strings::*, SMBiosVersion, StructBuilder, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::{
@@ -55,17 +55,47 @@ impl<'a> SMBiosSystemInformation<'a> {
     }
 
     /// System UUID
+    ///
+    /// Assumes the byte order of version 2.6 and later, in which the first
+    /// three fields are little-endian.  Use [SMBiosSystemInformation::uuid_for_version]
+    /// when the table version is known.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn uuid(&self) -> Option<SystemUuidData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_data(0x08, 0x18)
             .map(|raw| SystemUuidData::try_from(raw).expect("A GUID is 0x10 bytes"))
     }
 
+    /// System UUID of a table with the given SMBIOS version
+    ///
+    /// Before version 2.6 the specification did not define the byte order and
+    /// implementations stored every field in network (big-endian) order; such
+    /// UUIDs are converted to the 2.6 and later encoding held by [SystemUuid].
+    /// An unknown version is treated as 2.6 or later.
+    pub fn uuid_for_version(&self, version: Option<SMBiosVersion>) -> Option<SystemUuidData> {
+        match version {
+            Some(version) if version < SMBiosVersion::new(2, 6, 0) => {
+                self.uuid().map(|uuid_data| match uuid_data {
+                    SystemUuidData::Uuid(uuid) => {
+                        SystemUuidData::Uuid(SystemUuid::from_rfc4122_bytes(&uuid.raw))
+                    }
+                    sentinel => sentinel,
+                })
+            }
+            _ => self.uuid(),
+        }
+    }
+
     /// Wake-up type
     ///
     /// Identifies the event that caused the system to power up.
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn wakeup_type(&self) -> Option<SystemWakeUpTypeData> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x18)
             .map(|raw| SystemWakeUpTypeData::from(raw))
     }
@@ -81,8 +111,13 @@ impl<'a> SMBiosSystemInformation<'a> {
     /// given OEM, there are tens of unique
     /// processor, memory, hard drive, and optical
     /// drive configurations.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn sku_number(&self) -> SMBiosString {
-        self.parts.get_field_string(0x19)
+        match self.parts.supports(SMBiosVersion::new(2, 4, 0)) {
+            true => self.parts.get_field_string(0x19),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 
     /// Family
@@ -96,8 +131,13 @@ impl<'a> SMBiosSystemInformation<'a> {
     /// different configurations and pricing points.
     /// Computers in the same family often have
     /// similar branding and cosmetic features.
+    ///
+    /// Added in SMBIOS 2.4; absent from tables of earlier versions.
     pub fn family(&self) -> SMBiosString {
-        self.parts.get_field_string(0x1A)
+        match self.parts.supports(SMBiosVersion::new(2, 4, 0)) {
+            true => self.parts.get_field_string(0x1A),
+            false => Err(SMBiosStringError::FieldOutOfBounds).into(),
+        }
     }
 }
 
@@ -136,12 +176,105 @@ impl Serialize for SMBiosSystemInformation<'_> {
     }
 }
 
+/// # System Information (Type 1) Builder
+///
+/// Builds a [SMBiosSystemInformation] structure for use with a
+/// [TableBuilder](crate::TableBuilder).
+///
+/// ```
+/// # use smbioslib::*;
+/// let mut table = TableBuilder::new();
+/// table.add(
+///     SystemInformationBuilder::new()
+///         .manufacturer("Manufacturer")
+///         .product_name("Product")
+///         .wakeup_type(SystemWakeUpType::PowerSwitch),
+/// );
+/// let data = table.build_smbios_data(None);
+///
+/// let system_information = data.first::<SMBiosSystemInformation>().unwrap();
+/// assert_eq!(system_information.product_name().to_string(), "Product");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SystemInformationBuilder(StructBuilder);
+
+impl SystemInformationBuilder {
+    /// Creates a builder for an SMBIOS 2.4 length structure with all strings absent
+    /// and an unknown wake-up type
+    pub fn new() -> Self {
+        Self(
+            StructBuilder::with_length(SMBiosSystemInformation::STRUCT_TYPE, 0x1B).byte(0x18, 0x02),
+        )
+    }
+
+    /// Manufacturer
+    pub fn manufacturer(self, value: &str) -> Self {
+        Self(self.0.string(0x04, value))
+    }
+
+    /// Product name
+    pub fn product_name(self, value: &str) -> Self {
+        Self(self.0.string(0x05, value))
+    }
+
+    /// Version
+    pub fn version(self, value: &str) -> Self {
+        Self(self.0.string(0x06, value))
+    }
+
+    /// Serial number
+    pub fn serial_number(self, value: &str) -> Self {
+        Self(self.0.string(0x07, value))
+    }
+
+    /// System UUID
+    ///
+    /// The bytes are written as they appear in the structure (the first three
+    /// fields are little-endian).
+    pub fn uuid(self, value: [u8; 0x10]) -> Self {
+        Self(self.0.data(0x08, &value))
+    }
+
+    /// Wake-up type
+    ///
+    /// [SystemWakeUpType::None] is written as Unknown.
+    pub fn wakeup_type(self, value: SystemWakeUpType) -> Self {
+        let raw = SYSTEM_WAKE_UP_TYPES
+            .iter()
+            .find(|(_, defined)| *defined == value)
+            .map_or(0x02, |(raw, _)| *raw);
+        Self(self.0.byte(0x18, raw))
+    }
+
+    /// SKU Number
+    pub fn sku_number(self, value: &str) -> Self {
+        Self(self.0.string(0x19, value))
+    }
+
+    /// Family
+    pub fn family(self, value: &str) -> Self {
+        Self(self.0.string(0x1A, value))
+    }
+}
+
+impl Default for SystemInformationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SystemInformationBuilder> for StructBuilder {
+    fn from(builder: SystemInformationBuilder) -> Self {
+        builder.0
+    }
+}
+
 /// # System - UUID Data
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum SystemUuidData {
-    /// The ID is not currently present in the system, but it can be set
+    /// The ID is not currently present in the system, but it can be set (all FFh)
     IdNotPresentButSettable,
-    /// The ID is not present in the system
+    /// The ID is not present in the system (all 00h)
     IdNotPresent,
     /// System UUID
     Uuid(SystemUuid),
@@ -149,9 +282,9 @@ pub enum SystemUuidData {
 
 impl SystemUuidData {
     fn new<'a>(array: &'a [u8; 0x10]) -> SystemUuidData {
-        if array.iter().all(|&x| x == 0) {
+        if array.iter().all(|&x| x == 0xFF) {
             SystemUuidData::IdNotPresentButSettable
-        } else if array.iter().all(|&x| x == 0xFF) {
+        } else if array.iter().all(|&x| x == 0) {
             SystemUuidData::IdNotPresent
         } else {
             SystemUuidData::Uuid(SystemUuid::from(array))
@@ -178,13 +311,42 @@ impl fmt::Display for SystemUuidData {
 }
 
 /// # System - UUID
-#[derive(PartialEq, Eq)]
+///
+/// Holds the UUID as encoded by SMBIOS 2.6 and later: the time_low,
+/// time_mid and time_hi_and_version fields are little-endian and the
+/// remaining bytes are in RFC 4122 order.  [fmt::Display] produces the
+/// canonical RFC 4122 string.
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub struct SystemUuid {
     /// Raw byte array for this UUID
     pub raw: [u8; 0x10],
 }
 
 impl SystemUuid {
+    /// Creates a UUID from its RFC 4122 (network order) bytes
+    pub fn from_rfc4122_bytes(bytes: &[u8; 0x10]) -> Self {
+        SystemUuid {
+            raw: Self::swap_fields(bytes),
+        }
+    }
+
+    /// The RFC 4122 (network order) bytes of this UUID
+    ///
+    /// This is the order expected by UUID libraries, for example
+    /// `uuid::Uuid::from_bytes`.
+    pub fn to_rfc4122_bytes(&self) -> [u8; 0x10] {
+        Self::swap_fields(&self.raw)
+    }
+
+    /// Reverses the byte order of the first three fields
+    fn swap_fields(bytes: &[u8; 0x10]) -> [u8; 0x10] {
+        let mut swapped = *bytes;
+        swapped[..0x4].reverse();
+        swapped[0x4..0x6].reverse();
+        swapped[0x6..0x8].reverse();
+        swapped
+    }
+
     /// Low field of the timestamp
     pub fn time_low(&self) -> u32 {
         u32::from_le_bytes(self.raw[..0x4].try_into().expect("incorrect size"))
@@ -316,7 +478,7 @@ impl Deref for SystemWakeUpTypeData {
 }
 
 /// # System - Wake-up Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemWakeUpType {
     /// Other
     Other,
@@ -325,7 +487,7 @@ pub enum SystemWakeUpType {
     /// APM Timer
     ApmTimer,
     /// Modem Ring
-    ModernRing,
+    ModemRing,
     /// LAN Remote
     LanRemote,
     /// Power Switch
@@ -338,20 +500,32 @@ pub enum SystemWakeUpType {
     None,
 }
 
+impl SystemWakeUpType {
+    /// Modem Ring, under its former misspelled name
+    #[deprecated(note = "use SystemWakeUpType::ModemRing")]
+    #[allow(non_upper_case_globals)]
+    pub const ModernRing: SystemWakeUpType = SystemWakeUpType::ModemRing;
+}
+
+/// Every wake-up type defined by the specification with its raw value
+const SYSTEM_WAKE_UP_TYPES: &[(u8, SystemWakeUpType)] = &[
+    (0x01, SystemWakeUpType::Other),
+    (0x02, SystemWakeUpType::Unknown),
+    (0x03, SystemWakeUpType::ApmTimer),
+    (0x04, SystemWakeUpType::ModemRing),
+    (0x05, SystemWakeUpType::LanRemote),
+    (0x06, SystemWakeUpType::PowerSwitch),
+    (0x07, SystemWakeUpType::PciPme),
+    (0x08, SystemWakeUpType::ACPowerRestored),
+];
+
 impl From<u8> for SystemWakeUpTypeData {
     fn from(raw: u8) -> Self {
         SystemWakeUpTypeData {
-            value: match raw {
-                0x01 => SystemWakeUpType::Other,
-                0x02 => SystemWakeUpType::Unknown,
-                0x03 => SystemWakeUpType::ApmTimer,
-                0x04 => SystemWakeUpType::ModernRing,
-                0x05 => SystemWakeUpType::LanRemote,
-                0x06 => SystemWakeUpType::PowerSwitch,
-                0x07 => SystemWakeUpType::PciPme,
-                0x08 => SystemWakeUpType::ACPowerRestored,
-                _ => SystemWakeUpType::None,
-            },
+            value: SYSTEM_WAKE_UP_TYPES
+                .iter()
+                .find(|(defined, _)| *defined == raw)
+                .map_or(SystemWakeUpType::None, |(_, value)| *value),
             raw,
         }
     }
@@ -409,4 +583,126 @@ mod tests {
             "ThinkStation P520".to_string()
         );
     }
+
+    #[test]
+    fn test_builder() {
+        let builder = SystemInformationBuilder::new()
+            .manufacturer("LENOVO")
+            .product_name("30BFS07500")
+            .version("ThinkStation P520")
+            .serial_number("MN06PQRS")
+            .uuid([
+                0xD2, 0x01, 0x25, 0x3E, 0x48, 0xE6, 0x11, 0xE8, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F,
+                0x9D, 0x47,
+            ])
+            .wakeup_type(SystemWakeUpType::PowerSwitch)
+            .sku_number("LENOVO_MT_30BF_BU_Think_FM_ThinkStation P520")
+            .family("ThinkStation P520");
+        let raw = StructBuilder::from(builder).build(crate::core::Handle(1));
+
+        // Identical to the structure in unit_test() apart from the shared family string
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(parts.header.length(), 0x1B);
+        assert_eq!(test_struct.manufacturer().to_string(), "LENOVO");
+        assert_eq!(test_struct.serial_number().to_string(), "MN06PQRS");
+        assert_eq!(
+            format!("{:?}", test_struct.uuid()),
+            "Some(Uuid(3e2501d2-e648-e811-bad3-7020840f9d47))"
+        );
+        assert_eq!(
+            *test_struct.wakeup_type().unwrap(),
+            SystemWakeUpType::PowerSwitch
+        );
+        assert_eq!(test_struct.family().to_string(), "ThinkStation P520");
+        assert_eq!(parts.strings.iter().count(), 5);
+    }
+
+    #[test]
+    fn test_uuid() {
+        let raw = [
+            0xD2, 0x01, 0x25, 0x3E, 0x48, 0xE6, 0x11, 0xE8, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F,
+            0x9D, 0x47,
+        ];
+        let uuid = SystemUuid::from(&raw);
+        let rfc4122 = [
+            0x3E, 0x25, 0x01, 0xD2, 0xE6, 0x48, 0xE8, 0x11, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F,
+            0x9D, 0x47,
+        ];
+        assert_eq!(uuid.to_rfc4122_bytes(), rfc4122);
+        assert_eq!(SystemUuid::from_rfc4122_bytes(&rfc4122), uuid);
+
+        let build = |value: [u8; 0x10]| {
+            StructBuilder::from(SystemInformationBuilder::new().uuid(value))
+                .build(crate::core::Handle(1))
+        };
+
+        // Before 2.6 the bytes are in network order
+        let parts = UndefinedStruct::new(&build(rfc4122));
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(
+            test_struct
+                .uuid_for_version(Some(SMBiosVersion::new(2, 5, 0)))
+                .unwrap()
+                .to_string(),
+            "3e2501d2-e648-e811-bad3-7020840f9d47"
+        );
+        let parts = UndefinedStruct::new(&build(raw));
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        for version in [None, Some(SMBiosVersion::new(2, 6, 0))] {
+            assert_eq!(
+                test_struct.uuid_for_version(version).unwrap().to_string(),
+                "3e2501d2-e648-e811-bad3-7020840f9d47"
+            );
+        }
+
+        // Sentinels
+        let parts = UndefinedStruct::new(&build([0xFF; 0x10]));
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(
+            test_struct.uuid(),
+            Some(SystemUuidData::IdNotPresentButSettable)
+        );
+        assert_eq!(
+            test_struct.uuid_for_version(Some(SMBiosVersion::new(2, 4, 0))),
+            Some(SystemUuidData::IdNotPresentButSettable)
+        );
+        let parts = UndefinedStruct::new(&build([0x00; 0x10]));
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(test_struct.uuid(), Some(SystemUuidData::IdNotPresent));
+    }
+
+    #[test]
+    fn test_wakeup_type() {
+        let expected = [
+            (0x01, SystemWakeUpType::Other),
+            (0x02, SystemWakeUpType::Unknown),
+            (0x03, SystemWakeUpType::ApmTimer),
+            (0x04, SystemWakeUpType::ModemRing),
+            (0x05, SystemWakeUpType::LanRemote),
+            (0x06, SystemWakeUpType::PowerSwitch),
+            (0x07, SystemWakeUpType::PciPme),
+            (0x08, SystemWakeUpType::ACPowerRestored),
+        ];
+        for (raw, value) in expected {
+            let builder = SystemInformationBuilder::new().wakeup_type(value);
+            let built = StructBuilder::from(builder).build(crate::core::Handle(1));
+            let parts = UndefinedStruct::new(&built);
+            let wakeup_type = SMBiosSystemInformation::new(&parts).wakeup_type().unwrap();
+            assert_eq!(wakeup_type.raw, raw);
+            assert_eq!(*wakeup_type, value);
+        }
+
+        // Reserved and out of range values keep the raw value
+        for raw in [0x00, 0x09, 0xFF] {
+            let wakeup_type = SystemWakeUpTypeData::from(raw);
+            assert_eq!(wakeup_type.value, SystemWakeUpType::None);
+            assert_eq!(wakeup_type.raw, raw);
+            assert_eq!(wakeup_type.to_string(), raw.to_string());
+        }
+        assert_eq!(SystemWakeUpTypeData::from(0x04).to_string(), "ModemRing");
+        #[allow(deprecated)]
+        let modern_ring = SystemWakeUpType::ModernRing;
+        assert_eq!(*SystemWakeUpTypeData::from(0x04), modern_ring);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{BcdValue, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -71,6 +71,17 @@ impl<'a> SMBiosSystemPowerControls<'a> {
     pub fn next_scheduled_power_on_second(&self) -> Option<u8> {
         self.parts.get_field_byte(0x08)
     }
+
+    /// The next scheduled power-on with each BCD field decoded
+    pub fn next_scheduled_power_on(&self) -> Option<ScheduledPowerOn> {
+        Some(ScheduledPowerOn {
+            month: BcdValue::from(self.next_scheduled_power_on_month()?),
+            day_of_month: BcdValue::from(self.next_scheduled_power_on_day_of_month()?),
+            hour: BcdValue::from(self.next_scheduled_power_on_hour()?),
+            minute: BcdValue::from(self.next_scheduled_power_on_minute()?),
+            second: BcdValue::from(self.next_scheduled_power_on_second()?),
+        })
+    }
 }
 
 impl fmt::Debug for SMBiosSystemPowerControls<'_> {
@@ -132,6 +143,36 @@ impl Serialize for SMBiosSystemPowerControls<'_> {
     }
 }
 
+/// # Scheduled Power-On
+///
+/// The decoded next scheduled power-on of [SMBiosSystemPowerControls].
+///
+/// An unspecified (FFh) field matches any value.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ScheduledPowerOn {
+    /// Month (1-12)
+    pub month: BcdValue,
+    /// Day of the month (1-31)
+    pub day_of_month: BcdValue,
+    /// Hour (0-23)
+    pub hour: BcdValue,
+    /// Minute (0-59)
+    pub minute: BcdValue,
+    /// Second (0-59)
+    pub second: BcdValue,
+}
+
+/// Formats as `MM-DD hh:mm:ss` with `*` for unspecified fields
+impl fmt::Display for ScheduledPowerOn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}:{}:{}",
+            self.month, self.day_of_month, self.hour, self.minute, self.second
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +192,45 @@ mod tests {
         assert_eq!(test_struct.next_scheduled_power_on_minute(), Some(0));
         assert_eq!(test_struct.next_scheduled_power_on_second(), Some(0));
     }
+
+    #[test]
+    fn test_next_scheduled_power_on() {
+        let struct_type25 = vec![
+            0x19, 0x09, 0x27, 0x00, 0x12, 0xFF, 0x23, 0x59, 0x05, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type25);
+        let test_struct = SMBiosSystemPowerControls::new(&parts);
+
+        let next_scheduled_power_on = test_struct.next_scheduled_power_on().unwrap();
+        assert_eq!(
+            next_scheduled_power_on,
+            ScheduledPowerOn {
+                month: BcdValue::Value(12),
+                day_of_month: BcdValue::Unspecified,
+                hour: BcdValue::Value(23),
+                minute: BcdValue::Value(59),
+                second: BcdValue::Value(5),
+            }
+        );
+        assert_eq!(next_scheduled_power_on.to_string(), "12-* 23:59:05");
+
+        // A malformed field is not treated as unspecified
+        let struct_type25 = vec![
+            0x19, 0x09, 0x27, 0x00, 0x1A, 0xFF, 0x23, 0x59, 0x05, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type25);
+        let next_scheduled_power_on = SMBiosSystemPowerControls::new(&parts)
+            .next_scheduled_power_on()
+            .unwrap();
+        assert_eq!(next_scheduled_power_on.month, BcdValue::Invalid(0x1A));
+        assert_eq!(next_scheduled_power_on.to_string(), "1Ah-* 23:59:05");
+
+        // Truncated before the second field
+        let struct_type25 = vec![0x19, 0x08, 0x27, 0x00, 0x12, 0xFF, 0x23, 0x59, 0x00, 0x00];
+        let parts = UndefinedStruct::new(&struct_type25);
+        assert!(SMBiosSystemPowerControls::new(&parts)
+            .next_scheduled_power_on()
+            .is_none());
+    }
 }
@@ -115,7 +115,7 @@ impl Serialize for SMBiosSystemReset<'_> {
 }
 
 /// # System Reset Capabilities
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct SystemResetCapabilities {
     /// Raw byte of the system reset capabilities
     pub raw: u8,
@@ -190,7 +190,7 @@ impl Serialize for SystemResetCapabilities {
 ///
 /// Identifies one of the following system actions to
 /// be taken when the Reset Limit is reached
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BootOptionOnLimit {
     /// Reserved, do not use.
     Reserved,
@@ -218,7 +218,7 @@ impl From<u8> for BootOptionOnLimit {
 ///
 /// Indicates one of the following actions to be taken
 //  after a watchdog reset
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BootOption {
     /// Reserved, do not use.
     Reserved,
@@ -243,7 +243,7 @@ impl From<u8> for BootOption {
 }
 
 /// # Reset Count
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ResetCount {
     /// Number of automatic system resets since the last intentional reset
     Count(u16),
@@ -251,6 +251,16 @@ pub enum ResetCount {
     Unknown,
 }
 
+impl ResetCount {
+    /// The count, `None` when unknown
+    pub fn count(&self) -> Option<u16> {
+        match self {
+            ResetCount::Count(value) => Some(*value),
+            ResetCount::Unknown => None,
+        }
+    }
+}
+
 impl From<u16> for ResetCount {
     fn from(raw: u16) -> Self {
         match raw {
@@ -261,7 +271,7 @@ impl From<u16> for ResetCount {
 }
 
 /// # Reset Limit
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ResetLimit {
     /// Number of consecutive times the system reset is attempted
     Count(u16),
@@ -269,6 +279,16 @@ pub enum ResetLimit {
     Unknown,
 }
 
+impl ResetLimit {
+    /// The limit, `None` when unknown
+    pub fn count(&self) -> Option<u16> {
+        match self {
+            ResetLimit::Count(value) => Some(*value),
+            ResetLimit::Unknown => None,
+        }
+    }
+}
+
 impl From<u16> for ResetLimit {
     fn from(raw: u16) -> Self {
         match raw {
@@ -279,7 +299,7 @@ impl From<u16> for ResetLimit {
 }
 
 /// # Timer Interval
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TimerInterval {
     /// Number of minutes to use for the watchdog timer
     ///
@@ -290,6 +310,16 @@ pub enum TimerInterval {
     Unknown,
 }
 
+impl TimerInterval {
+    /// The interval in minutes, `None` when unknown
+    pub fn minutes(&self) -> Option<u16> {
+        match self {
+            TimerInterval::Minutes(value) => Some(*value),
+            TimerInterval::Unknown => None,
+        }
+    }
+}
+
 impl From<u16> for TimerInterval {
     fn from(raw: u16) -> Self {
         match raw {
@@ -300,7 +330,7 @@ impl From<u16> for TimerInterval {
 }
 
 /// # Timeout
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Timeout {
     /// Number of minutes before the reboot is initiated
     ///
@@ -311,6 +341,16 @@ pub enum Timeout {
     Unknown,
 }
 
+impl Timeout {
+    /// The timeout in minutes, `None` when unknown
+    pub fn minutes(&self) -> Option<u16> {
+        match self {
+            Timeout::Minutes(value) => Some(*value),
+            Timeout::Unknown => None,
+        }
+    }
+}
+
 impl From<u16> for Timeout {
     fn from(raw: u16) -> Self {
         match raw {
@@ -355,4 +395,29 @@ mod tests {
             Timeout::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_known_values() {
+        let struct_type23 = vec![
+            0x17, 0x0D, 0x4F, 0x01, 0x2B, 0x02, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x03, 0x00, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type23);
+        let test_struct = SMBiosSystemReset::new(&parts);
+
+        let capabilities = test_struct.capabilities().unwrap();
+        assert!(capabilities.has_watchdog_timer());
+        assert_eq!(
+            capabilities.boot_option_on_limit(),
+            BootOptionOnLimit::OperatingSystem
+        );
+        assert_eq!(capabilities.boot_option(), BootOption::OperatingSystem);
+        assert!(capabilities.reset_enabled());
+        assert_eq!(test_struct.reset_count(), Some(ResetCount::Count(2)));
+        assert_eq!(test_struct.reset_limit().unwrap().count(), Some(5));
+        assert_eq!(test_struct.timer_interval().unwrap().minutes(), Some(10));
+        assert_eq!(test_struct.timeout(), Some(Timeout::Minutes(3)));
+        assert_eq!(TimerInterval::from(0xFFFF).minutes(), None);
+    }
 }
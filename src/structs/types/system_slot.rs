@@ -1,4 +1,4 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{flag_set, strings::*, SMBiosVersion, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::{convert::TryInto, fmt, ops::Deref};
@@ -76,41 +76,77 @@ impl<'a> SMBiosSystemSlot<'a> {
     }
 
     /// Slot Characteristics 2
+    ///
+    /// Added in SMBIOS 2.1; absent from tables of earlier versions.
     pub fn slot_characteristics_2(&self) -> Option<SystemSlotCharacteristics2> {
         self.parts
+            .since(SMBiosVersion::new(2, 1, 0))?
             .get_field_byte(0x0C)
             .map(|raw| SystemSlotCharacteristics2::from(raw))
     }
 
     /// Segment Group Number (Base)
+    ///
+    /// Added in SMBIOS 2.6; absent from tables of earlier versions.
     pub fn segment_group_number(&self) -> Option<SegmentGroupNumber> {
         self.parts
+            .since(SMBiosVersion::new(2, 6, 0))?
             .get_field_word(0x0D)
             .map(|raw| SegmentGroupNumber::from(raw))
     }
 
     /// Bus Number (Base)
+    ///
+    /// Added in SMBIOS 2.6; absent from tables of earlier versions.
     pub fn bus_number(&self) -> Option<BusNumber> {
         self.parts
+            .since(SMBiosVersion::new(2, 6, 0))?
             .get_field_byte(0x0F)
             .map(|raw| BusNumber::from(raw))
     }
 
     /// Device/Function Number (Base)
+    ///
+    /// Added in SMBIOS 2.6; absent from tables of earlier versions.
     pub fn device_function_number(&self) -> Option<DeviceFunctionNumber> {
         self.parts
+            .since(SMBiosVersion::new(2, 6, 0))?
             .get_field_byte(0x10)
             .map(|raw| DeviceFunctionNumber::from(raw))
     }
 
+    /// Segment Group Number, Bus Number and Device/Function Number (Base)
+    /// as a [PciAddress]
+    ///
+    /// `None` when the fields are absent (before version 2.6) or any of them
+    /// is not applicable (FFh), as for slots which are not PCI, AGP, PCI-X or
+    /// PCI Express.
+    ///
+    /// Added in SMBIOS 2.6; absent from tables of earlier versions.
+    pub fn pci_address(&self) -> Option<PciAddress> {
+        self.parts.since(SMBiosVersion::new(2, 6, 0))?;
+        PciAddress::from_fields(
+            self.parts.get_field_word(0x0D)?,
+            self.parts.get_field_byte(0x0F)?,
+            self.parts.get_field_byte(0x10)?,
+        )
+    }
+
     /// Data Bus Width (Base)
+    ///
+    /// Added in SMBIOS 3.2; absent from tables of earlier versions.
     pub fn data_bus_width(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x11)
+        self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
+            .get_field_byte(0x11)
     }
 
     /// Number of peer Segment/Bus/Device/Function/Width groups that follow
+    ///
+    /// Added in SMBIOS 3.2; absent from tables of earlier versions.
     pub fn peer_group_count(&self) -> Option<usize> {
         self.parts
+            .since(SMBiosVersion::new(3, 2, 0))?
             .get_field_byte(0x12)
             .and_then(|count| Some(count as usize))
     }
@@ -122,14 +158,39 @@ impl<'a> SMBiosSystemSlot<'a> {
     }
 
     /// Iterates over the [SlotPeerGroup] entries
-    pub fn peer_group_iterator(&'a self) -> SlotPeerGroupIterator<'a> {
+    pub fn peer_group_iterator(&self) -> SlotPeerGroupIterator<'a> {
         SlotPeerGroupIterator::new(self)
     }
 
     /// Slot Information
+    ///
+    /// For PCI Express slots this is the generation of the slot (for
+    /// example, 04h for PCI Express Gen 4); otherwise it is 0.
+    ///
+    /// Available in version 3.4.0 and later.  This and the following fields
+    /// are found after the peer groups, so their offsets depend on
+    /// [SMBiosSystemSlot::peer_group_count], and are `None` in tables of
+    /// earlier versions, whose structures may be padded past the peer groups.
     pub fn slot_information(&self) -> Option<u8> {
-        self.peer_group_size()
-            .and_then(|size| self.parts.get_field_byte(size + 0x13))
+        let size = self.peer_group_size()?;
+        self.parts
+            .since(SMBiosVersion::new(3, 4, 0))?
+            .get_field_byte(size + 0x13)
+    }
+
+    /// The PCI Express generation of the slot
+    ///
+    /// Taken from [SMBiosSystemSlot::slot_information] when present and
+    /// non-zero, otherwise from the generation given by the
+    /// [SMBiosSystemSlot::system_slot_type].  `None` when neither defines it.
+    pub fn pci_express_generation(&self) -> Option<u8> {
+        match self.slot_information() {
+            Some(generation) if generation != 0 => Some(generation),
+            _ => self
+                .system_slot_type()?
+                .pci_express_generation()?
+                .generation(),
+        }
     }
 
     /// Slot Physical Width
@@ -138,12 +199,14 @@ impl<'a> SMBiosSystemSlot<'a> {
     /// electrical width of the slot.
     ///
     /// The possible values of both fields are listed in Table 46 – System Slots: Slot Width field.
+    ///
+    /// Available in version 3.4.0 and later.
     pub fn slot_physical_width(&self) -> Option<SlotWidthData> {
-        self.peer_group_size().and_then(|size| {
-            self.parts
-                .get_field_byte(size + 0x14)
-                .map(|raw| SlotWidthData::from(raw))
-        })
+        let size = self.peer_group_size()?;
+        self.parts
+            .since(SMBiosVersion::new(3, 4, 0))?
+            .get_field_byte(size + 0x14)
+            .map(|raw| SlotWidthData::from(raw))
     }
 
     /// Slot Pitch
@@ -159,9 +222,13 @@ impl<'a> SMBiosSystemSlot<'a> {
     /// For example, if the pitch for the slot is 12.5 mm, the value 1250 would be used.
     ///
     /// A value of 0 implies that the slot pitch is not given or is unknown.
+    ///
+    /// Available in version 3.4.0 and later.
     pub fn slot_pitch(&self) -> Option<u16> {
-        self.peer_group_size()
-            .and_then(|size| self.parts.get_field_word(size + 0x15))
+        let size = self.peer_group_size()?;
+        self.parts
+            .since(SMBiosVersion::new(3, 4, 0))?
+            .get_field_word(size + 0x15)
     }
 
     /// Slot Height
@@ -170,11 +237,11 @@ impl<'a> SMBiosSystemSlot<'a> {
     ///
     /// Available in version 3.5.0 and later.
     pub fn slot_height(&self) -> Option<SlotHeightData> {
-        self.peer_group_size().and_then(|size| {
-            self.parts
-                .get_field_byte(size + 0x17)
-                .map(|raw| SlotHeightData::from(raw))
-        })
+        let size = self.peer_group_size()?;
+        self.parts
+            .since(SMBiosVersion::new(3, 5, 0))?
+            .get_field_byte(size + 0x17)
+            .map(|raw| SlotHeightData::from(raw))
     }
 }
 
@@ -413,7 +480,7 @@ impl fmt::Display for SystemSlotTypeData {
 }
 
 /// # System Slot Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemSlotType {
     /// Other
     Other,
@@ -479,8 +546,26 @@ pub enum SystemSlotType {
     None,
 }
 
+impl SystemSlotType {
+    /// The generation of a PCI Express slot, `None` for other slot types
+    pub fn pci_express_generation(&self) -> Option<PciExpressGeneration> {
+        match self {
+            SystemSlotType::PciExpress(generation, _) => Some(*generation),
+            _ => None,
+        }
+    }
+
+    /// The width of a PCI Express slot, `None` for other slot types
+    pub fn pci_express_width(&self) -> Option<PciExpressSlotWidth> {
+        match self {
+            SystemSlotType::PciExpress(_, width) => Some(*width),
+            _ => None,
+        }
+    }
+}
+
 /// The generation of PciExpress used by the slot.
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PciExpressGeneration {
     /// PCI Express Gen 1
     PCIExpressGen1,
@@ -498,8 +583,25 @@ pub enum PciExpressGeneration {
     Undefined,
 }
 
+impl PciExpressGeneration {
+    /// The generation number, 6 for Gen 6 and beyond
+    ///
+    /// `None` when the slot type does not define the generation.
+    pub fn generation(&self) -> Option<u8> {
+        match self {
+            PciExpressGeneration::PCIExpressGen1 => Some(1),
+            PciExpressGeneration::PCIExpressGen2 => Some(2),
+            PciExpressGeneration::PCIExpressGen3 => Some(3),
+            PciExpressGeneration::PCIExpressGen4 => Some(4),
+            PciExpressGeneration::PCIExpressGen5 => Some(5),
+            PciExpressGeneration::PCIExpressGen6 => Some(6),
+            PciExpressGeneration::Undefined => None,
+        }
+    }
+}
+
 /// The slot width of a PCI Express slot specified in the SystemSlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PciExpressSlotWidth {
     /// An undefined slot width
     UndefinedSlotWidth,
@@ -523,8 +625,26 @@ pub enum PciExpressSlotWidth {
     PciExpressMini76,
 }
 
+impl PciExpressSlotWidth {
+    /// The number of lanes, `None` for a form factor without a defined width
+    pub fn lanes(&self) -> Option<u8> {
+        match self {
+            PciExpressSlotWidth::X1 => Some(1),
+            PciExpressSlotWidth::X2 => Some(2),
+            PciExpressSlotWidth::X4 => Some(4),
+            PciExpressSlotWidth::X8 => Some(8),
+            PciExpressSlotWidth::X16 => Some(16),
+            PciExpressSlotWidth::UndefinedSlotWidth
+            | PciExpressSlotWidth::Sff8639
+            | PciExpressSlotWidth::PciExpressMini52WithKeepouts
+            | PciExpressSlotWidth::PciExpressMini52WithoutKeepouts
+            | PciExpressSlotWidth::PciExpressMini76 => None,
+        }
+    }
+}
+
 /// The slot width of an AGP slot specified in the SystemSlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AgpSlotWidth {
     /// X1
     X1,
@@ -537,7 +657,7 @@ pub enum AgpSlotWidth {
 }
 
 /// An MXM SlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MXMSlotType {
     /// MXM Type I
     MxmTypeI,
@@ -556,7 +676,7 @@ pub enum MXMSlotType {
 }
 
 /// An M.2 SlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum M2SlotType {
     /// M.2 Socket 1-DP (Mechanical Key A)
     M2Socket1DP,
@@ -635,8 +755,17 @@ impl Serialize for SlotWidthData {
     }
 }
 
+impl fmt::Display for SlotWidthData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            SlotWidth::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 /// # Slot Width
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SlotWidth {
     /// Other
     Other,
@@ -670,6 +799,34 @@ pub enum SlotWidth {
     None,
 }
 
+impl SlotWidth {
+    /// The number of lanes of a serial bus width (x1 through x32)
+    pub fn lanes(&self) -> Option<u8> {
+        match self {
+            SlotWidth::X1 => Some(1),
+            SlotWidth::X2 => Some(2),
+            SlotWidth::X4 => Some(4),
+            SlotWidth::X8 => Some(8),
+            SlotWidth::X12 => Some(12),
+            SlotWidth::X16 => Some(16),
+            SlotWidth::X32 => Some(32),
+            _ => None,
+        }
+    }
+
+    /// The number of bits of a parallel bus width (8 through 128 bit)
+    pub fn bits(&self) -> Option<u8> {
+        match self {
+            SlotWidth::Bit8 => Some(8),
+            SlotWidth::Bit16 => Some(16),
+            SlotWidth::Bit32 => Some(32),
+            SlotWidth::Bit64 => Some(64),
+            SlotWidth::Bit128 => Some(128),
+            _ => None,
+        }
+    }
+}
+
 /// # Slot Height Data
 pub struct SlotHeightData {
     /// Raw value
@@ -900,7 +1057,15 @@ pub enum SlotLength {
 }
 
 /// # System Slot Characteristics 1
-#[derive(PartialEq, Eq)]
+///
+/// ```
+/// # use smbioslib::*;
+/// let characteristics = SystemSlotCharacteristics1::from(0x0C);
+/// assert!(characteristics.contains(
+///     SystemSlotCharacteristics1::PROVIDES_3_3_VOLTS | SystemSlotCharacteristics1::SHARED
+/// ));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct SystemSlotCharacteristics1 {
     /// Raw value
     ///
@@ -923,47 +1088,85 @@ impl From<u8> for SystemSlotCharacteristics1 {
 }
 
 impl SystemSlotCharacteristics1 {
+    /// Characteristics unknown.
+    pub const UNKNOWN: Self = Self { raw: 0x01 };
+
+    /// Provides 5.0 volts.
+    pub const PROVIDES_5_VOLTS: Self = Self { raw: 0x02 };
+
+    /// Provides 3.3 volts.
+    pub const PROVIDES_3_3_VOLTS: Self = Self { raw: 0x04 };
+
+    /// Slot’s opening is shared with another slot (for example, PCI/EISA shared slot).
+    pub const SHARED: Self = Self { raw: 0x08 };
+
+    /// PC Card slot supports PC Card-16.
+    pub const PC_CARD_16: Self = Self { raw: 0x10 };
+
+    /// PC Card slot supports CardBus.
+    pub const CARD_BUS: Self = Self { raw: 0x20 };
+
+    /// PC Card slot supports Zoom Video.
+    pub const ZOOM_VIDEO: Self = Self { raw: 0x40 };
+
+    /// PC Card slot supports Modem Ring Resume.
+    pub const MODEM_RING_RESUME: Self = Self { raw: 0x80 };
+
     /// Characteristics unknown.
     pub fn unknown(&self) -> bool {
-        self.raw & 0x01 == 0x01
+        self.contains(Self::UNKNOWN)
     }
 
     /// Provides 5.0 volts.
     pub fn provides5_volts(&self) -> bool {
-        self.raw & 0x02 == 0x02
+        self.contains(Self::PROVIDES_5_VOLTS)
     }
 
     /// Provides 3.3 volts.
     pub fn provides33_volts(&self) -> bool {
-        self.raw & 0x04 == 0x04
+        self.contains(Self::PROVIDES_3_3_VOLTS)
     }
 
     /// Slot’s opening is shared with another slot (for example, PCI/EISA shared slot).
     pub fn shared(&self) -> bool {
-        self.raw & 0x08 == 0x08
+        self.contains(Self::SHARED)
     }
 
     /// PC Card slot supports PC Card-16.
     pub fn supports_pc_card16(&self) -> bool {
-        self.raw & 0x10 == 0x10
+        self.contains(Self::PC_CARD_16)
     }
 
     /// PC Card slot supports CardBus.
     pub fn supports_card_bus(&self) -> bool {
-        self.raw & 0x20 == 0x20
+        self.contains(Self::CARD_BUS)
     }
 
     /// PC Card slot supports Zoom Video.
     pub fn supports_zoom_video(&self) -> bool {
-        self.raw & 0x40 == 0x40
+        self.contains(Self::ZOOM_VIDEO)
     }
 
     /// PC Card slot supports Modem Ring Resume.
     pub fn supports_modem_ring_resume(&self) -> bool {
-        self.raw & 0x80 == 0x80
+        self.contains(Self::MODEM_RING_RESUME)
     }
 }
 
+flag_set!(
+    SystemSlotCharacteristics1,
+    [
+        ("Characteristics unknown", UNKNOWN),
+        ("5.0 V is provided", PROVIDES_5_VOLTS),
+        ("3.3 V is provided", PROVIDES_3_3_VOLTS),
+        ("Opening is shared", SHARED),
+        ("PC Card-16 is supported", PC_CARD_16),
+        ("Cardbus is supported", CARD_BUS),
+        ("Zoom Video is supported", ZOOM_VIDEO),
+        ("Modem ring resume is supported", MODEM_RING_RESUME),
+    ]
+);
+
 impl fmt::Debug for SystemSlotCharacteristics1 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<SystemSlotCharacteristics1>())
@@ -1006,7 +1209,7 @@ impl Serialize for SystemSlotCharacteristics1 {
 }
 
 /// # System Slot Characteristics 2
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct SystemSlotCharacteristics2 {
     /// Raw value
     ///
@@ -1032,19 +1235,43 @@ impl From<u8> for SystemSlotCharacteristics2 {
 }
 
 impl SystemSlotCharacteristics2 {
+    /// PCI slot supports Power Management Event (PME#) signal.
+    pub const POWER_MANAGEMENT_EVENT: Self = Self { raw: 0x01 };
+
+    /// Slot supports hot-plug devices.
+    pub const HOT_PLUG: Self = Self { raw: 0x02 };
+
+    /// PCI slot supports SMBus signal.
+    pub const SMBUS_SIGNAL: Self = Self { raw: 0x04 };
+
+    /// PCIe slot supports bifurcation.
+    pub const BIFURCATION: Self = Self { raw: 0x08 };
+
+    /// Slot supports async/surprise removal.
+    pub const SURPRISE_REMOVAL: Self = Self { raw: 0x10 };
+
+    /// Flexbus slot, CXL 1.0 capable.
+    pub const CXL_1_0: Self = Self { raw: 0x20 };
+
+    /// Flexbus slot, CXL 2.0 capable.
+    pub const CXL_2_0: Self = Self { raw: 0x40 };
+
+    /// Flexbus slot, CXL 3.0 capable
+    pub const CXL_3_0: Self = Self { raw: 0x80 };
+
     /// PCI slot supports Power Management Event (PME#) signal.
     pub fn supports_power_management_event(&self) -> bool {
-        self.raw & 0x01 == 0x01
+        self.contains(Self::POWER_MANAGEMENT_EVENT)
     }
 
     /// Slot supports hot-plug devices.
     pub fn supports_hot_plug_devices(&self) -> bool {
-        self.raw & 0x02 == 0x02
+        self.contains(Self::HOT_PLUG)
     }
 
     /// PCI slot supports SMBus signal.
     pub fn supports_smbus_signal(&self) -> bool {
-        self.raw & 0x04 == 0x04
+        self.contains(Self::SMBUS_SIGNAL)
     }
 
     /// PCIe slot supports bifurcation.
@@ -1053,32 +1280,46 @@ impl SystemSlotCharacteristics2 {
     /// Note: This field does not indicate complete details on what levels of bifurcation
     /// are supported by the slot, but only that the slot supports some level of bifurcation.
     pub fn supports_bifurcation(&self) -> bool {
-        self.raw & 0x08 == 0x08
+        self.contains(Self::BIFURCATION)
     }
 
     /// Slot supports async/surprise removal.
     ///
     /// i.e., removal without prior notification to the operating system, device driver, or applications.
     pub fn supports_suprise_removal(&self) -> bool {
-        self.raw & 0x10 == 0x10
+        self.contains(Self::SURPRISE_REMOVAL)
     }
 
     /// Flexbus slot, CXL 1.0 capable.
     pub fn flexbus_slot_cxl10_capable(&self) -> bool {
-        self.raw & 0x20 == 0x20
+        self.contains(Self::CXL_1_0)
     }
 
     /// Flexbus slot, CXL 2.0 capable.
     pub fn flexbus_slot_cxl20_capable(&self) -> bool {
-        self.raw & 0x40 == 0x40
+        self.contains(Self::CXL_2_0)
     }
 
     /// Flexbus slot, CXL 3.0 capable
     pub fn flexbus_slot_cxl30_capable(&self) -> bool {
-        self.raw & 0x80 == 0x80
+        self.contains(Self::CXL_3_0)
     }
 }
 
+flag_set!(
+    SystemSlotCharacteristics2,
+    [
+        ("PME signal is supported", POWER_MANAGEMENT_EVENT),
+        ("Hot-plug devices are supported", HOT_PLUG),
+        ("SMBus signal is supported", SMBUS_SIGNAL),
+        ("PCIe slot bifurcation is supported", BIFURCATION),
+        ("Async/surprise removal is supported", SURPRISE_REMOVAL),
+        ("Flexbus slot, CXL 1.0 capable", CXL_1_0),
+        ("Flexbus slot, CXL 2.0 capable", CXL_2_0),
+        ("Flexbus slot, CXL 3.0 capable", CXL_3_0),
+    ]
+);
+
 impl fmt::Debug for SystemSlotCharacteristics2 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<SystemSlotCharacteristics2>())
@@ -1115,7 +1356,7 @@ impl Serialize for SystemSlotCharacteristics2 {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SystemSlotCharacteristics2", 8)?;
+        let mut state = serializer.serialize_struct("SystemSlotCharacteristics2", 9)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field(
             "supports_power_management_event",
@@ -1144,6 +1385,66 @@ impl Serialize for SystemSlotCharacteristics2 {
     }
 }
 
+/// # PCI Address
+///
+/// The segment, bus, device and function of a PCI device, displayed in the
+/// form used by `lspci -D`.
+///
+/// ```
+/// # use smbioslib::*;
+/// let address = PciAddress::new(0, 0x17, 0, 0);
+/// assert_eq!(address.to_string(), "0000:17:00.0");
+/// ```
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PciAddress {
+    /// Segment Group Number
+    pub segment: u16,
+    /// Bus Number
+    pub bus: u8,
+    /// Device Number (0 through 31)
+    pub device: u8,
+    /// Function Number (0 through 7)
+    pub function: u8,
+}
+
+impl PciAddress {
+    /// Creates a PCI address
+    pub fn new(segment: u16, bus: u8, device: u8, function: u8) -> Self {
+        PciAddress {
+            segment,
+            bus,
+            device,
+            function,
+        }
+    }
+
+    /// Creates a PCI address from the raw segment group, bus and
+    /// device/function fields, `None` when any is not applicable
+    fn from_fields(segment: u16, bus: u8, device_function: u8) -> Option<Self> {
+        if SegmentGroupNumber::from(segment).is_not_applicable()
+            || BusNumber::from(bus).is_not_applicable()
+        {
+            return None;
+        }
+        match DeviceFunctionNumber::from(device_function) {
+            DeviceFunctionNumber::Number { device, function } => {
+                Some(PciAddress::new(segment, bus, device, function))
+            }
+            DeviceFunctionNumber::NotApplicable => None,
+        }
+    }
+}
+
+impl fmt::Display for PciAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            self.segment, self.bus, self.device, self.function
+        )
+    }
+}
+
 /// # Segment Group Number
 #[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum SegmentGroupNumber {
@@ -1156,11 +1457,18 @@ pub enum SegmentGroupNumber {
     NotApplicable,
 }
 
+impl SegmentGroupNumber {
+    /// The device has no bus/device/function information (all bits set)
+    pub fn is_not_applicable(&self) -> bool {
+        *self == SegmentGroupNumber::NotApplicable
+    }
+}
+
 impl From<u16> for SegmentGroupNumber {
     fn from(raw: u16) -> Self {
         match raw {
-            0x00 => SegmentGroupNumber::SingleSegment,
-            0xFF => SegmentGroupNumber::NotApplicable,
+            0x0000 => SegmentGroupNumber::SingleSegment,
+            0xFFFF => SegmentGroupNumber::NotApplicable,
             _ => SegmentGroupNumber::Number(raw),
         }
     }
@@ -1176,6 +1484,13 @@ pub enum BusNumber {
     NotApplicable,
 }
 
+impl BusNumber {
+    /// The device has no bus/device/function information (all bits set)
+    pub fn is_not_applicable(&self) -> bool {
+        *self == BusNumber::NotApplicable
+    }
+}
+
 impl From<u8> for BusNumber {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1200,6 +1515,13 @@ pub enum DeviceFunctionNumber {
     NotApplicable,
 }
 
+impl DeviceFunctionNumber {
+    /// The device has no bus/device/function information (all bits set)
+    pub fn is_not_applicable(&self) -> bool {
+        *self == DeviceFunctionNumber::NotApplicable
+    }
+}
+
 impl From<u8> for DeviceFunctionNumber {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1214,7 +1536,7 @@ impl From<u8> for DeviceFunctionNumber {
 
 /// # Slot Peer Group entry within [SMBiosSystemSlot]
 pub struct SlotPeerGroup<'a> {
-    system_slot: &'a SMBiosSystemSlot<'a>,
+    parts: &'a UndefinedStruct,
     entry_offset: usize,
 }
 
@@ -1226,31 +1548,28 @@ impl<'a> SlotPeerGroup<'a> {
     const DEVICE_FUNCTION_NUMBER_OFFSET: usize = 3;
     const DATA_BUS_WIDTH_OFFSET: usize = 4;
 
-    fn new(system_slot: &'a SMBiosSystemSlot<'a>, entry_offset: usize) -> Self {
+    fn new(parts: &'a UndefinedStruct, entry_offset: usize) -> Self {
         Self {
-            system_slot,
+            parts,
             entry_offset,
         }
     }
 
     /// Segment Group Number (Peer)
     pub fn segment_group_number(&self) -> Option<u16> {
-        self.system_slot
-            .parts()
+        self.parts
             .get_field_word(self.entry_offset + Self::SEGMENT_GROUP_NUMBER_OFFSET)
     }
 
     /// Bus Number (Peer)
     pub fn bus_number(&self) -> Option<u8> {
-        self.system_slot
-            .parts()
+        self.parts
             .get_field_byte(self.entry_offset + Self::BUS_NUMBER_OFFSET)
     }
 
     /// Device/Function Number (Peer)
     pub fn device_function_number(&self) -> Option<u8> {
-        self.system_slot
-            .parts()
+        self.parts
             .get_field_byte(self.entry_offset + Self::DEVICE_FUNCTION_NUMBER_OFFSET)
     }
 
@@ -1258,10 +1577,35 @@ impl<'a> SlotPeerGroup<'a> {
     ///
     /// Indicates electrical bus width of peer Segment/Bus/Device/Function.
     pub fn data_bus_width(&self) -> Option<u8> {
-        self.system_slot
-            .parts()
+        self.parts
             .get_field_byte(self.entry_offset + Self::DATA_BUS_WIDTH_OFFSET)
     }
+
+    /// Segment Group Number, Bus Number and Device/Function Number (Peer)
+    /// as a [PciAddress]
+    pub fn pci_address(&self) -> Option<PciAddress> {
+        PciAddress::from_fields(
+            self.segment_group_number()?,
+            self.bus_number()?,
+            self.device_function_number()?,
+        )
+    }
+
+    /// Segment Group Number (Peer), decoded
+    pub fn segment_group(&self) -> Option<SegmentGroupNumber> {
+        self.segment_group_number().map(SegmentGroupNumber::from)
+    }
+
+    /// Bus Number (Peer), decoded
+    pub fn bus(&self) -> Option<BusNumber> {
+        self.bus_number().map(BusNumber::from)
+    }
+
+    /// Device/Function Number (Peer), decoded
+    pub fn device_function(&self) -> Option<DeviceFunctionNumber> {
+        self.device_function_number()
+            .map(DeviceFunctionNumber::from)
+    }
 }
 
 impl fmt::Debug for SlotPeerGroup<'_> {
@@ -1291,7 +1635,7 @@ impl Serialize for SlotPeerGroup<'_> {
 
 /// # On-board Device Itereator for [SlotPeerGroup]s contained within [SMBiosSystemSlot]
 pub struct SlotPeerGroupIterator<'a> {
-    data: &'a SMBiosSystemSlot<'a>,
+    parts: &'a UndefinedStruct,
     current_index: usize,
     current_entry: usize,
     number_of_entries: usize,
@@ -1300,9 +1644,9 @@ pub struct SlotPeerGroupIterator<'a> {
 impl<'a> SlotPeerGroupIterator<'a> {
     const PEER_GROUPS_OFFSET: usize = 0x13;
 
-    fn new(data: &'a SMBiosSystemSlot<'a>) -> Self {
+    fn new(data: &SMBiosSystemSlot<'a>) -> Self {
         SlotPeerGroupIterator {
-            data: data,
+            parts: data.parts(),
             current_index: Self::PEER_GROUPS_OFFSET,
             current_entry: 0,
             number_of_entries: data.peer_group_count().unwrap_or(0),
@@ -1321,10 +1665,10 @@ impl<'a> IntoIterator for &'a SlotPeerGroupIterator<'a> {
 
     fn into_iter(self) -> Self::IntoIter {
         SlotPeerGroupIterator {
-            data: self.data,
+            parts: self.parts,
             current_index: SlotPeerGroupIterator::PEER_GROUPS_OFFSET,
             current_entry: 0,
-            number_of_entries: self.data.peer_group_count().unwrap_or(0),
+            number_of_entries: self.number_of_entries,
         }
     }
 }
@@ -1339,13 +1683,9 @@ impl<'a> Iterator for SlotPeerGroupIterator<'a> {
         }
 
         let next_index = self.current_index + SlotPeerGroup::SIZE;
-        match self
-            .data
-            .parts()
-            .get_field_data(self.current_index, next_index)
-        {
+        match self.parts.get_field_data(self.current_index, next_index) {
             Some(_) => {
-                let result = SlotPeerGroup::new(self.data, self.current_index);
+                let result = SlotPeerGroup::new(self.parts, self.current_index);
                 self.current_index = next_index;
                 self.current_entry += 1;
                 Some(result)
@@ -1381,6 +1721,7 @@ impl<'a> Serialize for SlotPeerGroupIterator<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Handle, StructBuilder};
 
     #[test]
     fn unit_test() {
@@ -1451,4 +1792,264 @@ mod tests {
 
         println!("{:?}", test_struct);
     }
+
+    #[test]
+    fn test_slot_type_and_width() {
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .string(0x04, "PCIE1")
+            .byte(0x05, 0xBD)
+            .byte(0x06, 0x0D)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+
+        let slot_type = slot.system_slot_type().unwrap();
+        assert_eq!(
+            *slot_type,
+            SystemSlotType::PciExpress(
+                PciExpressGeneration::PCIExpressGen4,
+                PciExpressSlotWidth::X16
+            )
+        );
+        assert_eq!(
+            slot_type
+                .pci_express_generation()
+                .and_then(|generation| generation.generation()),
+            Some(4)
+        );
+        assert_eq!(
+            slot_type
+                .pci_express_width()
+                .and_then(|width| width.lanes()),
+            Some(16)
+        );
+
+        let width = slot.slot_data_bus_width().unwrap();
+        assert_eq!(*width, SlotWidth::X16);
+        assert_eq!(width.lanes(), Some(16));
+        assert_eq!(width.bits(), None);
+        assert_eq!(width.to_string(), "X16");
+
+        assert_eq!(SlotWidthData::from(0x06).bits(), Some(64));
+        assert_eq!(SlotWidthData::from(0x0F).to_string(), "15");
+
+        let ocp = SystemSlotTypeData::from(0x26);
+        assert_eq!(*ocp, SystemSlotType::OcpNic30SmallFormFactor);
+        assert_eq!(ocp.pci_express_generation(), None);
+        assert_eq!(
+            *SystemSlotTypeData::from(0x17),
+            SystemSlotType::M2(M2SlotType::M2Socket3)
+        );
+        assert_eq!(
+            *SystemSlotTypeData::from(0xC6),
+            SystemSlotType::EnterpriseAndDataCenter3InE3
+        );
+        assert_eq!(*SystemSlotTypeData::from(0x30), SystemSlotType::CxlFlexbus1);
+        assert_eq!(
+            SystemSlotTypeData::from(0xC4)
+                .pci_express_generation()
+                .and_then(|generation| generation.generation()),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_characteristics_flags() {
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .byte(0x0B, 0x0C)
+            .byte(0x0C, 0x0F)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+
+        let characteristics_1 = slot.slot_characteristics_1().unwrap();
+        assert!(characteristics_1.provides33_volts());
+        assert!(characteristics_1.shared());
+        assert!(!characteristics_1.provides5_volts());
+
+        let characteristics_2 = slot.slot_characteristics_2().unwrap();
+        assert!(characteristics_2.supports_hot_plug_devices());
+        assert!(characteristics_2.supports_bifurcation());
+        assert_eq!(characteristics_2.iter().count(), 4);
+    }
+
+    #[test]
+    fn test_peer_groups() {
+        use crate::core::TableBuilder;
+
+        // An x16 slot bifurcated into two x8 peers on bus 0x41 and 0x42
+        let mut builder = TableBuilder::new();
+        builder.add(
+            StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+                .string(0x04, "SLOT1")
+                .byte(0x05, 0xBD)
+                .byte(0x06, 0x0D)
+                .word(0x0D, 0x0000)
+                .byte(0x0F, 0x40)
+                .byte(0x10, 0x08)
+                .byte(0x11, 0x0D)
+                .byte(0x12, 2)
+                .data(0x13, &[0x00, 0x00, 0x41, 0x00, 0x0B])
+                .data(0x18, &[0x01, 0x00, 0x42, 0x09, 0x0B])
+                .byte(0x1D, 0x04)
+                .byte(0x1E, 0x0D),
+        );
+        let data = builder.build_smbios_data(None);
+
+        // Usable on a temporary structure
+        let peers: Vec<SlotPeerGroup<'_>> = data
+            .first::<SMBiosSystemSlot<'_>>()
+            .unwrap()
+            .peer_group_iterator()
+            .collect();
+        assert_eq!(peers.len(), 2);
+        assert_eq!(
+            peers[0].segment_group(),
+            Some(SegmentGroupNumber::SingleSegment)
+        );
+        assert_eq!(peers[0].bus(), Some(BusNumber::Number(0x41)));
+        assert_eq!(peers[0].data_bus_width(), Some(0x0B));
+        assert_eq!(peers[1].segment_group_number(), Some(1));
+        assert_eq!(peers[1].bus_number(), Some(0x42));
+        assert_eq!(
+            peers[1].device_function(),
+            Some(DeviceFunctionNumber::Number {
+                device: 1,
+                function: 1
+            })
+        );
+
+        let slot = data.first::<SMBiosSystemSlot<'_>>().unwrap();
+        assert_eq!(slot.peer_group_count(), Some(2));
+        assert_eq!(slot.slot_information(), Some(0x04));
+        assert_eq!(*slot.slot_physical_width().unwrap(), SlotWidth::X16);
+
+        assert_eq!(
+            SegmentGroupNumber::from(0xFFFF),
+            SegmentGroupNumber::NotApplicable
+        );
+        assert_eq!(
+            SegmentGroupNumber::from(0x00FF),
+            SegmentGroupNumber::Number(0xFF)
+        );
+    }
+
+    #[test]
+    fn test_fields_after_peer_groups() {
+        // 3.5 layout with one peer group; the trailing fields start at 0x18
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .byte(0x05, 0xB1)
+            .byte(0x06, 0x0B)
+            .byte(0x12, 1)
+            .data(0x13, &[0x00, 0x00, 0x02, 0x00, 0x0B])
+            .byte(0x18, 0x03)
+            .byte(0x19, 0x0D)
+            .word(0x1A, 1250)
+            .byte(0x1C, 0x04)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.slot_information(), Some(0x03));
+        assert_eq!(slot.pci_express_generation(), Some(3));
+        assert_eq!(*slot.slot_physical_width().unwrap(), SlotWidth::X16);
+        assert_eq!(slot.slot_pitch(), Some(1250));
+        assert_eq!(*slot.slot_height().unwrap(), SlotHeight::LowProfile);
+
+        // 3.2 layout without peer groups ends before the 3.4 fields
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .byte(0x05, 0xBD)
+            .byte(0x06, 0x0D)
+            .byte(0x12, 0)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.slot_information(), None);
+        assert!(slot.slot_physical_width().is_none());
+        assert_eq!(slot.slot_pitch(), None);
+        assert!(slot.slot_height().is_none());
+        assert_eq!(slot.pci_express_generation(), Some(4));
+
+        // 2.6 layout without the peer group count
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .byte(0x05, 0x06)
+            .byte(0x10, 0)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.peer_group_count(), None);
+        assert_eq!(slot.slot_information(), None);
+        assert_eq!(slot.pci_express_generation(), None);
+    }
+
+    #[test]
+    fn test_version_gating() {
+        // A 3.3 structure without peer groups, padded to the 3.5 length
+        let raw = StructBuilder::with_length(SMBiosSystemSlot::STRUCT_TYPE, 0x18)
+            .byte(0x05, 0xB1)
+            .byte(0x06, 0x0B)
+            .byte(0x12, 0)
+            .data(0x13, &[0xAA, 0xBB, 0xCC, 0xDD, 0xEE])
+            .build(Handle(9));
+        let mut parts = UndefinedStruct::new(&raw);
+        parts.version = Some(SMBiosVersion::new(3, 3, 0));
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.slot_information(), None);
+        assert!(slot.slot_physical_width().is_none());
+        assert_eq!(slot.slot_pitch(), None);
+        assert!(slot.slot_height().is_none());
+        assert_eq!(slot.pci_express_generation(), Some(3));
+
+        // Slot Height was added in 3.5
+        parts.version = Some(SMBiosVersion::new(3, 4, 0));
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.slot_information(), Some(0xAA));
+        assert_eq!(slot.slot_pitch(), Some(0xDDCC));
+        assert!(slot.slot_height().is_none());
+
+        parts.version = Some(SMBiosVersion::new(3, 5, 0));
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert_eq!(slot.slot_height().unwrap().raw, 0xEE);
+    }
+
+    #[test]
+    fn test_pci_address() {
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .word(0x0D, 0x0001)
+            .byte(0x0F, 0x17)
+            .byte(0x10, 0x1A)
+            .byte(0x11, 0x0D)
+            .byte(0x12, 1)
+            .data(0x13, &[0x00, 0x00, 0xFF, 0xFF, 0x0D])
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+
+        let address = slot.pci_address().unwrap();
+        assert_eq!(address, PciAddress::new(1, 0x17, 3, 2));
+        assert_eq!(address.to_string(), "0001:17:03.2");
+        assert!(!slot.bus_number().unwrap().is_not_applicable());
+
+        let peer = slot.peer_group_iterator().next().unwrap();
+        assert!(peer.bus().unwrap().is_not_applicable());
+        assert!(peer.device_function().unwrap().is_not_applicable());
+        assert_eq!(peer.pci_address(), None);
+
+        // Not a PCI slot
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .word(0x0D, 0xFFFF)
+            .byte(0x0F, 0xFF)
+            .byte(0x10, 0xFF)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        let slot = SMBiosSystemSlot::new(&parts);
+        assert!(slot.segment_group_number().unwrap().is_not_applicable());
+        assert_eq!(slot.pci_address(), None);
+
+        // Before 2.6
+        let raw = StructBuilder::new(SMBiosSystemSlot::STRUCT_TYPE)
+            .byte(0x0C, 0x00)
+            .build(Handle(9));
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(SMBiosSystemSlot::new(&parts).pci_address(), None);
+    }
 }
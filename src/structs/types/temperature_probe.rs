@@ -1,5 +1,5 @@
 use crate::core::{strings::*, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{ProbeStatus, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -155,7 +155,7 @@ impl Serialize for SMBiosTemperatureProbe<'_> {
 }
 
 /// # Temperature Probe Location and Status
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct TemperatureProbeLocationAndStatus {
     /// Raw value
     pub raw: u8,
@@ -203,42 +203,10 @@ impl Serialize for TemperatureProbeLocationAndStatus {
 }
 
 /// # Temperature Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub enum TemperatureProbeStatus {
-    /// Other
-    Other,
-    /// Unknown
-    Unknown,
-    /// OK
-    OK,
-    /// Non-critical
-    NonCritical,
-    /// Critical
-    Critical,
-    /// Non-recoverable
-    NonRecoverable,
-    /// A value unknown to this standard, check the raw value
-    None,
-}
-
-impl From<u8> for TemperatureProbeStatus {
-    fn from(raw: u8) -> Self {
-        match raw & 0b1110_0000 {
-            0b0000_0000 => TemperatureProbeStatus::None,
-            0b0010_0000 => TemperatureProbeStatus::Other,
-            0b0100_0000 => TemperatureProbeStatus::Unknown,
-            0b0110_0000 => TemperatureProbeStatus::OK,
-            0b1000_0000 => TemperatureProbeStatus::NonCritical,
-            0b1010_0000 => TemperatureProbeStatus::Critical,
-            0b1100_0000 => TemperatureProbeStatus::NonRecoverable,
-            0b1110_0000 => TemperatureProbeStatus::None,
-            _ => panic!("impossible value"),
-        }
-    }
-}
+pub type TemperatureProbeStatus = ProbeStatus;
 
 /// # Temperature Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureProbeLocation {
     /// Other
     Other,
@@ -298,7 +266,7 @@ impl From<u8> for TemperatureProbeLocation {
 }
 
 /// # Probe Temperature
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProbeTemperature {
     /// Temperature in 1/10 degrees C
     OneTenthDegreesC(u16),
@@ -315,8 +283,30 @@ impl From<u16> for ProbeTemperature {
     }
 }
 
+impl ProbeTemperature {
+    /// The temperature in degrees C, `None` when unknown
+    ///
+    /// The 1/10th degree word is read as two's complement so temperatures
+    /// below zero decode correctly.
+    pub fn celsius(&self) -> Option<f32> {
+        match self {
+            ProbeTemperature::OneTenthDegreesC(tenths) => Some(*tenths as i16 as f32 / 10.0),
+            ProbeTemperature::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for ProbeTemperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.celsius() {
+            Some(celsius) => write!(f, "{:.1} deg C", celsius),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// # Temperature Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureProbeResolution {
     /// Resolution for the probe's reading in 1/1000 degrees C
     OneOneThousandthDegreesC(u16),
@@ -333,8 +323,29 @@ impl From<u16> for TemperatureProbeResolution {
     }
 }
 
+impl TemperatureProbeResolution {
+    /// The resolution in degrees C, `None` when unknown
+    pub fn celsius(&self) -> Option<f32> {
+        match self {
+            TemperatureProbeResolution::OneOneThousandthDegreesC(thousandths) => {
+                Some(*thousandths as f32 / 1000.0)
+            }
+            TemperatureProbeResolution::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for TemperatureProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.celsius() {
+            Some(celsius) => write!(f, "{:.3} deg C", celsius),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// # Temperature Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureProbeAccuracy {
     /// Accuracy for the probe's reading in 1/100 degrees C
     OneOneHundredthDegreesC(u16),
@@ -351,6 +362,30 @@ impl From<u16> for TemperatureProbeAccuracy {
     }
 }
 
+impl TemperatureProbeAccuracy {
+    /// The accuracy in percent, `None` when unknown
+    ///
+    /// The specification gives this field in 1/100th of a percent, the
+    /// same unit as [super::ProbeAccuracy], despite the variant's name.
+    pub fn percent(&self) -> Option<f32> {
+        match self {
+            TemperatureProbeAccuracy::OneOneHundredthDegreesC(hundredths) => {
+                Some(*hundredths as f32 / 100.0)
+            }
+            TemperatureProbeAccuracy::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for TemperatureProbeAccuracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.percent() {
+            Some(percent) => write!(f, "{:.2}%", percent),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,4 +432,34 @@ mod tests {
             ProbeTemperature::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_scaling() {
+        let struct_type28 = vec![
+            0x1C, 0x16, 0x2A, 0x00, 0x01, 0x63, 0x4C, 0x04, 0x70, 0xFE, 0xF4, 0x01, 0x0A, 0x00,
+            0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x01, 0x4C, 0x4D, 0x37, 0x38, 0x41, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type28);
+        let test_struct = SMBiosTemperatureProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(
+            location_and_status.location(),
+            TemperatureProbeLocation::Processor
+        );
+        assert_eq!(location_and_status.status(), TemperatureProbeStatus::OK);
+        assert_eq!(test_struct.maximum_value().unwrap().celsius(), Some(110.0));
+        assert_eq!(test_struct.minimum_value().unwrap().celsius(), Some(-40.0));
+        assert_eq!(test_struct.resolution().unwrap().celsius(), Some(0.5));
+        assert_eq!(test_struct.accuracy().unwrap().percent(), Some(1.0));
+        assert_eq!(test_struct.accuracy().unwrap().to_string(), "1.00%");
+        assert_eq!(test_struct.tolerance().unwrap().to_string(), "1.0 deg C");
+        assert_eq!(
+            test_struct.nominal_value().unwrap().to_string(),
+            "45.0 deg C"
+        );
+        assert_eq!(ProbeTemperature::from(0x8000).celsius(), None);
+    }
 }
@@ -32,7 +32,7 @@ impl<'a> SMBiosUnknown<'a> {
 
 impl fmt::Debug for SMBiosUnknown<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let fields = &self.parts.fields[Header::SIZE..];
+        let fields = self.parts.fields.get(Header::SIZE..).unwrap_or(&[]);
         fmt.debug_struct(std::any::type_name::<SMBiosUnknown<'_>>())
             .field("header", &self.parts.header)
             .field("fields", &fields)
@@ -46,7 +46,7 @@ impl Serialize for SMBiosUnknown<'_> {
     where
         S: Serializer,
     {
-        let fields = &self.parts.fields[Header::SIZE..];
+        let fields = self.parts.fields.get(Header::SIZE..).unwrap_or(&[]);
 
         let mut state = serializer.serialize_struct("SMBiosUnknown", 3)?;
         state.serialize_field("header", &self.parts.header)?;
@@ -1,5 +1,5 @@
 use crate::core::{strings::*, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{ProbeAccuracy, ProbeStatus, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -148,7 +148,7 @@ impl Serialize for SMBiosVoltageProbe<'_> {
 }
 
 /// # Voltage Probe Location and Status
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct VoltageProbeLocationAndStatus {
     /// Raw value
     pub raw: u8,
@@ -196,42 +196,10 @@ impl Serialize for VoltageProbeLocationAndStatus {
 }
 
 /// # Voltage Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub enum VoltageProbeStatus {
-    /// Other
-    Other,
-    /// Unknown
-    Unknown,
-    /// OK
-    OK,
-    /// Non-critical
-    NonCritical,
-    /// Critical
-    Critical,
-    /// Non-recoverable
-    NonRecoverable,
-    /// A value unknown to this standard, check the raw value
-    None,
-}
-
-impl From<u8> for VoltageProbeStatus {
-    fn from(raw: u8) -> Self {
-        match raw & 0b1110_0000 {
-            0b0000_0000 => VoltageProbeStatus::None,
-            0b0010_0000 => VoltageProbeStatus::Other,
-            0b0100_0000 => VoltageProbeStatus::Unknown,
-            0b0110_0000 => VoltageProbeStatus::OK,
-            0b1000_0000 => VoltageProbeStatus::NonCritical,
-            0b1010_0000 => VoltageProbeStatus::Critical,
-            0b1100_0000 => VoltageProbeStatus::NonRecoverable,
-            0b1110_0000 => VoltageProbeStatus::None,
-            _ => panic!("impossible value"),
-        }
-    }
-}
+pub type VoltageProbeStatus = ProbeStatus;
 
 /// # Voltage Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VoltageProbeLocation {
     /// Other
     Other,
@@ -279,7 +247,7 @@ impl From<u8> for VoltageProbeLocation {
 }
 
 /// # Probe Voltage
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProbeVoltage {
     /// Voltage in millivolts
     Millivolts(u16),
@@ -296,8 +264,30 @@ impl From<u16> for ProbeVoltage {
     }
 }
 
+impl ProbeVoltage {
+    /// The voltage in volts, `None` when unknown
+    ///
+    /// The millivolt word is read as two's complement so negative rails,
+    /// such as -12 V, decode correctly.
+    pub fn volts(&self) -> Option<f32> {
+        match self {
+            ProbeVoltage::Millivolts(millivolts) => Some(*millivolts as i16 as f32 / 1000.0),
+            ProbeVoltage::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for ProbeVoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.volts() {
+            Some(volts) => write!(f, "{:.3} V", volts),
+            None => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// # Voltage Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VoltageProbeResolution {
     /// Resolution for the probe's reading in tenths of millivolts
     TenthsOfMillivolts(u16),
@@ -314,24 +304,28 @@ impl From<u16> for VoltageProbeResolution {
     }
 }
 
-/// # Voltage Probe Accuracy
-#[derive(Serialize, Debug)]
-pub enum VoltageProbeAccuracy {
-    /// Accuracy for the probe's reading in 1/100th of a percent
-    OneOneHundredthPercent(u16),
-    /// Accuracy is unknown
-    Unknown,
+impl VoltageProbeResolution {
+    /// The resolution in millivolts, `None` when unknown
+    pub fn millivolts(&self) -> Option<f32> {
+        match self {
+            VoltageProbeResolution::TenthsOfMillivolts(tenths) => Some(*tenths as f32 / 10.0),
+            VoltageProbeResolution::Unknown => None,
+        }
+    }
 }
 
-impl From<u16> for VoltageProbeAccuracy {
-    fn from(raw: u16) -> Self {
-        match raw {
-            0x8000 => VoltageProbeAccuracy::Unknown,
-            _ => VoltageProbeAccuracy::OneOneHundredthPercent(raw),
+impl fmt::Display for VoltageProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.millivolts() {
+            Some(millivolts) => write!(f, "{:.1} mV", millivolts),
+            None => write!(f, "Unknown"),
         }
     }
 }
 
+/// # Voltage Probe Accuracy
+pub type VoltageProbeAccuracy = ProbeAccuracy;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,4 +371,32 @@ mod tests {
             ProbeVoltage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_scaling() {
+        let struct_type26 = vec![
+            26, 0x16, 0x2A, 0x00, 0x01, 0x67, 0xF8, 0x2E, 0x20, 0xD1, 0x0A, 0x00, 0x78, 0x00, 0x32,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0xD1, 0x4C, 0x4D, 0x37, 0x38, 0x41, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type26);
+        let test_struct = SMBiosVoltageProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(
+            location_and_status.location(),
+            VoltageProbeLocation::Motherboard
+        );
+        assert_eq!(location_and_status.status(), VoltageProbeStatus::OK);
+        assert_eq!(test_struct.maximum_value().unwrap().volts(), Some(12.024));
+        assert_eq!(test_struct.minimum_value().unwrap().volts(), Some(-12.0));
+        assert_eq!(
+            test_struct.nominal_value().unwrap().to_string(),
+            "-12.016 V"
+        );
+        assert_eq!(test_struct.resolution().unwrap().millivolts(), Some(1.0));
+        assert_eq!(test_struct.tolerance().unwrap().to_string(), "0.120 V");
+        assert_eq!(test_struct.accuracy().unwrap().to_string(), "0.50%");
+        assert_eq!(ProbeVoltage::from(0x8000).to_string(), "Unknown");
+    }
 }
@@ -0,0 +1,347 @@
+//! UEFI configuration table loader
+//!
+//! Locates the SMBIOS entry point published by firmware in the UEFI configuration
+//! table and parses the structure table directly from memory.  This is intended
+//! for pre-boot applications where memory is identity mapped and the firmware
+//! tables can be read through their physical addresses.
+//!
+//! The loader does not use any operating system facilities, but it is not
+//! `no_std`: like the rest of the library it reports failures as
+//! [std::io::Error] and copies the entry point and structure table into owned
+//! buffers, as [SMBiosData] does not borrow its table.  Applications therefore
+//! need `std` (or the standard library support of the `*-unknown-uefi` targets)
+//! and an allocator available when the loader runs, typically before
+//! `ExitBootServices()`.
+
+use crate::core::{SMBiosData, SMBiosEntryPoint32, SMBiosEntryPoint64, UndefinedStructTable};
+use std::convert::TryFrom;
+use std::ffi::c_void;
+use std::io::{Error, ErrorKind};
+
+/// EFI_GUID
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EfiGuid {
+    /// First 32 bits of the GUID
+    pub data1: u32,
+    /// Next 16 bits of the GUID
+    pub data2: u16,
+    /// Next 16 bits of the GUID
+    pub data3: u16,
+    /// Final 64 bits of the GUID
+    pub data4: [u8; 8],
+}
+
+/// SMBIOS_TABLE_GUID (points to a 32-bit `_SM_` entry point)
+pub const SMBIOS_TABLE_GUID: EfiGuid = EfiGuid {
+    data1: 0xeb9d2d31,
+    data2: 0x2d88,
+    data3: 0x11d3,
+    data4: [0x9a, 0x16, 0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+};
+
+/// SMBIOS3_TABLE_GUID (points to a 64-bit `_SM3_` entry point)
+pub const SMBIOS3_TABLE_GUID: EfiGuid = EfiGuid {
+    data1: 0xf2fd1544,
+    data2: 0x9794,
+    data3: 0x4a2c,
+    data4: [0x99, 0x2e, 0xe5, 0xbb, 0xcf, 0x20, 0xe3, 0x94],
+};
+
+/// EFI_TABLE_HEADER
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct EfiTableHeader {
+    /// Table signature
+    pub signature: u64,
+    /// Revision of the UEFI specification the table conforms to
+    pub revision: u32,
+    /// Size of the entire table including this header
+    pub header_size: u32,
+    /// CRC32 of the table
+    pub crc32: u32,
+    /// Reserved, must be zero
+    pub reserved: u32,
+}
+
+/// EFI_CONFIGURATION_TABLE
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct EfiConfigurationTable {
+    /// GUID identifying the table
+    pub vendor_guid: EfiGuid,
+    /// Pointer to the table associated with `vendor_guid`
+    pub vendor_table: *const c_void,
+}
+
+/// EFI_SYSTEM_TABLE
+///
+/// Protocol and service pointers are left opaque as only the configuration table
+/// is used by this library.
+#[repr(C)]
+#[derive(Debug)]
+pub struct EfiSystemTable {
+    /// Table header
+    pub hdr: EfiTableHeader,
+    /// Null terminated UCS-2 firmware vendor string
+    pub firmware_vendor: *const u16,
+    /// Firmware vendor specific revision
+    pub firmware_revision: u32,
+    /// Console input handle
+    pub console_in_handle: *const c_void,
+    /// EFI_SIMPLE_TEXT_INPUT_PROTOCOL
+    pub con_in: *const c_void,
+    /// Console output handle
+    pub console_out_handle: *const c_void,
+    /// EFI_SIMPLE_TEXT_OUTPUT_PROTOCOL
+    pub con_out: *const c_void,
+    /// Standard error handle
+    pub standard_error_handle: *const c_void,
+    /// EFI_SIMPLE_TEXT_OUTPUT_PROTOCOL for standard error
+    pub std_err: *const c_void,
+    /// EFI_RUNTIME_SERVICES
+    pub runtime_services: *const c_void,
+    /// EFI_BOOT_SERVICES
+    pub boot_services: *const c_void,
+    /// Number of entries in `configuration_table`
+    pub number_of_table_entries: usize,
+    /// Array of EFI_CONFIGURATION_TABLE entries
+    pub configuration_table: *const EfiConfigurationTable,
+}
+
+/// Loads [SMBiosData] using the configuration table of the given UEFI system table
+///
+/// # Safety
+///
+/// `system_table` must point to a valid EFI_SYSTEM_TABLE, and the SMBIOS entry
+/// point and structure table it references must be readable at their published
+/// addresses.
+pub unsafe fn table_load_from_system_table(
+    system_table: *const EfiSystemTable,
+) -> Result<SMBiosData, Error> {
+    let system_table = system_table
+        .as_ref()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Null system table"))?;
+
+    if system_table.configuration_table.is_null() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            "The system table has no configuration table",
+        ));
+    }
+
+    let entries = std::slice::from_raw_parts(
+        system_table.configuration_table,
+        system_table.number_of_table_entries,
+    );
+
+    table_load_from_configuration_table(entries)
+}
+
+/// Loads [SMBiosData] from the given UEFI configuration table entries
+///
+/// The SMBIOS 3.0 (64-bit) entry point is preferred over the 32-bit entry point
+/// when firmware publishes both.
+///
+/// # Safety
+///
+/// The `vendor_table` of any SMBIOS entry, and the structure table it references,
+/// must be readable at their published addresses.
+pub unsafe fn table_load_from_configuration_table(
+    entries: &[EfiConfigurationTable],
+) -> Result<SMBiosData, Error> {
+    load_from_configuration_table(entries, |address, length| read_table(address, length))
+}
+
+/// Locates and reads the entry point, reading the structure table it references with `read_table`
+unsafe fn load_from_configuration_table<F>(
+    entries: &[EfiConfigurationTable],
+    read_table: F,
+) -> Result<SMBiosData, Error>
+where
+    F: Fn(u64, usize) -> Result<UndefinedStructTable, Error>,
+{
+    let find = |guid: &EfiGuid| {
+        entries
+            .iter()
+            .find(|entry| entry.vendor_guid == *guid && !entry.vendor_table.is_null())
+            .map(|entry| entry.vendor_table as *const u8)
+    };
+
+    if let Some(address) = find(&SMBIOS3_TABLE_GUID) {
+        let raw = read_entry_point(
+            address,
+            SMBiosEntryPoint64::MINIMUM_SIZE,
+            SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET,
+        );
+        let entry_point = SMBiosEntryPoint64::try_from(raw)?;
+        let table = read_table(
+            entry_point.structure_table_address(),
+            entry_point.structure_table_maximum_size() as usize,
+        )?;
+        return Ok(SMBiosData::new(table, Some(entry_point.version())));
+    }
+
+    if let Some(address) = find(&SMBIOS_TABLE_GUID) {
+        let raw = read_entry_point(
+            address,
+            SMBiosEntryPoint32::MINIMUM_SIZE,
+            SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET,
+        );
+        let entry_point = SMBiosEntryPoint32::try_from(raw)?;
+        let table = read_table(
+            entry_point.structure_table_address() as u64,
+            entry_point.structure_table_length() as usize,
+        )?;
+        return Ok(SMBiosData::new(table, Some(entry_point.version())));
+    }
+
+    Err(Error::new(
+        ErrorKind::NotFound,
+        "No SMBIOS entry point found in the configuration table",
+    ))
+}
+
+/// Copies an entry point from memory, using its length field when it exceeds the minimum size.
+unsafe fn read_entry_point(
+    address: *const u8,
+    minimum_size: usize,
+    length_offset: usize,
+) -> Vec<u8> {
+    let length = (*address.add(length_offset) as usize).max(minimum_size);
+    std::slice::from_raw_parts(address, length).to_vec()
+}
+
+unsafe fn read_table(address: u64, length: usize) -> Result<UndefinedStructTable, Error> {
+    if address == 0 || usize::try_from(address).is_err() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The entry point has given an invalid table address: {:#X}",
+                address
+            ),
+        ));
+    }
+
+    let table = std::slice::from_raw_parts(address as usize as *const u8, length);
+    Ok(UndefinedStructTable::from(table.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SMBiosVersion;
+    use std::ptr;
+
+    // Type 127 end-of-table structure
+    const TABLE: [u8; 6] = [0x7F, 0x04, 0x00, 0x00, 0x00, 0x00];
+
+    fn set_checksum(raw: &mut [u8], checksum_offset: usize) {
+        raw[checksum_offset] = 0;
+        let sum = raw.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        raw[checksum_offset] = 0u8.wrapping_sub(sum);
+    }
+
+    fn entry(vendor_guid: EfiGuid, vendor_table: *const u8) -> EfiConfigurationTable {
+        EfiConfigurationTable {
+            vendor_guid,
+            vendor_table: vendor_table as *const c_void,
+        }
+    }
+
+    #[test]
+    fn test_load_from_configuration_table() {
+        let table = TABLE.to_vec();
+
+        let mut entry_point_64 = vec![
+            b'_', b'S', b'M', b'3', b'_', 0x00, 0x18, 0x03, 0x05, 0x00, 0x01, 0x00,
+        ];
+        entry_point_64.extend_from_slice(&(table.len() as u32).to_le_bytes());
+        entry_point_64.extend_from_slice(&(table.as_ptr() as u64).to_le_bytes());
+        set_checksum(
+            &mut entry_point_64,
+            SMBiosEntryPoint64::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET,
+        );
+
+        let other_guid = EfiGuid {
+            data1: 0x8868e871,
+            data2: 0xe4f1,
+            data3: 0x11d3,
+            data4: [0xbc, 0x22, 0x00, 0x80, 0xc7, 0x3c, 0x88, 0x81],
+        };
+
+        let entries = [
+            entry(other_guid, ptr::null()),
+            entry(SMBIOS3_TABLE_GUID, entry_point_64.as_ptr()),
+        ];
+        let data = unsafe { table_load_from_configuration_table(&entries) }.unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 5, 0)));
+        assert_eq!(data.iter().count(), 1);
+
+        let system_table = EfiSystemTable {
+            hdr: EfiTableHeader {
+                signature: 0x5453595320494249,
+                revision: 0x0002_0046,
+                header_size: std::mem::size_of::<EfiSystemTable>() as u32,
+                crc32: 0,
+                reserved: 0,
+            },
+            firmware_vendor: ptr::null(),
+            firmware_revision: 0,
+            console_in_handle: ptr::null(),
+            con_in: ptr::null(),
+            console_out_handle: ptr::null(),
+            con_out: ptr::null(),
+            standard_error_handle: ptr::null(),
+            std_err: ptr::null(),
+            runtime_services: ptr::null(),
+            boot_services: ptr::null(),
+            number_of_table_entries: entries.len(),
+            configuration_table: entries.as_ptr(),
+        };
+        let data = unsafe { table_load_from_system_table(&system_table) }.unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 5, 0)));
+
+        // The 32-bit entry point can only reference tables below 4GB, so the
+        // table is published at a fixed low address and read from `TABLE`
+        const TABLE_ADDRESS: u32 = 0x000F_0000;
+        let mut entry_point_32 = vec![
+            b'_', b'S', b'M', b'_', 0x00, 0x1F, 0x02, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, b'_', b'D', b'M', b'I', b'_', 0x00,
+        ];
+        entry_point_32.extend_from_slice(&(TABLE.len() as u16).to_le_bytes());
+        entry_point_32.extend_from_slice(&TABLE_ADDRESS.to_le_bytes());
+        entry_point_32.extend_from_slice(&[0x01, 0x00, 0x28]);
+        set_checksum(
+            &mut entry_point_32[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET..],
+            SMBiosEntryPoint32::INTERMEDIATE_CHECKSUM_OFFSET
+                - SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET,
+        );
+        set_checksum(
+            &mut entry_point_32,
+            SMBiosEntryPoint32::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET,
+        );
+
+        let read_low_table = |address: u64, length: usize| {
+            assert_eq!(address, TABLE_ADDRESS as u64);
+            assert_eq!(length, TABLE.len());
+            Ok(UndefinedStructTable::from(TABLE.to_vec()))
+        };
+        let entries = [entry(SMBIOS_TABLE_GUID, entry_point_32.as_ptr())];
+        let data = unsafe { load_from_configuration_table(&entries, read_low_table) }.unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(2, 8, 0)));
+        assert_eq!(data.iter().count(), 1);
+
+        // The 64-bit entry point is preferred when both are published
+        let entries = [
+            entry(SMBIOS_TABLE_GUID, entry_point_32.as_ptr()),
+            entry(SMBIOS3_TABLE_GUID, entry_point_64.as_ptr()),
+        ];
+        let data = unsafe { table_load_from_configuration_table(&entries) }.unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 5, 0)));
+
+        let entries = [entry(other_guid, entry_point_64.as_ptr())];
+        let error = unsafe { table_load_from_configuration_table(&entries) }.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+}
@@ -1,7 +1,13 @@
-#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[cfg(all(
+    any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+    feature = "os-loader"
+))]
 mod platform;
 
-#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[cfg(all(
+    any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+    feature = "os-loader"
+))]
 pub use platform::*;
 
 pub use std::convert::TryInto;
@@ -1,5 +1,8 @@
 use crate::*;
-use std::{io::Error, io::ErrorKind};
+use std::io::Error;
+
+#[cfg(any(target_os = "freebsd", all(target_os = "linux", feature = "dev-mem")))]
+use std::io::ErrorKind;
 
 #[cfg(any(target_os = "linux"))]
 /// Full path to smbios_entry_point file on Linux (contains entry point data)
@@ -47,90 +50,176 @@ pub const DEV_MEM_FILE: &'static str = "/dev/mem";
 #[cfg(any(target_os = "linux"))]
 /// Loads [SMBiosData] from the device via /sys/firmware/dmi/tables (on Linux)
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    let version: SMBiosVersion;
-    let entry_path = std::path::Path::new(SYS_ENTRY_FILE);
-
-    match SMBiosEntryPoint64::try_load_from_file(entry_path) {
-        Ok(entry_point) => {
-            version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: entry_point.docrev(),
-            }
-        }
-        Err(err) => match err.kind() {
-            ErrorKind::InvalidData => match SMBiosEntryPoint32::try_load_from_file(entry_path) {
-                Ok(entry_point) => {
-                    version = SMBiosVersion {
-                        major: entry_point.major_version(),
-                        minor: entry_point.minor_version(),
-                        revision: 0,
-                    }
-                }
-                Err(err) => return Err(err),
-            },
-            _ => return Err(err),
-        },
-    }
-
-    SMBiosData::try_load_from_file(SYS_TABLE_FILE, Some(version))
+    load_smbios_data_from_entry_point_and_table_files(
+        std::path::Path::new(SYS_ENTRY_FILE),
+        std::path::Path::new(SYS_TABLE_FILE),
+    )
 }
 
 #[cfg(any(target_os = "freebsd"))]
 /// Loads [SMBiosData] from the device via /dev/mem (on FreeBSD)
+///
+/// The entry point is found using the `smbios.entry` kernel environment
+/// variable, falling back to a scan of the legacy 0xF0000-0xFFFFF range.
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    const RANGE_START: u64 = 0x000F0000u64;
-    const RANGE_END: u64 = 0x000FFFFFu64;
-    let structure_table_address: u64;
-    let structure_table_length: u32;
-    let version: SMBiosVersion;
-
     let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+    let (structure_table_address, structure_table_length, version) =
+        locate_freebsd_table(&mut dev_mem)?;
 
-    match SMBiosEntryPoint32::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END) {
-        Ok(entry_point) => {
-            structure_table_address = entry_point.structure_table_address() as u64;
-            structure_table_length = entry_point.structure_table_length() as u32;
+    let table = UndefinedStructTable::try_load_from_file_offset(
+        &mut dev_mem,
+        structure_table_address,
+        structure_table_length,
+    )?;
 
-            version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: 0,
-            }
-        }
-        Err(error) => {
-            if error.kind() != ErrorKind::UnexpectedEof {
-                return Err(error);
-            }
+    Ok(SMBiosData::new(table, Some(version)))
+}
 
-            let entry_point =
-                SMBiosEntryPoint64::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END)?;
+#[cfg(target_os = "freebsd")]
+/// Kernel environment variable holding the entry point physical address (on FreeBSD)
+pub const KENV_SMBIOS_ENTRY: &str = "smbios.entry";
+
+/// Reads the entry point physical address from the `smbios.entry` kernel environment variable.
+///
+/// The loader sets this variable on both legacy and UEFI systems, e.g. "0xf05b0".
+#[cfg(target_os = "freebsd")]
+fn kenv_entry_point_address() -> Option<u64> {
+    // KENV_MVALLEN (128) plus the terminating null
+    let mut value = [0u8; 129];
+    let name = std::ffi::CString::new(KENV_SMBIOS_ENTRY).ok()?;
+
+    let result = unsafe {
+        libc::kenv(
+            libc::KENV_GET,
+            name.as_ptr(),
+            value.as_mut_ptr() as *mut libc::c_char,
+            value.len() as libc::c_int,
+        )
+    };
+
+    if result <= 0 {
+        return None;
+    }
 
-            structure_table_address = entry_point.structure_table_address();
-            structure_table_length = entry_point.structure_table_maximum_size();
+    let value = value.split(|byte| *byte == 0).next()?;
+    let value = std::str::from_utf8(value).ok()?.trim();
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse::<u64>().ok(),
+    }
+}
 
-            version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: entry_point.docrev(),
-            }
+/// Locates the structure table in /dev/mem, returning its address, length, and SMBIOS version.
+#[cfg(target_os = "freebsd")]
+fn locate_freebsd_table(dev_mem: &mut std::fs::File) -> Result<(u64, usize, SMBiosVersion), Error> {
+    // The kernel environment gives the exact entry point location, which on UEFI
+    // systems is outside of the legacy range and therefore cannot be found by scanning.
+    if let Some(address) = kenv_entry_point_address() {
+        if let Ok(entry_point) = SMBiosEntryPoint64::try_scan_from_file(dev_mem, address..=address)
+        {
+            return Ok((
+                entry_point.structure_table_address(),
+                entry_point.structure_table_maximum_size() as usize,
+                SMBiosVersion {
+                    major: entry_point.major_version(),
+                    minor: entry_point.minor_version(),
+                    revision: entry_point.docrev(),
+                },
+            ));
+        }
+
+        if let Ok(entry_point) = SMBiosEntryPoint32::try_scan_from_file(dev_mem, address..=address)
+        {
+            return Ok((
+                entry_point.structure_table_address() as u64,
+                entry_point.structure_table_length() as usize,
+                SMBiosVersion {
+                    major: entry_point.major_version(),
+                    minor: entry_point.minor_version(),
+                    revision: 0,
+                },
+            ));
         }
     }
 
-    if structure_table_address + structure_table_length as u64 > RANGE_END {
+    scan_dev_mem_table(dev_mem)
+}
+
+/// Scans the legacy 0xF0000-0xFFFFF range of /dev/mem for an entry point,
+/// returning the structure table address, length, and SMBIOS version.
+///
+/// Only the entry point is required to be within the legacy range; the table
+/// it points to may be anywhere in physical memory.
+#[cfg(any(target_os = "freebsd", all(target_os = "linux", feature = "dev-mem")))]
+fn scan_dev_mem_table(dev_mem: &mut std::fs::File) -> Result<(u64, usize, SMBiosVersion), Error> {
+    const RANGE_START: u64 = 0x000F0000u64;
+    const RANGE_END: u64 = 0x000FFFFFu64;
+
+    let (structure_table_address, structure_table_length, version) =
+        match SMBiosEntryPoint32::try_scan_from_file(dev_mem, RANGE_START..=RANGE_END) {
+            Ok(entry_point) => (
+                entry_point.structure_table_address() as u64,
+                entry_point.structure_table_length() as usize,
+                SMBiosVersion {
+                    major: entry_point.major_version(),
+                    minor: entry_point.minor_version(),
+                    revision: 0,
+                },
+            ),
+            Err(error) => {
+                if error.kind() != ErrorKind::UnexpectedEof {
+                    return Err(error);
+                }
+
+                let entry_point =
+                    SMBiosEntryPoint64::try_scan_from_file(dev_mem, RANGE_START..=RANGE_END)?;
+
+                (
+                    entry_point.structure_table_address(),
+                    entry_point.structure_table_maximum_size() as usize,
+                    SMBiosVersion {
+                        major: entry_point.major_version(),
+                        minor: entry_point.minor_version(),
+                        revision: entry_point.docrev(),
+                    },
+                )
+            }
+        };
+
+    if structure_table_address
+        .checked_add(structure_table_length as u64)
+        .is_none()
+    {
         return Err(Error::new(
             ErrorKind::InvalidData,
             format!(
-                "The entry point has given a length which exceeds the range: {}",
+                "The entry point has given a length which exceeds the address space: {}",
                 structure_table_length
             ),
         ));
     }
 
+    Ok((structure_table_address, structure_table_length, version))
+}
+
+#[cfg(any(target_os = "freebsd", all(target_os = "linux", feature = "dev-mem")))]
+/// Loads [SMBiosData] by scanning /dev/mem for a legacy entry point
+///
+/// This is intended for systems without sysfs DMI support.  Reading /dev/mem
+/// requires root privileges, so on Linux this is only available with the
+/// `dev-mem` feature.
+pub fn table_load_from_dev_mem() -> Result<SMBiosData, Error> {
+    let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+    let (structure_table_address, structure_table_length, version) =
+        scan_dev_mem_table(&mut dev_mem)?;
+
     let table = UndefinedStructTable::try_load_from_file_offset(
         &mut dev_mem,
         structure_table_address,
-        structure_table_length as usize,
+        structure_table_length,
     )?;
 
     Ok(SMBiosData::new(table, Some(version)))
@@ -146,50 +235,9 @@ pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
 /// Returns smbios raw data via /dev/mem (on FreeBSD)
 pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
     use std::io::{prelude::*, SeekFrom};
-    const RANGE_START: u64 = 0x000F0000u64;
-    const RANGE_END: u64 = 0x000FFFFFu64;
-    let structure_table_address: u64;
-    let structure_table_length: usize;
 
     let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
-
-    match SMBiosEntryPoint32::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END) {
-        Ok(entry_point) => {
-            structure_table_address = entry_point.structure_table_address() as u64;
-            structure_table_length = entry_point.structure_table_length() as usize;
-        }
-        Err(error) => {
-            if error.kind() != ErrorKind::UnexpectedEof {
-                return Err(error);
-            }
-
-            let entry_point =
-                SMBiosEntryPoint64::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END)?;
-
-            structure_table_address = entry_point.structure_table_address();
-            structure_table_length = entry_point.structure_table_maximum_size() as usize;
-        }
-    }
-
-    if structure_table_address < RANGE_START || structure_table_address > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given an out of range start address for the table: {}",
-                structure_table_address
-            ),
-        ));
-    }
-
-    if structure_table_address + structure_table_length as u64 > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given a length which exceeds the range: {}",
-                structure_table_length
-            ),
-        ));
-    }
+    let (structure_table_address, structure_table_length, _) = locate_freebsd_table(&mut dev_mem)?;
 
     if structure_table_length < Header::SIZE + 2 {
         return Err(Error::new(
@@ -210,7 +258,7 @@ pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
 mod tests {
     use super::*;
     use std::fs::File;
-    use std::io;
+    use std::io::{self, ErrorKind};
 
     #[test]
     fn test_dev_mem_scan() -> io::Result<()> {
@@ -18,12 +18,12 @@
 
 mod win_struct;
 
-#[cfg(target_family = "windows")]
+#[cfg(all(target_family = "windows", feature = "os-loader"))]
 mod platform;
 
 pub use win_struct::*;
 
-#[cfg(target_family = "windows")]
+#[cfg(all(target_family = "windows", feature = "os-loader"))]
 pub use platform::*;
 
 pub use std::convert::TryInto;
@@ -116,6 +116,14 @@ impl WinSMBiosData {
         self.windows_header[WinSMBiosData::DMI_REVISION_OFFSET]
     }
 
+    /// SMBIOS version reported by the Windows header
+    ///
+    /// Combines `smbios_major_version`, `smbios_minor_version`, and
+    /// `dmi_revision` into an [SMBiosVersion].
+    pub fn version(&self) -> SMBiosVersion {
+        WinSMBiosData::version_from_raw_header(&self.windows_header)
+    }
+
     fn version_from_raw_header(windows_header: &Vec<u8>) -> SMBiosVersion {
         SMBiosVersion {
             major: windows_header[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET],
@@ -200,6 +208,11 @@ mod tests {
         assert_eq!(win_smbios_data.smbios_minor_version(), 0x04);
         assert_eq!(win_smbios_data.dmi_revision(), 0x00);
         assert_eq!(win_smbios_data.table_data_length(), 0x01);
+        assert_eq!(win_smbios_data.version(), SMBiosVersion::new(3, 4, 0));
+        assert_eq!(
+            win_smbios_data.smbios_data.version,
+            Some(win_smbios_data.version())
+        );
     }
 
     #[test]
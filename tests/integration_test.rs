@@ -1,3 +1,6 @@
+// Every test loads the table of the running system
+#![cfg(all(feature = "os-loader", feature = "memory", feature = "processor"))]
+
 use smbioslib::*;
 
 #[cfg(target_family = "windows")]
@@ -87,3 +90,33 @@ fn find_installed_memory() {
             .for_each(|installed_memory| println!("Installed memory: {:#X?}", installed_memory)),
     }
 }
+
+/// Test that a loaded table can be stored and used after the source bytes are gone
+#[test]
+fn owned_table_storage() {
+    struct Inventory {
+        data: SMBiosData,
+    }
+
+    fn load() -> Inventory {
+        // Type 1 with a manufacturer string, followed by the end-of-table structure
+        let raw = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', b'C', b'M', b'E', 0x00, 0x00,
+            0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+        Inventory {
+            data: SMBiosData::from(raw),
+        }
+    }
+
+    let inventory = load();
+    let copy = inventory.data.clone();
+    drop(inventory);
+
+    let system_information = copy.first::<SMBiosSystemInformation>().unwrap();
+    assert_eq!(
+        system_information.manufacturer().to_string(),
+        "ACME".to_string()
+    );
+    assert_eq!(copy.iter().count(), 2);
+}